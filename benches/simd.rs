@@ -0,0 +1,89 @@
+//! Benchmarks the tensor kernels used when calculating Cauchy tangent stiffnesses.
+//!
+//! Compare `cargo bench --features constitutive` with `cargo bench --features constitutive,simd`,
+//! and set `RUSTFLAGS="-C target-cpu=native"` for both to use AVX registers on x86_64 targets that have them.
+
+use flavio::{
+    constitutive::{
+        solid::{elastic::Elastic, hyperelastic::NeoHookean},
+        Constitutive,
+    },
+    math::{ContractFirstSecondIndicesWithSecondIndicesOf, TensorArray, TensorRank2, TensorRank4},
+    mechanics::DeformationGradient,
+};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const ITERATIONS: u32 = 200_000;
+const SAMPLES: u32 = 10;
+
+fn bench<F: FnMut()>(name: &str, mut function: F) {
+    //
+    // The fastest sample is reported, since it is the one least disturbed by other processes.
+    //
+    (0..ITERATIONS).for_each(|_| function());
+    let elapsed = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            (0..ITERATIONS).for_each(|_| function());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    println!(
+        "{:<56} {:>10.1} ns/iter",
+        name,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let deformation_gradient = DeformationGradient::new([
+        [0.63595746, 0.69157849, 0.71520784],
+        [0.80589604, 0.83687323, 0.19312595],
+        [0.05387420, 0.86551549, 0.41880244],
+    ]);
+    let tensor_rank_2_a = TensorRank2::<3, 1, 1>::new(deformation_gradient.as_array());
+    let tensor_rank_2_b = tensor_rank_2_a.inverse();
+    let model = NeoHookean::new(&[13.0, 3.0]);
+    let tangent_stiffness = model
+        .calculate_cauchy_tangent_stiffness(&deformation_gradient)
+        .unwrap();
+    println!(
+        "simd feature {}",
+        if cfg!(feature = "simd") {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    );
+    bench("TensorRank2 * TensorRank2", || {
+        black_box(black_box(&tensor_rank_2_a) * black_box(&tensor_rank_2_b));
+    });
+    bench("dyad_ik_jl", || {
+        black_box(TensorRank4::<3, 1, 1, 1, 1>::dyad_ik_jl(
+            black_box(&tensor_rank_2_a),
+            black_box(&tensor_rank_2_b),
+        ));
+    });
+    bench(
+        "contract_first_second_indices_with_second_indices_of",
+        || {
+            black_box(
+                black_box(&tangent_stiffness).contract_first_second_indices_with_second_indices_of(
+                    black_box(&tensor_rank_2_a),
+                    black_box(&tensor_rank_2_b),
+                ),
+            );
+        },
+    );
+    bench("NeoHookean::calculate_cauchy_tangent_stiffness", || {
+        black_box(
+            model
+                .calculate_cauchy_tangent_stiffness(black_box(&deformation_gradient))
+                .unwrap(),
+        );
+    });
+}
@@ -11,11 +11,11 @@ pub mod solid;
 pub mod thermal;
 
 use crate::{
-    get_defeat_message,
     math::optimize::OptimizeError,
     mechanics::{DeformationGradient, Scalar},
+    write_error, write_error_debug,
 };
-use std::fmt;
+use std::{error::Error, fmt};
 
 /// Array of constitutive model parameters.
 pub type Parameters<'a> = &'a [Scalar];
@@ -30,96 +30,76 @@ pub trait Constitutive<'a> {
 pub enum ConstitutiveError {
     Custom(String, DeformationGradient, String),
     InvalidJacobian(Scalar, DeformationGradient, String),
-    MaximumStepsReached(usize, String),
-    NotMinimum(String, String),
+    Optimize(Box<OptimizeError>),
 }
 
 impl From<ConstitutiveError> for OptimizeError {
-    fn from(_error: ConstitutiveError) -> OptimizeError {
-        todo!()
+    fn from(error: ConstitutiveError) -> OptimizeError {
+        OptimizeError::Evaluation(Box::new(error), 0)
     }
 }
 
 impl From<OptimizeError> for ConstitutiveError {
-    fn from(_error: OptimizeError) -> ConstitutiveError {
-        todo!()
+    fn from(error: OptimizeError) -> ConstitutiveError {
+        //
+        // Errors that were themselves encountered in a constitutive model are returned as they are.
+        //
+        match error {
+            OptimizeError::Evaluation(error, steps) => {
+                match error.downcast::<ConstitutiveError>() {
+                    Ok(error) => *error,
+                    Err(error) => Self::Optimize(Box::new(OptimizeError::Evaluation(error, steps))),
+                }
+            }
+            _ => Self::Optimize(Box::new(error)),
+        }
     }
 }
 
-impl fmt::Debug for ConstitutiveError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = match self {
-            Self::Custom(message, deformation_gradient, constitutive_model) => format!(
-                "\x1b[1;91m{}\x1b[0;91m\n\
-                 From deformation gradient: {}.\n\
-                 In constitutive model: {}.",
-                message, deformation_gradient, constitutive_model
-            ),
-            Self::InvalidJacobian(jacobian, deformation_gradient, constitutive_model) => {
+impl Error for ConstitutiveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Optimize(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl ConstitutiveError {
+    fn message(&self) -> (String, String) {
+        match self {
+            Self::Custom(message, deformation_gradient, constitutive_model) => (
+                message.to_string(),
                 format!(
-                    "\x1b[1;91mInvalid Jacobian: {:.6e}.\x1b[0;91m\n\
-                     From deformation gradient: {}.\n\
+                    "From deformation gradient: {}.\n\
                      In constitutive model: {}.",
-                    jacobian, deformation_gradient, constitutive_model
-                )
-            }
-            Self::MaximumStepsReached(steps, constitutive_model) => {
+                    deformation_gradient, constitutive_model
+                ),
+            ),
+            Self::InvalidJacobian(jacobian, deformation_gradient, constitutive_model) => (
+                format!("Invalid Jacobian: {:.6e}.", jacobian),
                 format!(
-                    "\x1b[1;91mMaximum number of steps ({}) reached.\x1b[0;91m\n\
+                    "From deformation gradient: {}.\n\
                      In constitutive model: {}.",
-                    steps, constitutive_model
-                )
-            }
-            Self::NotMinimum(deformation_gradient, constitutive_model) => {
-                format!(
-                    "\x1b[1;91mThe obtained solution is not a minimum.\x1b[0;91m\n\
-                     {}\nIn constitutive model: {}.",
                     deformation_gradient, constitutive_model
-                )
-            }
-        };
-        write!(
-            f,
-            "\n{}\n\x1b[0;2;31m{}\x1b[0m\n",
-            error,
-            get_defeat_message()
-        )
+                ),
+            ),
+            Self::Optimize(error) => ("Failed to optimize.".to_string(), format!("{}", error)),
+        }
+    }
+}
+
+impl fmt::Debug for ConstitutiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (headline, details) = self.message();
+        write_error_debug(f, &headline, &details)
     }
 }
 
 impl fmt::Display for ConstitutiveError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = match self {
-            Self::Custom(message, deformation_gradient, constitutive_model) => format!(
-                "\x1b[1;91m{}\x1b[0;91m\n\
-                 From deformation gradient: {}.\n\
-                 In constitutive model: {}.",
-                message, deformation_gradient, constitutive_model
-            ),
-            Self::InvalidJacobian(jacobian, deformation_gradient, constitutive_model) => {
-                format!(
-                    "\x1b[1;91mInvalid Jacobian: {:.6e}.\x1b[0;91m\n\
-                     From deformation gradient: {}.\n\
-                     In constitutive model: {}.",
-                    jacobian, deformation_gradient, constitutive_model
-                )
-            }
-            Self::MaximumStepsReached(steps, constitutive_model) => {
-                format!(
-                    "\x1b[1;91mMaximum number of steps ({}) reached.\x1b[0;91m\n\
-                     In constitutive model: {}.",
-                    steps, constitutive_model
-                )
-            }
-            Self::NotMinimum(deformation_gradient, constitutive_model) => {
-                format!(
-                    "\x1b[1;91mThe obtained solution is not a minimum.\x1b[0;91m\n\
-                     {}\nIn constitutive model: {}.",
-                    deformation_gradient, constitutive_model
-                )
-            }
-        };
-        write!(f, "{}\x1b[0m", error)
+        let (headline, details) = self.message();
+        write_error(f, &headline, &details)
     }
 }
 
@@ -134,8 +114,10 @@ impl PartialEq for ConstitutiveError {
                 Self::InvalidJacobian(d, e, f) => a == d && b == e && c == f,
                 _ => false,
             },
-            Self::MaximumStepsReached(_, _) => todo!(),
-            Self::NotMinimum(_, _) => todo!(),
+            Self::Optimize(a) => match other {
+                Self::Optimize(b) => a == b,
+                _ => false,
+            },
         }
     }
 }
@@ -86,6 +86,62 @@ impl<'a> Viscoelastic<'a> for AlmansiHamel<'a> {
             ))
         }
     }
+    /// Calculates and returns the tangent stiffness associated with the Cauchy stress.
+    ///
+    /// ```math
+    /// \mathcal{T}_{ijkL}(\mathbf{F},\dot\mathbf{F}) = \frac{\mu}{J}\left[B_{jk}^{-1}F_{iL}^{-T} + B_{ik}^{-1}F_{jL}^{-T}\right] + \frac{1}{J}\left(\kappa - \frac{2}{3}\,\mu\right)\delta_{ij}B_{km}^{-1}F_{mL}^{-T} - \frac{\eta}{J}\left[L_{ik}F_{jL}^{-T} + L_{jk}F_{iL}^{-T}\right] - \frac{1}{J}\left(\zeta - \frac{2}{3}\,\eta\right)\delta_{ij}L_{mk}F_{mL}^{-T} - \sigma_{ij}F_{kL}^{-T}
+    /// ```
+    fn calculate_cauchy_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+        deformation_gradient_rate: &DeformationGradientRate,
+    ) -> Result<CauchyTangentStiffness, ConstitutiveError> {
+        let jacobian = deformation_gradient.determinant();
+        if jacobian > 0.0 {
+            let inverse_transpose_deformation_gradient = deformation_gradient.inverse_transpose();
+            let inverse_left_cauchy_green_deformation = &inverse_transpose_deformation_gradient
+                * inverse_transpose_deformation_gradient.transpose();
+            let velocity_gradient =
+                deformation_gradient_rate * inverse_transpose_deformation_gradient.transpose();
+            Ok((CauchyTangentStiffness::dyad_il_jk(
+                &inverse_transpose_deformation_gradient,
+                &inverse_left_cauchy_green_deformation,
+            ) + CauchyTangentStiffness::dyad_ik_jl(
+                &inverse_left_cauchy_green_deformation,
+                &inverse_transpose_deformation_gradient,
+            )) * (self.get_shear_modulus() / jacobian)
+                - (CauchyTangentStiffness::dyad_il_jk(
+                    &inverse_transpose_deformation_gradient,
+                    &velocity_gradient,
+                ) + CauchyTangentStiffness::dyad_ik_jl(
+                    &velocity_gradient,
+                    &inverse_transpose_deformation_gradient,
+                )) * (self.get_shear_viscosity() / jacobian)
+                + CauchyTangentStiffness::dyad_ij_kl(
+                    &IDENTITY,
+                    &(inverse_left_cauchy_green_deformation
+                        * &inverse_transpose_deformation_gradient
+                        * ((self.get_bulk_modulus() - self.get_shear_modulus() * TWO_THIRDS)
+                            / jacobian)
+                        - velocity_gradient.transpose()
+                            * &inverse_transpose_deformation_gradient
+                            * ((self.get_bulk_viscosity()
+                                - self.get_shear_viscosity() * TWO_THIRDS)
+                                / jacobian)),
+                )
+                - CauchyTangentStiffness::dyad_ij_kl(
+                    &self
+                        .calculate_cauchy_stress(deformation_gradient, deformation_gradient_rate)?,
+                    &inverse_transpose_deformation_gradient,
+                ))
+        } else {
+            Err(ConstitutiveError::InvalidJacobian(
+                jacobian,
+                deformation_gradient.copy(),
+                format!("{:?}", &self),
+            ))
+        }
+    }
     /// Calculates and returns the rate tangent stiffness associated with the Cauchy stress.
     ///
     /// ```math
@@ -69,7 +69,7 @@ where
         let mut cauchy_stresses = CauchyStresses::zero();
         let mut deformation_gradients = DeformationGradients::identity();
         let time_steps = evaluation_times.windows(2).map(|time| time[1] - time[0]);
-        for ((index, time_step), time) in time_steps.enumerate().zip(evaluation_times.into_iter()) {
+        for ((index, time_step), time) in time_steps.enumerate().zip(evaluation_times) {
             (deformation_gradients[index + 1], cauchy_stresses[index + 1]) = self
                 .solve_uniaxial_inner(
                     &deformation_gradients[index],
@@ -34,7 +34,11 @@ pub use self::{
     neo_hookean::NeoHookean, saint_venant_kirchoff::SaintVenantKirchoff, yeoh::Yeoh,
 };
 use super::{elastic::Elastic, *};
-use std::fmt::Debug;
+use crate::math::{
+    dual::{Dual, HyperDual, Number},
+    TensorRank2,
+};
+use std::{array::from_fn, fmt::Debug};
 
 /// Required methods for hyperelastic constitutive models.
 pub trait Hyperelastic<'a>
@@ -51,3 +55,141 @@ where
         deformation_gradient: &DeformationGradient,
     ) -> Result<Scalar, ConstitutiveError>;
 }
+
+/// Required methods for hyperelastic constitutive models using automatic differentiation.
+///
+/// The stresses are obtained exactly from the Helmholtz free energy density evaluated for a deformation gradient of dual numbers,
+/// and the tangent stiffnesses from one of hyper-dual numbers.
+///
+/// ```math
+/// P_{iJ} = \frac{\partial a}{\partial F_{iJ}},\quad\mathcal{C}_{iJkL} = \frac{\partial^2a}{\partial F_{iJ}\partial F_{kL}}
+/// ```
+pub trait AutomaticHyperelastic<'a>
+where
+    Self: Solid<'a> + Debug,
+{
+    /// Calculates and returns the Helmholtz free energy density for a deformation gradient of real, dual, or hyper-dual numbers.
+    fn calculate_helmholtz_free_energy_density_number<N: Number>(
+        &self,
+        deformation_gradient: &TensorRank2<3, 1, 0, N>,
+    ) -> Result<N, ConstitutiveError>;
+}
+
+/// Returns the deformation gradient as dual numbers, seeding the derivative along the given flattened entry.
+fn dual_deformation_gradient(
+    deformation_gradient: &DeformationGradient,
+    entry: usize,
+) -> TensorRank2<3, 1, 0, Dual> {
+    TensorRank2::new(from_fn(|i| {
+        from_fn(|j| Dual::new(deformation_gradient[i][j], seed(entry, 3 * i + j)))
+    }))
+}
+
+/// Returns the deformation gradient as hyper-dual numbers, seeding the first and second derivatives along the given flattened entries.
+fn hyper_dual_deformation_gradient(
+    deformation_gradient: &DeformationGradient,
+    entry_a: usize,
+    entry_b: usize,
+) -> TensorRank2<3, 1, 0, HyperDual> {
+    TensorRank2::new(from_fn(|i| {
+        from_fn(|j| {
+            Dual::new(
+                Dual::new(deformation_gradient[i][j], seed(entry_b, 3 * i + j)),
+                Dual::constant(seed(entry_a, 3 * i + j)),
+            )
+        })
+    }))
+}
+
+/// Returns the seed of a flattened entry, which is one along the given entry and zero otherwise.
+fn seed(entry: usize, index: usize) -> Scalar {
+    if entry == index {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+impl<'a, T> Elastic<'a> for T
+where
+    T: AutomaticHyperelastic<'a>,
+{
+    fn calculate_cauchy_stress(
+        &self,
+        deformation_gradient: &DeformationGradient,
+    ) -> Result<CauchyStress, ConstitutiveError> {
+        Ok(
+            self.calculate_first_piola_kirchoff_stress(deformation_gradient)?
+                * deformation_gradient.transpose()
+                / deformation_gradient.determinant(),
+        )
+    }
+    fn calculate_cauchy_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+    ) -> Result<CauchyTangentStiffness, ConstitutiveError> {
+        let jacobian = deformation_gradient.determinant();
+        let first_piola_kirchoff_stress =
+            self.calculate_first_piola_kirchoff_stress(deformation_gradient)?;
+        Ok((self
+            .calculate_first_piola_kirchoff_tangent_stiffness(deformation_gradient)?
+            .contract_second_index_with_first_index_of(&deformation_gradient.transpose())
+            + CauchyTangentStiffness::dyad_il_jk(&first_piola_kirchoff_stress, &IDENTITY))
+            / jacobian
+            - CauchyTangentStiffness::dyad_ij_kl(
+                &(first_piola_kirchoff_stress * deformation_gradient.transpose() / jacobian),
+                &deformation_gradient.inverse_transpose(),
+            ))
+    }
+    fn calculate_first_piola_kirchoff_stress(
+        &self,
+        deformation_gradient: &DeformationGradient,
+    ) -> Result<FirstPiolaKirchoffStress, ConstitutiveError> {
+        let mut first_piola_kirchoff_stress = FirstPiolaKirchoffStress::zero();
+        for i in 0..3 {
+            for j in 0..3 {
+                first_piola_kirchoff_stress[i][j] =
+                    self.calculate_helmholtz_free_energy_density_number(
+                        &dual_deformation_gradient(deformation_gradient, 3 * i + j),
+                    )?
+                    .derivative;
+            }
+        }
+        Ok(first_piola_kirchoff_stress)
+    }
+    fn calculate_first_piola_kirchoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+    ) -> Result<FirstPiolaKirchoffTangentStiffness, ConstitutiveError> {
+        //
+        // The tangent stiffness is symmetric, so only the upper triangle of second derivatives is evaluated.
+        //
+        let mut first_piola_kirchoff_tangent_stiffness = FirstPiolaKirchoffTangentStiffness::zero();
+        for index_a in 0..9 {
+            for index_b in index_a..9 {
+                let (i, j, k, l) = (index_a / 3, index_a % 3, index_b / 3, index_b % 3);
+                let second_derivative = self
+                    .calculate_helmholtz_free_energy_density_number(
+                        &hyper_dual_deformation_gradient(deformation_gradient, index_a, index_b),
+                    )?
+                    .derivative
+                    .derivative;
+                first_piola_kirchoff_tangent_stiffness[i][j][k][l] = second_derivative;
+                first_piola_kirchoff_tangent_stiffness[k][l][i][j] = second_derivative;
+            }
+        }
+        Ok(first_piola_kirchoff_tangent_stiffness)
+    }
+}
+
+impl<'a, T> Hyperelastic<'a> for T
+where
+    T: AutomaticHyperelastic<'a>,
+{
+    fn calculate_helmholtz_free_energy_density(
+        &self,
+        deformation_gradient: &DeformationGradient,
+    ) -> Result<Scalar, ConstitutiveError> {
+        self.calculate_helmholtz_free_energy_density_number(deformation_gradient)
+    }
+}
@@ -10,3 +10,79 @@ test_solid_hyperelastic_constitutive_model!(
 );
 
 test_solve!(NeoHookean::new(NEOHOOKEANPARAMETERS));
+
+mod automatic {
+    use super::*;
+    use crate::{
+        constitutive::solid::hyperelastic::AutomaticHyperelastic,
+        math::{dual::Number, TensorRank2},
+    };
+
+    #[derive(Debug)]
+    struct AutomaticNeoHookean<'a> {
+        parameters: Parameters<'a>,
+    }
+
+    impl<'a> Constitutive<'a> for AutomaticNeoHookean<'a> {
+        fn new(parameters: Parameters<'a>) -> Self {
+            Self { parameters }
+        }
+    }
+
+    impl<'a> Solid<'a> for AutomaticNeoHookean<'a> {
+        fn get_bulk_modulus(&self) -> &Scalar {
+            &self.parameters[0]
+        }
+        fn get_shear_modulus(&self) -> &Scalar {
+            &self.parameters[1]
+        }
+    }
+
+    impl<'a> AutomaticHyperelastic<'a> for AutomaticNeoHookean<'a> {
+        fn calculate_helmholtz_free_energy_density_number<N: Number>(
+            &self,
+            deformation_gradient: &TensorRank2<3, 1, 0, N>,
+        ) -> Result<N, ConstitutiveError> {
+            let jacobian = deformation_gradient.determinant();
+            if jacobian.to_f64() > 0.0 {
+                Ok(
+                    ((deformation_gradient * deformation_gradient.transpose()).trace()
+                        / jacobian.powf(TWO_THIRDS)
+                        - 3.0)
+                        * (0.5 * self.get_shear_modulus())
+                        + ((jacobian.powi(2) - 1.0) * 0.5 - jacobian.ln())
+                            * (0.5 * self.get_bulk_modulus()),
+                )
+            } else {
+                Err(ConstitutiveError::InvalidJacobian(
+                    jacobian.to_f64(),
+                    TensorRank2::new(
+                        deformation_gradient
+                            .as_array()
+                            .map(|row| row.map(|entry| entry.to_f64())),
+                    ),
+                    format!("{:?}", &self),
+                ))
+            }
+        }
+    }
+
+    use_elastic_macros!();
+
+    test_solid_hyperelastic_constitutive_model!(
+        AutomaticNeoHookean,
+        NEOHOOKEANPARAMETERS,
+        AutomaticNeoHookean::new(NEOHOOKEANPARAMETERS)
+    );
+
+    #[test]
+    fn first_piola_kirchoff_tangent_stiffness() -> Result<(), TestError> {
+        let deformation_gradient = get_deformation_gradient();
+        assert_eq_within_tols(
+            &AutomaticNeoHookean::new(NEOHOOKEANPARAMETERS)
+                .calculate_first_piola_kirchoff_tangent_stiffness(&deformation_gradient)?,
+            &NeoHookean::new(NEOHOOKEANPARAMETERS)
+                .calculate_first_piola_kirchoff_tangent_stiffness(&deformation_gradient)?,
+        )
+    }
+}
@@ -83,6 +83,43 @@ impl<'a> Viscoelastic<'a> for SaintVenantKirchoff<'a> {
             ))
         }
     }
+    /// Calculates and returns the tangent stiffness associated with the second Piola-Kirchoff stress.
+    ///
+    /// ```math
+    /// \mathcal{G}_{IJkL}(\mathbf{F},\dot\mathbf{F}) = \mu\,\delta_{JL}F_{kI} + \mu\,\delta_{IL}F_{kJ} + \left(\kappa - \frac{2}{3}\,\mu\right)\delta_{IJ}F_{kL} + \eta\,\delta_{JL}\dot{F}_{kI} + \eta\,\delta_{IL}\dot{F}_{kJ} + \left(\zeta - \frac{2}{3}\,\eta\right)\delta_{IJ}\dot{F}_{kL}
+    /// ```
+    fn calculate_second_piola_kirchoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+        deformation_gradient_rate: &DeformationGradientRate,
+    ) -> Result<SecondPiolaKirchoffTangentStiffness, ConstitutiveError> {
+        let jacobian = deformation_gradient.determinant();
+        if jacobian > 0.0 {
+            let scaled_transposes = deformation_gradient.transpose() * self.get_shear_modulus()
+                + deformation_gradient_rate.transpose() * self.get_shear_viscosity();
+            Ok(
+                SecondPiolaKirchoffTangentStiffness::dyad_ik_jl(&scaled_transposes, &IDENTITY_00)
+                    + SecondPiolaKirchoffTangentStiffness::dyad_il_jk(
+                        &IDENTITY_00,
+                        &scaled_transposes,
+                    )
+                    + SecondPiolaKirchoffTangentStiffness::dyad_ij_kl(
+                        &IDENTITY_00,
+                        &(deformation_gradient
+                            * (self.get_bulk_modulus() - TWO_THIRDS * self.get_shear_modulus())
+                            + deformation_gradient_rate
+                                * (self.get_bulk_viscosity()
+                                    - TWO_THIRDS * self.get_shear_viscosity())),
+                    ),
+            )
+        } else {
+            Err(ConstitutiveError::InvalidJacobian(
+                jacobian,
+                deformation_gradient.copy(),
+                format!("{:?}", &self),
+            ))
+        }
+    }
     /// Calculates and returns the rate tangent stiffness associated with the second Piola-Kirchoff stress.
     ///
     /// ```math
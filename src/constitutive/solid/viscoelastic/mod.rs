@@ -9,6 +9,8 @@
 //! ```math
 //! \mathcal{U}_{iJkL} \neq \mathcal{U}_{kLiJ}
 //! ```
+//!
+//! The tangent stiffnesses are taken with respect to the deformation gradient at a fixed deformation gradient rate.
 
 #[cfg(test)]
 pub mod test;
@@ -59,6 +61,37 @@ where
             )
             / deformation_gradient.determinant())
     }
+    /// Calculates and returns the tangent stiffness associated with the Cauchy stress.
+    ///
+    /// ```math
+    /// \mathcal{T}_{ijkL} = \frac{\partial\sigma_{ij}}{\partial F_{kL}} = J^{-1} \mathcal{G}_{MNkL} F_{iM} F_{jN} - \sigma_{ij} F_{kL}^{-T} + \left(\delta_{jk}\sigma_{is} + \delta_{ik}\sigma_{js}\right)F_{sL}^{-T}
+    /// ```
+    fn calculate_cauchy_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+        deformation_gradient_rate: &DeformationGradientRate,
+    ) -> Result<CauchyTangentStiffness, ConstitutiveError> {
+        let deformation_gradient_inverse_transpose = deformation_gradient.inverse_transpose();
+        let cauchy_stress =
+            self.calculate_cauchy_stress(deformation_gradient, deformation_gradient_rate)?;
+        let some_stress = &cauchy_stress * &deformation_gradient_inverse_transpose;
+        Ok(self
+            .calculate_second_piola_kirchoff_tangent_stiffness(
+                deformation_gradient,
+                deformation_gradient_rate,
+            )?
+            .contract_first_second_indices_with_second_indices_of(
+                deformation_gradient,
+                deformation_gradient,
+            )
+            / deformation_gradient.determinant()
+            - CauchyTangentStiffness::dyad_ij_kl(
+                &cauchy_stress,
+                &deformation_gradient_inverse_transpose,
+            )
+            + CauchyTangentStiffness::dyad_il_kj(&some_stress, &IDENTITY)
+            + CauchyTangentStiffness::dyad_ik_jl(&IDENTITY, &some_stress))
+    }
     /// Calculates and returns the first Piola-Kirchoff stress.
     ///
     /// ```math
@@ -93,6 +126,34 @@ where
             .contract_second_index_with_first_index_of(&deformation_gradient.inverse_transpose())
             * deformation_gradient.determinant())
     }
+    /// Calculates and returns the tangent stiffness associated with the first Piola-Kirchoff stress.
+    ///
+    /// ```math
+    /// \mathcal{C}_{iJkL} = \frac{\partial P_{iJ}}{\partial F_{kL}} = J \mathcal{T}_{iskL} F_{sJ}^{-T} + P_{iJ} F_{kL}^{-T} - P_{iL} F_{kJ}^{-T}
+    /// ```
+    fn calculate_first_piola_kirchoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+        deformation_gradient_rate: &DeformationGradientRate,
+    ) -> Result<FirstPiolaKirchoffTangentStiffness, ConstitutiveError> {
+        let deformation_gradient_inverse_transpose = deformation_gradient.inverse_transpose();
+        let first_piola_kirchoff_stress = self.calculate_first_piola_kirchoff_stress(
+            deformation_gradient,
+            deformation_gradient_rate,
+        )?;
+        Ok(self
+            .calculate_cauchy_tangent_stiffness(deformation_gradient, deformation_gradient_rate)?
+            .contract_second_index_with_first_index_of(&deformation_gradient_inverse_transpose)
+            * deformation_gradient.determinant()
+            + FirstPiolaKirchoffTangentStiffness::dyad_ij_kl(
+                &first_piola_kirchoff_stress,
+                &deformation_gradient_inverse_transpose,
+            )
+            - FirstPiolaKirchoffTangentStiffness::dyad_il_kj(
+                &first_piola_kirchoff_stress,
+                &deformation_gradient_inverse_transpose,
+            ))
+    }
     /// Calculates and returns the second Piola-Kirchoff stress.
     ///
     /// ```math
@@ -130,4 +191,40 @@ where
             )
             * deformation_gradient.determinant())
     }
+    /// Calculates and returns the tangent stiffness associated with the second Piola-Kirchoff stress.
+    ///
+    /// ```math
+    /// \mathcal{G}_{IJkL} = \frac{\partial S_{IJ}}{\partial F_{kL}} = J \mathcal{T}_{mnkL} F_{mI}^{-T} F_{nJ}^{-T} + S_{IJ} F_{kL}^{-T} - S_{IL} F_{kJ}^{-T} -S_{LJ} F_{kI}^{-T}
+    /// ```
+    fn calculate_second_piola_kirchoff_tangent_stiffness(
+        &self,
+        deformation_gradient: &DeformationGradient,
+        deformation_gradient_rate: &DeformationGradientRate,
+    ) -> Result<SecondPiolaKirchoffTangentStiffness, ConstitutiveError> {
+        let deformation_gradient_inverse_transpose = deformation_gradient.inverse_transpose();
+        let deformation_gradient_inverse = deformation_gradient_inverse_transpose.transpose();
+        let second_piola_kirchoff_stress = self.calculate_second_piola_kirchoff_stress(
+            deformation_gradient,
+            deformation_gradient_rate,
+        )?;
+        Ok(self
+            .calculate_cauchy_tangent_stiffness(deformation_gradient, deformation_gradient_rate)?
+            .contract_first_second_indices_with_second_indices_of(
+                &deformation_gradient_inverse,
+                &deformation_gradient_inverse,
+            )
+            * deformation_gradient.determinant()
+            + SecondPiolaKirchoffTangentStiffness::dyad_ij_kl(
+                &second_piola_kirchoff_stress,
+                &deformation_gradient_inverse_transpose,
+            )
+            - SecondPiolaKirchoffTangentStiffness::dyad_il_kj(
+                &second_piola_kirchoff_stress,
+                &deformation_gradient_inverse_transpose,
+            )
+            - SecondPiolaKirchoffTangentStiffness::dyad_ik_jl(
+                &deformation_gradient_inverse,
+                &second_piola_kirchoff_stress,
+            ))
+    }
 }
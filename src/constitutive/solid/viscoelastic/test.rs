@@ -644,6 +644,134 @@ macro_rules! test_solid_viscous_constitutive_model
                     }
                 }
             }
+            mod tangent_stiffness
+            {
+                use super::*;
+                fn get_deformation_gradient_and_deformation_gradient_rate(is_deformed: bool) -> (DeformationGradient, DeformationGradientRate)
+                {
+                    if is_deformed
+                    {
+                        (get_deformation_gradient(), get_deformation_gradient_rate())
+                    }
+                    else
+                    {
+                        (DeformationGradient::identity(), DeformationGradientRate::zero())
+                    }
+                }
+                fn calculate_tangent_stiffness_from_finite_difference<T: Tensor>(
+                    is_deformed: bool,
+                    stress: impl Fn(&DeformationGradient, &DeformationGradientRate) -> Result<T, TestError>,
+                    component: impl Fn(&T, usize, usize) -> Scalar
+                ) -> Result<FirstPiolaKirchoffTangentStiffness, TestError>
+                {
+                    let (deformation_gradient, deformation_gradient_rate) = get_deformation_gradient_and_deformation_gradient_rate(is_deformed);
+                    let mut tangent_stiffness = FirstPiolaKirchoffTangentStiffness::zero();
+                    for k in 0..3
+                    {
+                        for l in 0..3
+                        {
+                            let mut deformation_gradient_plus = deformation_gradient.copy();
+                            deformation_gradient_plus[k][l] += 0.5*EPSILON;
+                            let stress_plus = stress(&deformation_gradient_plus, &deformation_gradient_rate)?;
+                            let mut deformation_gradient_minus = deformation_gradient.copy();
+                            deformation_gradient_minus[k][l] -= 0.5*EPSILON;
+                            let stress_minus = stress(&deformation_gradient_minus, &deformation_gradient_rate)?;
+                            for i in 0..3
+                            {
+                                for j in 0..3
+                                {
+                                    tangent_stiffness[i][j][k][l] = (
+                                        component(&stress_plus, i, j) - component(&stress_minus, i, j)
+                                    )/EPSILON;
+                                }
+                            }
+                        }
+                    }
+                    Ok(tangent_stiffness)
+                }
+                fn cauchy_tangent_stiffness(is_deformed: bool) -> Result<(), TestError>
+                {
+                    let (deformation_gradient, deformation_gradient_rate) = get_deformation_gradient_and_deformation_gradient_rate(is_deformed);
+                    let tangent_stiffness = $constitutive_model_constructed.calculate_cauchy_tangent_stiffness(&deformation_gradient, &deformation_gradient_rate)?;
+                    assert_eq_from_fd(
+                        &FirstPiolaKirchoffTangentStiffness::new(tangent_stiffness.as_array()),
+                        &calculate_tangent_stiffness_from_finite_difference(
+                            is_deformed,
+                            |deformation_gradient, deformation_gradient_rate| Ok(
+                                $constitutive_model_constructed.calculate_cauchy_stress(deformation_gradient, deformation_gradient_rate)?
+                            ),
+                            |stress: &CauchyStress, i, j| stress[i][j]
+                        )?
+                    )
+                }
+                fn first_piola_kirchoff_tangent_stiffness(is_deformed: bool) -> Result<(), TestError>
+                {
+                    let (deformation_gradient, deformation_gradient_rate) = get_deformation_gradient_and_deformation_gradient_rate(is_deformed);
+                    assert_eq_from_fd(
+                        &$constitutive_model_constructed.calculate_first_piola_kirchoff_tangent_stiffness(&deformation_gradient, &deformation_gradient_rate)?,
+                        &calculate_tangent_stiffness_from_finite_difference(
+                            is_deformed,
+                            |deformation_gradient, deformation_gradient_rate| Ok(
+                                $constitutive_model_constructed.calculate_first_piola_kirchoff_stress(deformation_gradient, deformation_gradient_rate)?
+                            ),
+                            |stress: &FirstPiolaKirchoffStress, i, j| stress[i][j]
+                        )?
+                    )
+                }
+                fn second_piola_kirchoff_tangent_stiffness(is_deformed: bool) -> Result<(), TestError>
+                {
+                    let (deformation_gradient, deformation_gradient_rate) = get_deformation_gradient_and_deformation_gradient_rate(is_deformed);
+                    let tangent_stiffness = $constitutive_model_constructed.calculate_second_piola_kirchoff_tangent_stiffness(&deformation_gradient, &deformation_gradient_rate)?;
+                    assert_eq_from_fd(
+                        &FirstPiolaKirchoffTangentStiffness::new(tangent_stiffness.as_array()),
+                        &calculate_tangent_stiffness_from_finite_difference(
+                            is_deformed,
+                            |deformation_gradient, deformation_gradient_rate| Ok(
+                                $constitutive_model_constructed.calculate_second_piola_kirchoff_stress(deformation_gradient, deformation_gradient_rate)?
+                            ),
+                            |stress: &SecondPiolaKirchoffStress, i, j| stress[i][j]
+                        )?
+                    )
+                }
+                mod deformed
+                {
+                    use super::*;
+                    #[test]
+                    fn cauchy_finite_difference() -> Result<(), TestError>
+                    {
+                        cauchy_tangent_stiffness(true)
+                    }
+                    #[test]
+                    fn first_piola_kirchoff_finite_difference() -> Result<(), TestError>
+                    {
+                        first_piola_kirchoff_tangent_stiffness(true)
+                    }
+                    #[test]
+                    fn second_piola_kirchoff_finite_difference() -> Result<(), TestError>
+                    {
+                        second_piola_kirchoff_tangent_stiffness(true)
+                    }
+                }
+                mod undeformed
+                {
+                    use super::*;
+                    #[test]
+                    fn cauchy_finite_difference() -> Result<(), TestError>
+                    {
+                        cauchy_tangent_stiffness(false)
+                    }
+                    #[test]
+                    fn first_piola_kirchoff_finite_difference() -> Result<(), TestError>
+                    {
+                        first_piola_kirchoff_tangent_stiffness(false)
+                    }
+                    #[test]
+                    fn second_piola_kirchoff_finite_difference() -> Result<(), TestError>
+                    {
+                        second_piola_kirchoff_tangent_stiffness(false)
+                    }
+                }
+            }
         }
     }
 }
@@ -1,6 +1,60 @@
-use super::{ConstitutiveError, Parameters};
-use crate::math::test::TestError;
-use std::convert::From;
+use super::{ConstitutiveError, OptimizeError, Parameters};
+use crate::{
+    math::{optimize::Convergence, test::TestError, TensorArray},
+    mechanics::DeformationGradient,
+};
+use std::error::Error;
+
+#[test]
+fn from_optimize_error() {
+    let optimize_error = || {
+        OptimizeError::MaximumStepsReached(
+            3,
+            Convergence {
+                residual_norms: vec![1.0, 0.5, 0.25],
+                ..Default::default()
+            },
+            "NewtonRaphson".to_string(),
+        )
+    };
+    let error = ConstitutiveError::from(optimize_error());
+    match &error {
+        ConstitutiveError::Optimize(error) => {
+            assert_eq!(error.convergence().unwrap().residual_norms.len(), 3)
+        }
+        _ => panic!(),
+    }
+    assert!(error.source().is_some());
+    assert!(error == ConstitutiveError::Optimize(Box::new(optimize_error())));
+    assert!(
+        error
+            != ConstitutiveError::from(OptimizeError::NotMinimum(
+                Convergence::default(),
+                "NewtonRaphson".to_string()
+            ))
+    )
+}
+
+#[test]
+fn round_trip() {
+    let error = || {
+        ConstitutiveError::InvalidJacobian(
+            -1.0,
+            DeformationGradient::new([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]),
+            "NeoHookean".to_string(),
+        )
+    };
+    let optimize = || -> Result<(), OptimizeError> { Ok(Err(error())?) };
+    let constitutive = || -> Result<(), ConstitutiveError> { Ok(optimize()?) };
+    match optimize() {
+        Err(OptimizeError::Evaluation(source, steps)) => {
+            assert!(source.downcast_ref::<ConstitutiveError>() == Some(&error()));
+            assert_eq!(steps, 0)
+        }
+        _ => panic!(),
+    }
+    assert!(constitutive() == Err(error()))
+}
 
 #[test]
 fn size() {
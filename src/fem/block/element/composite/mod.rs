@@ -368,6 +368,65 @@ pub trait ViscoelasticCompositeElement<
             )
             .sum())
     }
+    fn calculate_nodal_stiffnesses_elastic_composite_element(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        Ok(self
+            .get_constitutive_models()
+            .iter()
+            .zip(
+                self.calculate_deformation_gradients(nodal_coordinates)
+                    .iter()
+                    .zip(
+                        self.calculate_deformation_gradient_rates(
+                            nodal_coordinates,
+                            nodal_velocities,
+                        )
+                        .iter(),
+                    ),
+            )
+            .map(
+                |(constitutive_model, (deformation_gradient, deformation_gradient_rate))| {
+                    constitutive_model.calculate_first_piola_kirchoff_tangent_stiffness(
+                        deformation_gradient,
+                        deformation_gradient_rate,
+                    )
+                },
+            )
+            .collect::<Result<FirstPiolaKirchoffTangentStiffnesses<G>, _>>()?
+            .iter()
+            .zip(
+                self.get_projected_gradient_vectors()
+                    .iter()
+                    .zip(self.get_integration_weights().iter()),
+            )
+            .map(
+                |(
+                    first_piola_kirchoff_tangent_stiffness,
+                    (projected_gradient_vectors, scaled_composite_jacobian),
+                )| {
+                    projected_gradient_vectors
+                        .iter()
+                        .map(|projected_gradient_vector_a| {
+                            projected_gradient_vectors
+                                .iter()
+                                .map(|projected_gradient_vector_b| {
+                                    first_piola_kirchoff_tangent_stiffness
+                                        .contract_second_fourth_indices_with_first_indices_of(
+                                            projected_gradient_vector_a,
+                                            projected_gradient_vector_b,
+                                        )
+                                        * scaled_composite_jacobian
+                                })
+                                .collect()
+                        })
+                        .collect()
+                },
+            )
+            .sum())
+    }
 }
 
 pub trait ElasticHyperviscousCompositeElement<
@@ -199,6 +199,33 @@ pub trait CompositeSurfaceElement<
             ).collect()
         }).collect()
     }
+    fn calculate_normal_rate_gradients(
+        nodal_coordinates: &Coordinates<1, O>,
+        nodal_velocities: &NodalVelocities<O>,
+    ) -> NormalGradientss<P, O> {
+        Self::calculate_normal_tangents(nodal_coordinates)
+            .iter()
+            .map(|normal_tangents| {
+                (0..O)
+                    .map(|b| {
+                        normal_tangents
+                            .iter()
+                            .zip(nodal_velocities.iter())
+                            .map(|(normal_tangent_a, nodal_velocity_a)| {
+                                normal_tangent_a[b]
+                                    .iter()
+                                    .zip(nodal_velocity_a.iter())
+                                    .map(|(normal_tangent_ab_m, nodal_velocity_a_m)| {
+                                        normal_tangent_ab_m * nodal_velocity_a_m
+                                    })
+                                    .sum::<TensorRank2<3, 1, 1>>()
+                            })
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
     fn calculate_normal_tangents(nodal_coordinates: &Coordinates<1, O>) -> NormalTangentss<P, O> {
         let levi_civita_symbol = LEVI_CIVITA;
         let mut normalization: Scalar = 0.0;
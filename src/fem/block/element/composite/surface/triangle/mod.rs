@@ -460,6 +460,116 @@ where
             ).collect()
         ).sum())
     }
+    fn calculate_nodal_stiffnesses_elastic(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        let deformation_gradients = self.calculate_deformation_gradients(nodal_coordinates);
+        let deformation_gradient_rates =
+            self.calculate_deformation_gradient_rates(nodal_coordinates, nodal_velocities);
+        let normal_tangentss = Self::calculate_normal_tangents(nodal_coordinates);
+        let objectss = self.calculate_objects(&Self::calculate_normal_gradients(nodal_coordinates));
+        let rate_objectss = self.calculate_objects(&Self::calculate_normal_rate_gradients(
+            nodal_coordinates,
+            nodal_velocities,
+        ));
+        let mut scaled_traction = ZERO_VECTOR;
+        Ok(self.get_constitutive_models().iter()
+        .zip(deformation_gradients.iter()
+        .zip(deformation_gradient_rates.iter()))
+        .map(|(constitutive_model, (deformation_gradient, deformation_gradient_rate))|
+            constitutive_model.calculate_first_piola_kirchoff_stress(deformation_gradient, deformation_gradient_rate)
+        ).collect::<Result<FirstPiolaKirchoffStresses<G>, _>>()?.iter()
+        .zip(self.get_constitutive_models().iter()
+        .zip(deformation_gradients.iter()
+        .zip(deformation_gradient_rates.iter()))
+        .map(|(constitutive_model, (deformation_gradient, deformation_gradient_rate))|
+            constitutive_model.calculate_first_piola_kirchoff_tangent_stiffness(deformation_gradient, deformation_gradient_rate)
+        ).collect::<Result<FirstPiolaKirchoffTangentStiffnesses<G>, _>>()?.iter()
+        .zip(self.get_constitutive_models().iter()
+        .zip(deformation_gradients.iter()
+        .zip(deformation_gradient_rates.iter()))
+        .map(|(constitutive_model, (deformation_gradient, deformation_gradient_rate))|
+            constitutive_model.calculate_first_piola_kirchoff_rate_tangent_stiffness(deformation_gradient, deformation_gradient_rate)
+        ).collect::<Result<FirstPiolaKirchoffRateTangentStiffnesses<G>, _>>()?.iter()
+        .zip(self.get_projected_gradient_vectors().iter()
+        .zip(self.get_integration_weights().iter()
+        .zip(self.get_scaled_reference_normals().iter()
+        .zip(objectss.iter()
+        .zip(rate_objectss.iter())))))))
+        .map(|(first_piola_kirchoff_stress, (first_piola_kirchoff_tangent_stiffness, (first_piola_kirchoff_rate_tangent_stiffness, (projected_gradient_vectors, (scaled_composite_jacobian, (scaled_reference_normals, (objects, rate_objects)))))))|
+            projected_gradient_vectors.iter()
+            .zip(objects.iter())
+            .map(|(projected_gradient_vector_a, object_a)|
+                projected_gradient_vectors.iter()
+                .zip(objects.iter()
+                .zip(rate_objects.iter()))
+                .map(|(projected_gradient_vector_b, (object_b, rate_object_b))|
+                    IDENTITY.iter()
+                    .zip(object_a.iter())
+                    .map(|(identity_m, object_a_m)|
+                        IDENTITY.iter()
+                        .zip(object_b.iter()
+                        .zip(rate_object_b.iter()))
+                        .map(|(identity_n, (object_b_n, rate_object_b_n))|
+                            first_piola_kirchoff_tangent_stiffness.iter()
+                            .zip(first_piola_kirchoff_rate_tangent_stiffness.iter()
+                            .zip(identity_m.iter()
+                            .zip(object_a_m.iter())))
+                            .map(|(first_piola_kirchoff_tangent_stiffness_i, (first_piola_kirchoff_rate_tangent_stiffness_i, (identity_mi, object_a_mi)))|
+                                first_piola_kirchoff_tangent_stiffness_i.iter()
+                                .zip(first_piola_kirchoff_rate_tangent_stiffness_i.iter()
+                                .zip(projected_gradient_vector_a.iter()
+                                .zip(object_a_mi.iter())))
+                                .map(|(first_piola_kirchoff_tangent_stiffness_ij, (first_piola_kirchoff_rate_tangent_stiffness_ij, (projected_gradient_vector_a_j, object_a_mij)))|
+                                    first_piola_kirchoff_tangent_stiffness_ij.iter()
+                                    .zip(first_piola_kirchoff_rate_tangent_stiffness_ij.iter()
+                                    .zip(identity_n.iter()
+                                    .zip(object_b_n.iter()
+                                    .zip(rate_object_b_n.iter()))))
+                                    .map(|(first_piola_kirchoff_tangent_stiffness_ijk, (first_piola_kirchoff_rate_tangent_stiffness_ijk, (identity_nk, (object_b_nk, rate_object_b_nk))))|
+                                        first_piola_kirchoff_tangent_stiffness_ijk.iter()
+                                        .zip(first_piola_kirchoff_rate_tangent_stiffness_ijk.iter()
+                                        .zip(projected_gradient_vector_b.iter()
+                                        .zip(object_b_nk.iter()
+                                        .zip(rate_object_b_nk.iter()))))
+                                        .map(|(first_piola_kirchoff_tangent_stiffness_ijkl, (first_piola_kirchoff_rate_tangent_stiffness_ijkl, (projected_gradient_vector_b_l, (object_b_nkl, rate_object_b_nkl))))|
+                                            (
+                                                first_piola_kirchoff_tangent_stiffness_ijkl * (
+                                                    identity_nk * projected_gradient_vector_b_l + object_b_nkl
+                                                ) + first_piola_kirchoff_rate_tangent_stiffness_ijkl * rate_object_b_nkl
+                                            ) * (
+                                                identity_mi * projected_gradient_vector_a_j + object_a_mij
+                                            ) * scaled_composite_jacobian
+                                        ).sum::<Scalar>()
+                                    ).sum::<Scalar>()
+                                ).sum::<Scalar>()
+                            ).sum::<Scalar>()
+                        ).collect()
+                    ).collect()
+                ).collect()
+            ).collect::<NodalStiffnesses<N>>() +
+            normal_tangentss.iter()
+            .zip(scaled_reference_normals.iter())
+            .map(|(normal_tangents, scaled_reference_normal)|{
+                scaled_traction = (first_piola_kirchoff_stress * scaled_reference_normal) * scaled_composite_jacobian;
+                normal_tangents.iter()
+                .map(|normal_tangent_a|
+                    normal_tangent_a.iter()
+                    .map(|normal_tangent_ab|
+                        normal_tangent_ab.iter()
+                        .map(|normal_tangent_ab_m|
+                            normal_tangent_ab_m.iter()
+                            .map(|normal_tangent_ab_mn|
+                                normal_tangent_ab_mn * &scaled_traction
+                            ).collect()
+                        ).collect()
+                    ).collect()
+                ).collect::<NodalStiffnesses<N>>()
+            }).sum::<NodalStiffnesses<N>>()
+        ).sum())
+    }
 }
 
 impl<'a, C> CompositeSurfaceElement<'a, C, G, M, N, O, P, Q> for Triangle<C>
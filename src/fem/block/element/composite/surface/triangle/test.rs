@@ -141,5 +141,29 @@ fn get_dirichlet_values(_x: Scalar) -> [Scalar; 10] {
     panic!()
 }
 
+fn get_dirichlet_places_constrained<'a>() -> [&'a [usize]; 0] {
+    panic!()
+}
+
+fn get_dirichlet_values_constrained(_x: Scalar) -> [Scalar; 0] {
+    panic!()
+}
+
+fn get_dirichlet_places_applied_force<'a>() -> [&'a [usize]; 0] {
+    panic!()
+}
+
+fn get_dirichlet_values_applied_force() -> [Scalar; 0] {
+    panic!()
+}
+
+fn get_neumann_places<'a>() -> [&'a [usize]; 0] {
+    panic!()
+}
+
+fn get_neumann_values(_force: Scalar) -> [Scalar; 0] {
+    panic!()
+}
+
 test_composite_surface_element!(Triangle);
 test_finite_element_block!(Triangle);
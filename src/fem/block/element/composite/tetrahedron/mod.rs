@@ -596,6 +596,16 @@ where
     ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
         self.calculate_nodal_stiffnesses_composite_element(nodal_coordinates, nodal_velocities)
     }
+    fn calculate_nodal_stiffnesses_elastic(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        self.calculate_nodal_stiffnesses_elastic_composite_element(
+            nodal_coordinates,
+            nodal_velocities,
+        )
+    }
 }
 
 impl<'a, C> ViscoelasticCompositeElement<'a, C, G, M, N, O, P, Q> for Tetrahedron<C> where
@@ -350,6 +350,111 @@ where
             ).collect()
         ).collect())
     }
+    fn calculate_nodal_stiffnesses_elastic(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        let deformation_gradient = self.calculate_deformation_gradient(nodal_coordinates);
+        let deformation_gradient_rate =
+            self.calculate_deformation_gradient_rate(nodal_coordinates, nodal_velocities);
+        let first_piola_kirchoff_stress = self
+            .get_constitutive_model()
+            .calculate_first_piola_kirchoff_stress(
+                &deformation_gradient,
+                &deformation_gradient_rate,
+            )?;
+        let first_piola_kirchoff_tangent_stiffness = self
+            .get_constitutive_model()
+            .calculate_first_piola_kirchoff_tangent_stiffness(
+                &deformation_gradient,
+                &deformation_gradient_rate,
+            )?;
+        let first_piola_kirchoff_rate_tangent_stiffness = self
+            .get_constitutive_model()
+            .calculate_first_piola_kirchoff_rate_tangent_stiffness(
+                &deformation_gradient,
+                &deformation_gradient_rate,
+            )?;
+        let gradient_vectors = self.get_gradient_vectors();
+        let midplane = Self::calculate_midplane(nodal_coordinates);
+        let normal_gradients = Self::calculate_normal_gradients(&midplane);
+        let normal_rate_gradients = Self::calculate_normal_rate_gradients(
+            &midplane,
+            &Self::calculate_midplane(nodal_velocities),
+        );
+        let normal_tangents = Self::calculate_normal_tangents(&midplane);
+        let reference_normal = self.get_reference_normal() * 0.5;
+        let traction = (first_piola_kirchoff_stress * &reference_normal) * 0.5;
+        Ok(gradient_vectors.iter()
+        .zip(normal_gradients.iter()
+        .chain(normal_gradients.iter()))
+        .map(|(gradient_vector_a, normal_gradient_a)|
+            gradient_vectors.iter()
+            .zip(normal_gradients.iter()
+            .chain(normal_gradients.iter())
+            .zip(normal_rate_gradients.iter()
+            .chain(normal_rate_gradients.iter())))
+            .map(|(gradient_vector_b, (normal_gradient_b, normal_rate_gradient_b))|
+                IDENTITY.iter()
+                .zip(normal_gradient_a.iter())
+                .map(|(identity_m, normal_gradient_a_m)|
+                    IDENTITY.iter()
+                    .zip(normal_gradient_b.iter()
+                    .zip(normal_rate_gradient_b.iter()))
+                    .map(|(identity_n, (normal_gradient_b_n, normal_rate_gradient_b_n))|
+                        first_piola_kirchoff_tangent_stiffness.iter()
+                        .zip(first_piola_kirchoff_rate_tangent_stiffness.iter()
+                        .zip(identity_m.iter()
+                        .zip(normal_gradient_a_m.iter())))
+                        .map(|(first_piola_kirchoff_tangent_stiffness_i, (first_piola_kirchoff_rate_tangent_stiffness_i, (identity_mi, normal_gradient_a_m_i)))|
+                            first_piola_kirchoff_tangent_stiffness_i.iter()
+                            .zip(first_piola_kirchoff_rate_tangent_stiffness_i.iter()
+                            .zip(gradient_vector_a.iter()
+                            .zip(reference_normal.iter())))
+                            .map(|(first_piola_kirchoff_tangent_stiffness_ij, (first_piola_kirchoff_rate_tangent_stiffness_ij, (gradient_vector_a_j, reference_normal_j)))|
+                                first_piola_kirchoff_tangent_stiffness_ij.iter()
+                                .zip(first_piola_kirchoff_rate_tangent_stiffness_ij.iter()
+                                .zip(identity_n.iter()
+                                .zip(normal_gradient_b_n.iter()
+                                .zip(normal_rate_gradient_b_n.iter()))))
+                                .map(|(first_piola_kirchoff_tangent_stiffness_ijk, (first_piola_kirchoff_rate_tangent_stiffness_ijk, (identity_nk, (normal_gradient_b_n_k, normal_rate_gradient_b_n_k))))|
+                                    first_piola_kirchoff_tangent_stiffness_ijk.iter()
+                                    .zip(first_piola_kirchoff_rate_tangent_stiffness_ijk.iter()
+                                    .zip(gradient_vector_b.iter()
+                                    .zip(reference_normal.iter())))
+                                    .map(|(first_piola_kirchoff_tangent_stiffness_ijkl, (first_piola_kirchoff_rate_tangent_stiffness_ijkl, (gradient_vector_b_l, reference_normal_l)))|
+                                        (
+                                            first_piola_kirchoff_tangent_stiffness_ijkl * (
+                                                identity_nk * gradient_vector_b_l + normal_gradient_b_n_k * reference_normal_l
+                                            ) + first_piola_kirchoff_rate_tangent_stiffness_ijkl * normal_rate_gradient_b_n_k * reference_normal_l
+                                        ) * (
+                                            identity_mi * gradient_vector_a_j + normal_gradient_a_m_i * reference_normal_j
+                                        ) * self.get_integration_weight()
+                                    ).sum::<Scalar>()
+                                ).sum::<Scalar>()
+                            ).sum::<Scalar>()
+                        ).sum::<Scalar>()
+                    ).collect()
+                ).collect()
+            ).collect()
+        ).collect::<NodalStiffnesses<N>>() +
+        normal_tangents.iter()
+        .chain(normal_tangents.iter())
+        .map(|normal_tangent_a|
+            normal_tangent_a.iter()
+            .chain(normal_tangent_a.iter())
+            .map(|normal_tangent_ab|
+                normal_tangent_ab.iter()
+                .map(|normal_tangent_ab_m|
+                    normal_tangent_ab_m.iter()
+                    .map(|normal_tangent_ab_mn|
+                        (normal_tangent_ab_mn * &traction) * self.get_integration_weight()
+                    ).collect()
+                ).collect()
+            ).collect()
+        ).collect::<NodalStiffnesses<N>>())
+    }
 }
 
 impl<'a, C> LinearSurfaceElement<'a, C, G, M, N, O> for Wedge<C>
@@ -189,6 +189,35 @@ pub trait ViscoelasticLinearElement<
             })
             .collect())
     }
+    fn calculate_nodal_stiffnesses_elastic_linear_element(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        let first_piola_kirchoff_tangent_stiffness = self
+            .get_constitutive_model()
+            .calculate_first_piola_kirchoff_tangent_stiffness(
+                &self.calculate_deformation_gradient(nodal_coordinates),
+                &self.calculate_deformation_gradient_rate(nodal_coordinates, nodal_velocities),
+            )?;
+        let gradient_vectors = self.get_gradient_vectors();
+        Ok(gradient_vectors
+            .iter()
+            .map(|gradient_vector_a| {
+                gradient_vectors
+                    .iter()
+                    .map(|gradient_vector_b| {
+                        first_piola_kirchoff_tangent_stiffness
+                            .contract_second_fourth_indices_with_first_indices_of(
+                                gradient_vector_a,
+                                gradient_vector_b,
+                            )
+                            * self.get_integration_weight()
+                    })
+                    .collect()
+            })
+            .collect())
+    }
 }
 
 pub trait ElasticHyperviscousLinearElement<
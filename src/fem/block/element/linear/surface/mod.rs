@@ -174,6 +174,29 @@ pub trait LinearSurfaceElement<
             ).sum::<Scalar>() / normalization
         ).collect()
     }
+    fn calculate_normal_rate_gradients(
+        nodal_coordinates: &Coordinates<1, O>,
+        nodal_velocities: &NodalVelocities<O>,
+    ) -> NormalGradients<O> {
+        let normal_tangents = Self::calculate_normal_tangents(nodal_coordinates);
+        (0..O)
+            .map(|b| {
+                normal_tangents
+                    .iter()
+                    .zip(nodal_velocities.iter())
+                    .map(|(normal_tangent_a, nodal_velocity_a)| {
+                        normal_tangent_a[b]
+                            .iter()
+                            .zip(nodal_velocity_a.iter())
+                            .map(|(normal_tangent_ab_m, nodal_velocity_a_m)| {
+                                normal_tangent_ab_m * nodal_velocity_a_m
+                            })
+                            .sum::<TensorRank2<3, 1, 1>>()
+                    })
+                    .sum()
+            })
+            .collect()
+    }
     fn calculate_normal_tangents(nodal_coordinates: &Coordinates<1, O>) -> NormalTangents<O> {
         let basis_vectors = Self::calculate_basis(nodal_coordinates);
         let levi_civita_symbol = LEVI_CIVITA;
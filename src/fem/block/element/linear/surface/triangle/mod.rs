@@ -206,6 +206,72 @@ where
             ).collect()
         ).collect())
     }
+    fn calculate_nodal_stiffnesses_elastic(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        let deformation_gradient = self.calculate_deformation_gradient(nodal_coordinates);
+        let deformation_gradient_rate =
+            self.calculate_deformation_gradient_rate(nodal_coordinates, nodal_velocities);
+        let first_piola_kirchoff_tangent_stiffness = self
+            .get_constitutive_model()
+            .calculate_first_piola_kirchoff_tangent_stiffness(
+                &deformation_gradient,
+                &deformation_gradient_rate,
+            )?;
+        let first_piola_kirchoff_rate_tangent_stiffness = self
+            .get_constitutive_model()
+            .calculate_first_piola_kirchoff_rate_tangent_stiffness(
+                &deformation_gradient,
+                &deformation_gradient_rate,
+            )?;
+        let gradient_vectors = self.get_gradient_vectors();
+        let normal_gradients = Self::calculate_normal_gradients(nodal_coordinates);
+        let normal_rate_gradients =
+            Self::calculate_normal_rate_gradients(nodal_coordinates, nodal_velocities);
+        let reference_normal = self.get_reference_normal();
+        Ok(gradient_vectors.iter()
+        .map(|gradient_vector_a|
+            gradient_vectors.iter()
+            .zip(normal_gradients.iter()
+            .zip(normal_rate_gradients.iter()))
+            .map(|(gradient_vector_b, (normal_gradient_b, normal_rate_gradient_b))|
+                first_piola_kirchoff_tangent_stiffness.iter()
+                .zip(first_piola_kirchoff_rate_tangent_stiffness.iter())
+                .map(|(first_piola_kirchoff_tangent_stiffness_m, first_piola_kirchoff_rate_tangent_stiffness_m)|
+                    IDENTITY.iter()
+                    .zip(normal_gradient_b.iter()
+                    .zip(normal_rate_gradient_b.iter()))
+                    .map(|(identity_n, (normal_gradient_b_n, normal_rate_gradient_b_n))|
+                        first_piola_kirchoff_tangent_stiffness_m.iter()
+                        .zip(first_piola_kirchoff_rate_tangent_stiffness_m.iter()
+                        .zip(gradient_vector_a.iter()))
+                        .map(|(first_piola_kirchoff_tangent_stiffness_mj, (first_piola_kirchoff_rate_tangent_stiffness_mj, gradient_vector_a_j))|
+                            first_piola_kirchoff_tangent_stiffness_mj.iter()
+                            .zip(first_piola_kirchoff_rate_tangent_stiffness_mj.iter()
+                            .zip(identity_n.iter()
+                            .zip(normal_gradient_b_n.iter()
+                            .zip(normal_rate_gradient_b_n.iter()))))
+                            .map(|(first_piola_kirchoff_tangent_stiffness_mjk, (first_piola_kirchoff_rate_tangent_stiffness_mjk, (identity_nk, (normal_gradient_b_n_k, normal_rate_gradient_b_n_k))))|
+                                first_piola_kirchoff_tangent_stiffness_mjk.iter()
+                                .zip(first_piola_kirchoff_rate_tangent_stiffness_mjk.iter()
+                                .zip(gradient_vector_b.iter()
+                                .zip(reference_normal.iter())))
+                                .map(|(first_piola_kirchoff_tangent_stiffness_mjkl, (first_piola_kirchoff_rate_tangent_stiffness_mjkl, (gradient_vector_b_l, reference_normal_l)))|
+                                    (
+                                        first_piola_kirchoff_tangent_stiffness_mjkl * (
+                                            identity_nk * gradient_vector_b_l + normal_gradient_b_n_k * reference_normal_l
+                                        ) + first_piola_kirchoff_rate_tangent_stiffness_mjkl * normal_rate_gradient_b_n_k * reference_normal_l
+                                    ) * gradient_vector_a_j * self.get_integration_weight()
+                                ).sum::<Scalar>()
+                            ).sum::<Scalar>()
+                        ).sum::<Scalar>()
+                    ).collect()
+                ).collect()
+            ).collect()
+        ).collect())
+    }
 }
 
 impl<'a, C> LinearSurfaceElement<'a, C, G, M, N, O> for Triangle<C>
@@ -124,6 +124,13 @@ where
     ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
         self.calculate_nodal_stiffnesses_linear_element(nodal_coordinates, nodal_velocities)
     }
+    fn calculate_nodal_stiffnesses_elastic(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError> {
+        self.calculate_nodal_stiffnesses_elastic_linear_element(nodal_coordinates, nodal_velocities)
+    }
 }
 impl<'a, C> ViscoelasticLinearElement<'a, C, G, M, N, O> for Tetrahedron<C> where C: Viscoelastic<'a>
 {}
@@ -142,5 +142,75 @@ fn get_dirichlet_values(x: Scalar) -> [Scalar; 10] {
     ]
 }
 
+fn get_dirichlet_places_constrained<'a>() -> [&'a [usize]; 14] {
+    [
+        &[0, 0],
+        &[1, 0],
+        &[2, 0],
+        &[3, 0],
+        &[4, 0],
+        &[5, 0],
+        &[6, 0],
+        &[7, 0],
+        &[11, 0],
+        &[13, 0],
+        &[3, 1],
+        &[6, 1],
+        &[6, 2],
+        &[7, 2],
+    ]
+}
+
+fn get_dirichlet_values_constrained(x: Scalar) -> [Scalar; 14] {
+    [
+        0.5 + x,
+        0.5 + x,
+        -0.5,
+        -0.5,
+        0.5 + x,
+        0.5 + x,
+        -0.5,
+        -0.5,
+        -0.5,
+        0.5 + x,
+        -0.5,
+        -0.5,
+        -0.5,
+        -0.5,
+    ]
+}
+
+fn get_dirichlet_places_applied_force<'a>() -> [&'a [usize]; 9] {
+    [
+        &[2, 0],
+        &[3, 0],
+        &[6, 0],
+        &[7, 0],
+        &[11, 0],
+        &[3, 1],
+        &[6, 1],
+        &[6, 2],
+        &[7, 2],
+    ]
+}
+
+fn get_dirichlet_values_applied_force() -> [Scalar; 9] {
+    [-0.5; 9]
+}
+
+fn get_neumann_places<'a>() -> [&'a [usize]; 5] {
+    [&[0, 0], &[1, 0], &[4, 0], &[5, 0], &[13, 0]]
+}
+
+fn get_neumann_values(force: Scalar) -> [Scalar; 5] {
+    [
+        force / 6.0,
+        force / 6.0,
+        force / 6.0,
+        force / 6.0,
+        force / 3.0,
+    ]
+}
+
 test_linear_element!(Tetrahedron);
 test_finite_element_block!(Tetrahedron);
@@ -87,6 +87,12 @@ where
         nodal_coordinates: &NodalCoordinates<N>,
         nodal_velocities: &NodalVelocities<N>,
     ) -> Result<NodalStiffnesses<N>, ConstitutiveError>;
+    /// Calculates and returns the derivatives of the nodal forces with respect to the nodal coordinates at fixed nodal velocities.
+    fn calculate_nodal_stiffnesses_elastic(
+        &self,
+        nodal_coordinates: &NodalCoordinates<N>,
+        nodal_velocities: &NodalVelocities<N>,
+    ) -> Result<NodalStiffnesses<N>, ConstitutiveError>;
 }
 
 pub trait ElasticHyperviscousFiniteElement<'a, C, const G: usize, const N: usize>
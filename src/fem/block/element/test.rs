@@ -903,6 +903,72 @@ macro_rules! test_finite_element_with_viscoelastic_constitutive_model {
                 })
                 .collect()
         }
+        fn get_finite_difference_of_nodal_forces_elastic(
+            is_deformed: bool,
+        ) -> Result<NodalStiffnesses<N>, TestError> {
+            let element = get_element();
+            let mut finite_difference = 0.0;
+            (0..N)
+                .map(|a| {
+                    (0..N)
+                        .map(|b| {
+                            (0..3)
+                                .map(|i| {
+                                    (0..3)
+                                        .map(|j| {
+                                            let mut nodal_coordinates = if is_deformed {
+                                                get_coordinates()
+                                            } else {
+                                                get_reference_coordinates().into()
+                                            };
+                                            let nodal_velocities = if is_deformed {
+                                                get_velocities()
+                                            } else {
+                                                NodalVelocities::zero()
+                                            };
+                                            nodal_coordinates[b][j] += 0.5 * EPSILON;
+                                            finite_difference = element.calculate_nodal_forces(
+                                                &nodal_coordinates,
+                                                &nodal_velocities,
+                                            )?[a][i];
+                                            nodal_coordinates[b][j] -= EPSILON;
+                                            finite_difference -= element.calculate_nodal_forces(
+                                                &nodal_coordinates,
+                                                &nodal_velocities,
+                                            )?[a][i];
+                                            Ok(finite_difference / EPSILON)
+                                        })
+                                        .collect()
+                                })
+                                .collect()
+                        })
+                        .collect()
+                })
+                .collect()
+        }
+        mod nodal_stiffnesses_elastic {
+            use super::*;
+            #[test]
+            fn finite_difference_deformed() -> Result<(), TestError> {
+                assert_eq_from_fd(
+                    &get_element().calculate_nodal_stiffnesses_elastic(
+                        &get_coordinates(),
+                        &get_velocities(),
+                    )?,
+                    &get_finite_difference_of_nodal_forces_elastic(true)?,
+                )
+            }
+            #[test]
+            fn finite_difference_undeformed() -> Result<(), TestError> {
+                assert_eq_from_fd(
+                    &get_element().calculate_nodal_stiffnesses_elastic(
+                        &get_reference_coordinates().into(),
+                        &NodalVelocities::zero(),
+                    )?,
+                    &get_finite_difference_of_nodal_forces_elastic(false)?,
+                )
+            }
+        }
         crate::fem::block::element::test::test_nodal_forces_and_nodal_stiffnesses!(
             $element,
             $constitutive_model,
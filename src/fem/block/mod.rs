@@ -9,7 +9,10 @@ use self::element::{
     ViscoelasticFiniteElement,
 };
 use super::*;
-use crate::math::optimize::{Dirichlet, FirstOrder, GradientDescent, OptimizeError};
+use crate::math::optimize::{
+    ArcLength, Dirichlet, EquilibriumPath, FirstOrder, Neumann, Optimization, OptimizeError,
+    SecondOrder,
+};
 use std::array::from_fn;
 
 pub struct ElasticBlock<const E: usize, F, const N: usize> {
@@ -86,8 +89,17 @@ where
         values_d: Option<&[Scalar]>,
         places_n: Option<&[&[usize]]>,
         values_n: Option<&[Scalar]>,
-        optimization: GradientDescent,
+        optimization: Optimization,
     ) -> Result<NodalCoordinatesBlock, OptimizeError>;
+    fn solve_arc_length(
+        &self,
+        initial_coordinates: NodalCoordinatesBlock,
+        places_d: Option<&[&[usize]]>,
+        values_d: Option<&[Scalar]>,
+        places_n: &[&[usize]],
+        values_n: &[Scalar],
+        arc_length: ArcLength,
+    ) -> Result<EquilibriumPath<NodalCoordinatesBlock>, OptimizeError>;
 }
 
 pub trait HyperelasticFiniteElementBlock<'a, C, const E: usize, F, const G: usize, const N: usize>
@@ -100,6 +112,15 @@ where
         &self,
         nodal_coordinates: &NodalCoordinatesBlock,
     ) -> Result<Scalar, ConstitutiveError>;
+    fn minimize(
+        &self,
+        initial_coordinates: NodalCoordinatesBlock,
+        places_d: Option<&[&[usize]]>,
+        values_d: Option<&[Scalar]>,
+        places_n: Option<&[&[usize]]>,
+        values_n: Option<&[Scalar]>,
+        optimization: Optimization,
+    ) -> Result<(NodalCoordinatesBlock, Scalar), OptimizeError>;
 }
 
 pub trait ViscoelasticFiniteElementBlock<'a, C, const E: usize, F, const G: usize, const N: usize>
@@ -122,6 +143,23 @@ where
         element_connectivity: &[usize; N],
         nodal_velocities: &NodalVelocitiesBlock,
     ) -> NodalVelocities<N>;
+    fn calculate_nodal_stiffnesses_time_step(
+        &self,
+        nodal_coordinates: &NodalCoordinatesBlock,
+        nodal_velocities: &NodalVelocitiesBlock,
+        time_step: Scalar,
+    ) -> Result<NodalStiffnessesBlock, ConstitutiveError>;
+    #[allow(clippy::too_many_arguments)]
+    fn solve<const W: usize>(
+        &self,
+        initial_coordinates: NodalCoordinatesBlock,
+        places_d: Option<&[&[usize]]>,
+        values_d: Option<impl Fn(Scalar) -> Vec<Scalar>>,
+        places_n: Option<&[&[usize]]>,
+        values_n: Option<&[Scalar]>,
+        evaluation_times: &TensorRank0List<W>,
+        optimization: Optimization,
+    ) -> Result<NodalCoordinatesHistory, OptimizeError>;
 }
 
 pub trait ElasticHyperviscousFiniteElementBlock<
@@ -295,25 +333,24 @@ where
         &self,
         nodal_coordinates: &NodalCoordinatesBlock,
     ) -> Result<NodalStiffnessesBlock, ConstitutiveError> {
-        let mut nodal_stiffnesses = NodalStiffnessesBlock::zero(nodal_coordinates.len());
+        let mut nodal_stiffnesses = NodalStiffnessesBlock::from_connectivity(
+            self.get_connectivity(),
+            nodal_coordinates.len(),
+            3,
+        );
         self.get_elements()
             .iter()
             .zip(self.get_connectivity().iter())
             .try_for_each(|(element, element_connectivity)| {
-                element
-                    .calculate_nodal_stiffnesses(&self.calculate_nodal_coordinates_element(
-                        element_connectivity,
-                        nodal_coordinates,
-                    ))?
-                    .iter()
-                    .zip(element_connectivity.iter())
-                    .for_each(|(object, node_a)| {
-                        object.iter().zip(element_connectivity.iter()).for_each(
-                            |(nodal_stiffness, node_b)| {
-                                nodal_stiffnesses[*node_a][*node_b] += nodal_stiffness
-                            },
-                        )
-                    });
+                nodal_stiffnesses.assemble(
+                    element_connectivity,
+                    &element.calculate_nodal_stiffnesses(
+                        &self.calculate_nodal_coordinates_element(
+                            element_connectivity,
+                            nodal_coordinates,
+                        ),
+                    )?,
+                );
                 Ok::<(), ConstitutiveError>(())
             })?;
         Ok(nodal_stiffnesses)
@@ -323,20 +360,61 @@ where
         initial_coordinates: NodalCoordinatesBlock,
         places_d: Option<&[&[usize]]>,
         values_d: Option<&[Scalar]>,
-        _places_n: Option<&[&[usize]]>,
-        _values_n: Option<&[Scalar]>,
-        optimization: GradientDescent,
+        places_n: Option<&[&[usize]]>,
+        values_n: Option<&[Scalar]>,
+        optimization: Optimization,
     ) -> Result<NodalCoordinatesBlock, OptimizeError> {
-        optimization.minimize(
+        let dirichlet = places_d
+            .zip(values_d)
+            .map(|(places, values)| Dirichlet { places, values });
+        let neumann = places_n
+            .zip(values_n)
+            .map(|(places, values)| Neumann { places, values });
+        let function = |nodal_coordinates: &NodalCoordinatesBlock| {
+            Ok(self.calculate_nodal_forces(nodal_coordinates)?)
+        };
+        let jacobian = |nodal_coordinates: &NodalCoordinatesBlock| {
+            Ok(self.calculate_nodal_stiffnesses(nodal_coordinates)?)
+        };
+        match optimization {
+            Optimization::ConjugateGradient(conjugate_gradient) => {
+                conjugate_gradient.minimize(function, initial_coordinates, dirichlet, neumann)
+            }
+            Optimization::GradientDescent(gradient_descent) => {
+                gradient_descent.minimize(function, initial_coordinates, dirichlet, neumann)
+            }
+            Optimization::LimitedMemoryBfgs(limited_memory_bfgs) => {
+                limited_memory_bfgs.minimize(function, initial_coordinates, dirichlet, neumann)
+            }
+            Optimization::NewtonRaphson(newton_raphson) => {
+                newton_raphson.minimize(function, jacobian, initial_coordinates, dirichlet, neumann)
+            }
+        }
+    }
+    fn solve_arc_length(
+        &self,
+        initial_coordinates: NodalCoordinatesBlock,
+        places_d: Option<&[&[usize]]>,
+        values_d: Option<&[Scalar]>,
+        places_n: &[&[usize]],
+        values_n: &[Scalar],
+        arc_length: ArcLength,
+    ) -> Result<EquilibriumPath<NodalCoordinatesBlock>, OptimizeError> {
+        arc_length.trace(
             |nodal_coordinates: &NodalCoordinatesBlock| {
                 Ok(self.calculate_nodal_forces(nodal_coordinates)?)
             },
+            |nodal_coordinates: &NodalCoordinatesBlock| {
+                Ok(self.calculate_nodal_stiffnesses(nodal_coordinates)?)
+            },
             initial_coordinates,
-            Some(Dirichlet {
-                places: places_d.unwrap(),
-                values: values_d.unwrap(),
-            }),
-            None,
+            places_d
+                .zip(values_d)
+                .map(|(places, values)| Dirichlet { places, values }),
+            Neumann {
+                places: places_n,
+                values: values_n,
+            },
         )
     }
 }
@@ -365,6 +443,65 @@ where
             })
             .sum()
     }
+    fn minimize(
+        &self,
+        initial_coordinates: NodalCoordinatesBlock,
+        places_d: Option<&[&[usize]]>,
+        values_d: Option<&[Scalar]>,
+        places_n: Option<&[&[usize]]>,
+        values_n: Option<&[Scalar]>,
+        optimization: Optimization,
+    ) -> Result<(NodalCoordinatesBlock, Scalar), OptimizeError> {
+        let dirichlet = places_d
+            .zip(values_d)
+            .map(|(places, values)| Dirichlet { places, values });
+        let neumann = places_n
+            .zip(values_n)
+            .map(|(places, values)| Neumann { places, values });
+        let objective = |nodal_coordinates: &NodalCoordinatesBlock| {
+            Ok(self.calculate_helmholtz_free_energy(nodal_coordinates)?)
+        };
+        let function = |nodal_coordinates: &NodalCoordinatesBlock| {
+            Ok(self.calculate_nodal_forces(nodal_coordinates)?)
+        };
+        let jacobian = |nodal_coordinates: &NodalCoordinatesBlock| {
+            Ok(self.calculate_nodal_stiffnesses(nodal_coordinates)?)
+        };
+        match optimization {
+            Optimization::ConjugateGradient(conjugate_gradient) => conjugate_gradient
+                .minimize_with_objective(
+                    objective,
+                    function,
+                    initial_coordinates,
+                    dirichlet,
+                    neumann,
+                ),
+            Optimization::GradientDescent(gradient_descent) => gradient_descent
+                .minimize_with_objective(
+                    objective,
+                    function,
+                    initial_coordinates,
+                    dirichlet,
+                    neumann,
+                ),
+            Optimization::LimitedMemoryBfgs(limited_memory_bfgs) => limited_memory_bfgs
+                .minimize_with_objective(
+                    objective,
+                    function,
+                    initial_coordinates,
+                    dirichlet,
+                    neumann,
+                ),
+            Optimization::NewtonRaphson(newton_raphson) => newton_raphson.minimize_with_objective(
+                objective,
+                function,
+                jacobian,
+                initial_coordinates,
+                dirichlet,
+                neumann,
+            ),
+        }
+    }
 }
 
 impl<'a, C, const E: usize, F, const G: usize, const N: usize>
@@ -487,13 +624,18 @@ where
         nodal_coordinates: &NodalCoordinatesBlock,
         nodal_velocities: &NodalVelocitiesBlock,
     ) -> Result<NodalStiffnessesBlock, ConstitutiveError> {
-        let mut nodal_stiffnesses = NodalStiffnessesBlock::zero(nodal_coordinates.len());
+        let mut nodal_stiffnesses = NodalStiffnessesBlock::from_connectivity(
+            self.get_connectivity(),
+            nodal_coordinates.len(),
+            3,
+        );
         self.get_elements()
             .iter()
             .zip(self.get_connectivity().iter())
             .try_for_each(|(element, element_connectivity)| {
-                element
-                    .calculate_nodal_stiffnesses(
+                nodal_stiffnesses.assemble(
+                    element_connectivity,
+                    &element.calculate_nodal_stiffnesses(
                         &self.calculate_nodal_coordinates_element(
                             element_connectivity,
                             nodal_coordinates,
@@ -502,16 +644,8 @@ where
                             element_connectivity,
                             nodal_velocities,
                         ),
-                    )?
-                    .iter()
-                    .zip(element_connectivity.iter())
-                    .for_each(|(object, node_a)| {
-                        object.iter().zip(element_connectivity.iter()).for_each(
-                            |(nodal_stiffness, node_b)| {
-                                nodal_stiffnesses[*node_a][*node_b] += nodal_stiffness
-                            },
-                        )
-                    });
+                    )?,
+                );
                 Ok::<(), ConstitutiveError>(())
             })?;
         Ok(nodal_stiffnesses)
@@ -526,6 +660,104 @@ where
             .map(|node| nodal_velocities[*node].copy())
             .collect()
     }
+    fn calculate_nodal_stiffnesses_time_step(
+        &self,
+        nodal_coordinates: &NodalCoordinatesBlock,
+        nodal_velocities: &NodalVelocitiesBlock,
+        time_step: Scalar,
+    ) -> Result<NodalStiffnessesBlock, ConstitutiveError> {
+        let mut nodal_stiffnesses = NodalStiffnessesBlock::from_connectivity(
+            self.get_connectivity(),
+            nodal_coordinates.len(),
+            3,
+        );
+        self.get_elements()
+            .iter()
+            .zip(self.get_connectivity().iter())
+            .try_for_each(|(element, element_connectivity)| {
+                let nodal_coordinates_element = self
+                    .calculate_nodal_coordinates_element(element_connectivity, nodal_coordinates);
+                let nodal_velocities_element =
+                    self.calculate_nodal_velocities_element(element_connectivity, nodal_velocities);
+                nodal_stiffnesses.assemble(
+                    element_connectivity,
+                    &(element.calculate_nodal_stiffnesses(
+                        &nodal_coordinates_element,
+                        &nodal_velocities_element,
+                    )? + element.calculate_nodal_stiffnesses_elastic(
+                        &nodal_coordinates_element,
+                        &nodal_velocities_element,
+                    )? * time_step),
+                );
+                Ok::<(), ConstitutiveError>(())
+            })?;
+        Ok(nodal_stiffnesses)
+    }
+    fn solve<const W: usize>(
+        &self,
+        initial_coordinates: NodalCoordinatesBlock,
+        places_d: Option<&[&[usize]]>,
+        values_d: Option<impl Fn(Scalar) -> Vec<Scalar>>,
+        places_n: Option<&[&[usize]]>,
+        values_n: Option<&[Scalar]>,
+        evaluation_times: &TensorRank0List<W>,
+        optimization: Optimization,
+    ) -> Result<NodalCoordinatesHistory, OptimizeError> {
+        let mut nodal_coordinates_history = vec![initial_coordinates];
+        let mut nodal_velocities = NodalVelocitiesBlock::zero(nodal_coordinates_history[0].len());
+        for time in evaluation_times.0.windows(2) {
+            let time_step = time[1] - time[0];
+            let nodal_coordinates = nodal_coordinates_history
+                .last()
+                .expect("History always contains the initial coordinates.");
+            let velocities_d: Option<Vec<Scalar>> =
+                places_d.zip(values_d.as_ref()).map(|(places, values)| {
+                    places
+                        .iter()
+                        .zip(values(time[1]).iter())
+                        .map(|(place, value)| (value - nodal_coordinates.get_at(place)) / time_step)
+                        .collect()
+                });
+            let dirichlet = places_d
+                .zip(velocities_d.as_deref())
+                .map(|(places, values)| Dirichlet { places, values });
+            let neumann = places_n
+                .zip(values_n)
+                .map(|(places, values)| Neumann { places, values });
+            let function = |nodal_velocities: &NodalVelocitiesBlock| {
+                Ok(self.calculate_nodal_forces(
+                    &(nodal_velocities * &time_step + nodal_coordinates),
+                    nodal_velocities,
+                )?)
+            };
+            let jacobian = |nodal_velocities: &NodalVelocitiesBlock| {
+                Ok(self.calculate_nodal_stiffnesses_time_step(
+                    &(nodal_velocities * &time_step + nodal_coordinates),
+                    nodal_velocities,
+                    time_step,
+                )?)
+            };
+            nodal_velocities =
+                match &optimization {
+                    Optimization::ConjugateGradient(conjugate_gradient) => conjugate_gradient
+                        .minimize(function, nodal_velocities, dirichlet, neumann)?,
+                    Optimization::GradientDescent(gradient_descent) => {
+                        gradient_descent.minimize(function, nodal_velocities, dirichlet, neumann)?
+                    }
+                    Optimization::LimitedMemoryBfgs(limited_memory_bfgs) => limited_memory_bfgs
+                        .minimize(function, nodal_velocities, dirichlet, neumann)?,
+                    Optimization::NewtonRaphson(newton_raphson) => newton_raphson.minimize(
+                        function,
+                        jacobian,
+                        nodal_velocities,
+                        dirichlet,
+                        neumann,
+                    )?,
+                };
+            nodal_coordinates_history.push(&nodal_velocities * &time_step + nodal_coordinates);
+        }
+        Ok(nodal_coordinates_history)
+    }
 }
 
 impl<'a, C, const E: usize, F, const G: usize, const N: usize>
@@ -11,7 +11,7 @@ macro_rules! test_finite_element_block {
                 },
                 math::{
                     test::{assert_eq, assert_eq_from_fd, assert_eq_within_tols, TestError},
-                    TensorRank2,
+                    TensorRank2, TensorRank2Vec2D,
                 },
                 mechanics::test::{
                     get_rotation_current_configuration, get_rotation_rate_current_configuration,
@@ -541,7 +541,7 @@ macro_rules! test_finite_element_block_with_elastic_constitutive_model {
     ($block: ident, $element: ident, $constitutive_model: ident, $constitutive_model_parameters: ident) => {
         fn get_finite_difference_of_nodal_forces(
             is_deformed: bool,
-        ) -> Result<NodalStiffnessesBlock, TestError> {
+        ) -> Result<TensorRank2Vec2D<3, 1, 1>, TestError> {
             let block = get_block();
             let mut finite_difference = 0.0;
             (0..D)
@@ -609,28 +609,35 @@ macro_rules! test_finite_element_block_with_elastic_constitutive_model {
         fn get_nodal_stiffnesses(
             is_deformed: bool,
             is_rotated: bool,
-        ) -> Result<NodalStiffnessesBlock, TestError> {
+        ) -> Result<TensorRank2Vec2D<3, 1, 1>, TestError> {
             if is_rotated {
                 if is_deformed {
                     Ok(get_rotation_current_configuration().transpose()
-                        * get_block_transformed()
-                            .calculate_nodal_stiffnesses(&get_coordinates_transformed_block())?
+                        * TensorRank2Vec2D::from(
+                            get_block_transformed()
+                                .calculate_nodal_stiffnesses(&get_coordinates_transformed_block())?,
+                        )
                         * get_rotation_current_configuration())
                 } else {
                     let converted: TensorRank2<3, 1, 1> =
                         get_rotation_reference_configuration().into();
                     Ok(converted.transpose()
-                        * get_block_transformed().calculate_nodal_stiffnesses(
-                            &get_reference_coordinates_transformed_block().into(),
-                        )?
+                        * TensorRank2Vec2D::from(
+                            get_block_transformed().calculate_nodal_stiffnesses(
+                                &get_reference_coordinates_transformed_block().into(),
+                            )?,
+                        )
                         * converted)
                 }
             } else {
                 if is_deformed {
-                    Ok(get_block().calculate_nodal_stiffnesses(&get_coordinates_block())?)
+                    Ok(get_block()
+                        .calculate_nodal_stiffnesses(&get_coordinates_block())?
+                        .into())
                 } else {
                     Ok(get_block()
-                        .calculate_nodal_stiffnesses(&get_reference_coordinates_block().into())?)
+                        .calculate_nodal_stiffnesses(&get_reference_coordinates_block().into())?
+                        .into())
                 }
             }
         }
@@ -658,21 +665,31 @@ macro_rules! test_finite_element_block_with_hyperelastic_constitutive_model {
             $constitutive_model,
             $constitutive_model_parameters
         );
-        use crate::constitutive::solid::AppliedLoad;
-        #[test]
-        fn solve() -> Result<(), TestError> {
+        use crate::{
+            constitutive::solid::AppliedLoad,
+            math::{
+                optimize::{
+                    ArcLength, ConjugateGradient, GradientDescent, LimitedMemoryBfgs, LinearSolver,
+                    NewtonRaphson, Optimization,
+                },
+                IterativeMethod, IterativeSolver, Preconditioner,
+            },
+        };
+        fn solve<const N: usize>(
+            dirichlet_places: fn() -> [&'static [usize]; N],
+            dirichlet_values: fn(Scalar) -> [Scalar; N],
+            optimization: Optimization,
+        ) -> Result<(), TestError> {
             if TEST_SOLVE {
                 let dx = 0.23;
                 let block = get_block();
                 let solution = block.solve(
                     get_reference_coordinates_block().into(),
-                    Some(&get_dirichlet_places()),
-                    Some(&get_dirichlet_values(dx)),
+                    Some(&dirichlet_places()),
+                    Some(&dirichlet_values(dx)),
                     None,
                     None,
-                    GradientDescent {
-                        ..Default::default()
-                    },
+                    optimization,
                 )?;
                 let (deformation_gradient, _) =
                     $constitutive_model::new($constitutive_model_parameters)
@@ -691,6 +708,238 @@ macro_rules! test_finite_element_block_with_hyperelastic_constitutive_model {
                 Ok(())
             }
         }
+        fn minimize<const N: usize>(
+            dirichlet_places: fn() -> [&'static [usize]; N],
+            dirichlet_values: fn(Scalar) -> [Scalar; N],
+            optimization: Optimization,
+        ) -> Result<(), TestError> {
+            if TEST_SOLVE {
+                let dx = 0.23;
+                let block = get_block();
+                let (solution, helmholtz_free_energy) = block.minimize(
+                    get_reference_coordinates_block().into(),
+                    Some(&dirichlet_places()),
+                    Some(&dirichlet_values(dx)),
+                    None,
+                    None,
+                    optimization,
+                )?;
+                assert_eq(
+                    &helmholtz_free_energy,
+                    &block.calculate_helmholtz_free_energy(&solution)?,
+                )?;
+                let (deformation_gradient, _) =
+                    $constitutive_model::new($constitutive_model_parameters)
+                        .solve(AppliedLoad::UniaxialStress(1.0 + dx))?;
+                block
+                    .calculate_deformation_gradients(&solution)
+                    .iter()
+                    .try_for_each(|deformation_gradients| {
+                        deformation_gradients
+                            .iter()
+                            .try_for_each(|deformation_gradient_g| {
+                                assert_eq_within_tols(deformation_gradient_g, &deformation_gradient)
+                            })
+                    })
+            } else {
+                Ok(())
+            }
+        }
+        fn solve_applied_force(optimization: Optimization) -> Result<(), TestError> {
+            if TEST_SOLVE {
+                let dx = 0.23;
+                let model = $constitutive_model::new($constitutive_model_parameters);
+                let (deformation_gradient, _) =
+                    model.solve(AppliedLoad::UniaxialStress(1.0 + dx))?;
+                let force =
+                    model.calculate_first_piola_kirchoff_stress(&deformation_gradient)?[0][0];
+                let block = get_block();
+                let solution = block.solve(
+                    get_reference_coordinates_block().into(),
+                    Some(&get_dirichlet_places_applied_force()),
+                    Some(&get_dirichlet_values_applied_force()),
+                    Some(&get_neumann_places()),
+                    Some(&get_neumann_values(force)),
+                    optimization,
+                )?;
+                block
+                    .calculate_deformation_gradients(&solution)
+                    .iter()
+                    .try_for_each(|deformation_gradients| {
+                        deformation_gradients
+                            .iter()
+                            .try_for_each(|deformation_gradient_g| {
+                                assert_eq_within_tols(deformation_gradient_g, &deformation_gradient)
+                            })
+                    })
+            } else {
+                Ok(())
+            }
+        }
+        fn solve_arc_length() -> Result<(), TestError> {
+            if TEST_SOLVE {
+                let dx = 0.23;
+                let model = $constitutive_model::new($constitutive_model_parameters);
+                let (deformation_gradient, _) =
+                    model.solve(AppliedLoad::UniaxialStress(1.0 + dx))?;
+                let force =
+                    model.calculate_first_piola_kirchoff_stress(&deformation_gradient)?[0][0];
+                let block = get_block();
+                let path = block.solve_arc_length(
+                    get_reference_coordinates_block().into(),
+                    Some(&get_dirichlet_places_applied_force()),
+                    Some(&get_dirichlet_values_applied_force()),
+                    &get_neumann_places(),
+                    &get_neumann_values(force),
+                    ArcLength {
+                        ..Default::default()
+                    },
+                )?;
+                let (load_factor, solution) = path.last().unwrap();
+                assert_eq!(load_factor, &1.0);
+                block
+                    .calculate_deformation_gradients(solution)
+                    .iter()
+                    .try_for_each(|deformation_gradients| {
+                        deformation_gradients
+                            .iter()
+                            .try_for_each(|deformation_gradient_g| {
+                                assert_eq_within_tols(deformation_gradient_g, &deformation_gradient)
+                            })
+                    })
+            } else {
+                Ok(())
+            }
+        }
+        mod minimize {
+            use super::*;
+            #[test]
+            fn limited_memory_bfgs() -> Result<(), TestError> {
+                minimize(
+                    get_dirichlet_places,
+                    get_dirichlet_values,
+                    Optimization::LimitedMemoryBfgs(LimitedMemoryBfgs {
+                        ..Default::default()
+                    }),
+                )
+            }
+            #[test]
+            fn newton_raphson() -> Result<(), TestError> {
+                minimize(
+                    get_dirichlet_places_constrained,
+                    get_dirichlet_values_constrained,
+                    Optimization::NewtonRaphson(NewtonRaphson {
+                        ..Default::default()
+                    }),
+                )
+            }
+        }
+        mod solve {
+            use super::*;
+            mod applied_displacement {
+                use super::*;
+                #[test]
+                fn conjugate_gradient() -> Result<(), TestError> {
+                    solve(
+                        get_dirichlet_places,
+                        get_dirichlet_values,
+                        Optimization::ConjugateGradient(ConjugateGradient {
+                            ..Default::default()
+                        }),
+                    )
+                }
+                #[test]
+                fn gradient_descent() -> Result<(), TestError> {
+                    solve(
+                        get_dirichlet_places,
+                        get_dirichlet_values,
+                        Optimization::GradientDescent(GradientDescent {
+                            ..Default::default()
+                        }),
+                    )
+                }
+                #[test]
+                fn limited_memory_bfgs() -> Result<(), TestError> {
+                    solve(
+                        get_dirichlet_places,
+                        get_dirichlet_values,
+                        Optimization::LimitedMemoryBfgs(LimitedMemoryBfgs {
+                            ..Default::default()
+                        }),
+                    )
+                }
+                #[test]
+                fn newton_raphson() -> Result<(), TestError> {
+                    solve(
+                        get_dirichlet_places_constrained,
+                        get_dirichlet_values_constrained,
+                        Optimization::NewtonRaphson(NewtonRaphson {
+                            ..Default::default()
+                        }),
+                    )
+                }
+                #[test]
+                fn newton_raphson_minres() -> Result<(), TestError> {
+                    solve(
+                        get_dirichlet_places_constrained,
+                        get_dirichlet_values_constrained,
+                        Optimization::NewtonRaphson(NewtonRaphson {
+                            linear_solver: LinearSolver::Iterative(IterativeSolver {
+                                method: IterativeMethod::Minres,
+                                preconditioner: Preconditioner::None,
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        }),
+                    )
+                }
+            }
+            mod applied_force {
+                use super::*;
+                #[test]
+                fn arc_length() -> Result<(), TestError> {
+                    solve_arc_length()
+                }
+                #[test]
+                fn conjugate_gradient() -> Result<(), TestError> {
+                    solve_applied_force(Optimization::ConjugateGradient(ConjugateGradient {
+                        abs_tol: 1e-13,
+                        ..Default::default()
+                    }))
+                }
+                #[test]
+                fn gradient_descent() -> Result<(), TestError> {
+                    solve_applied_force(Optimization::GradientDescent(GradientDescent {
+                        abs_tol: 1e-13,
+                        ..Default::default()
+                    }))
+                }
+                #[test]
+                fn limited_memory_bfgs() -> Result<(), TestError> {
+                    solve_applied_force(Optimization::LimitedMemoryBfgs(LimitedMemoryBfgs {
+                        abs_tol: 1e-13,
+                        ..Default::default()
+                    }))
+                }
+                #[test]
+                fn newton_raphson() -> Result<(), TestError> {
+                    solve_applied_force(Optimization::NewtonRaphson(NewtonRaphson {
+                        ..Default::default()
+                    }))
+                }
+                #[test]
+                fn newton_raphson_conjugate_gradient() -> Result<(), TestError> {
+                    solve_applied_force(Optimization::NewtonRaphson(NewtonRaphson {
+                        linear_solver: LinearSolver::Iterative(IterativeSolver {
+                            method: IterativeMethod::ConjugateGradient,
+                            preconditioner: Preconditioner::IncompleteCholesky,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }))
+                }
+            }
+        }
     };
 }
 pub(crate) use test_finite_element_block_with_hyperelastic_constitutive_model;
@@ -745,42 +994,50 @@ macro_rules! test_finite_element_block_with_viscoelastic_constitutive_model {
         fn get_nodal_stiffnesses(
             is_deformed: bool,
             is_rotated: bool,
-        ) -> Result<NodalStiffnessesBlock, TestError> {
+        ) -> Result<TensorRank2Vec2D<3, 1, 1>, TestError> {
             if is_rotated {
                 if is_deformed {
                     Ok(get_rotation_current_configuration().transpose()
-                        * get_block_transformed().calculate_nodal_stiffnesses(
-                            &get_coordinates_transformed_block(),
-                            &get_velocities_transformed_block(),
-                        )?
+                        * TensorRank2Vec2D::from(
+                            get_block_transformed().calculate_nodal_stiffnesses(
+                                &get_coordinates_transformed_block(),
+                                &get_velocities_transformed_block(),
+                            )?,
+                        )
                         * get_rotation_current_configuration())
                 } else {
                     let converted: TensorRank2<3, 1, 1> =
                         get_rotation_reference_configuration().into();
                     Ok(converted.transpose()
-                        * get_block_transformed().calculate_nodal_stiffnesses(
-                            &get_reference_coordinates_transformed_block().into(),
-                            &NodalVelocitiesBlock::zero(D),
-                        )?
+                        * TensorRank2Vec2D::from(
+                            get_block_transformed().calculate_nodal_stiffnesses(
+                                &get_reference_coordinates_transformed_block().into(),
+                                &NodalVelocitiesBlock::zero(D),
+                            )?,
+                        )
                         * converted)
                 }
             } else {
                 if is_deformed {
-                    Ok(get_block().calculate_nodal_stiffnesses(
-                        &get_coordinates_block(),
-                        &get_velocities_block(),
-                    )?)
+                    Ok(get_block()
+                        .calculate_nodal_stiffnesses(
+                            &get_coordinates_block(),
+                            &get_velocities_block(),
+                        )?
+                        .into())
                 } else {
-                    Ok(get_block().calculate_nodal_stiffnesses(
-                        &get_reference_coordinates_block().into(),
-                        &NodalVelocitiesBlock::zero(D),
-                    )?)
+                    Ok(get_block()
+                        .calculate_nodal_stiffnesses(
+                            &get_reference_coordinates_block().into(),
+                            &NodalVelocitiesBlock::zero(D),
+                        )?
+                        .into())
                 }
             }
         }
         fn get_finite_difference_of_nodal_forces(
             is_deformed: bool,
-        ) -> Result<NodalStiffnessesBlock, TestError> {
+        ) -> Result<TensorRank2Vec2D<3, 1, 1>, TestError> {
             let block = get_block();
             let nodal_coordinates = if is_deformed {
                 get_coordinates_block()
@@ -1194,6 +1451,82 @@ macro_rules! test_finite_element_block_with_elastic_hyperviscous_constitutive_mo
                 }
             }
         }
+        use crate::{
+            constitutive::solid::elastic_hyperviscous::ElasticHyperviscous,
+            math::{
+                optimize::{GradientDescent, LinearSolver, NewtonRaphson, Optimization},
+                IterativeMethod, IterativeSolver, Preconditioner, TensorRank0List,
+            },
+            mechanics::{CurrentCoordinate, ReferenceCoordinate},
+        };
+        fn solve(optimization: Optimization) -> Result<(), TestError> {
+            if TEST_SOLVE {
+                let evaluation_times =
+                    TensorRank0List::<6>::new(from_fn(|index| 0.02 * (index as Scalar)));
+                let block = get_block();
+                let nodal_coordinates_history = block.solve(
+                    get_reference_coordinates_block().into(),
+                    Some(&get_dirichlet_places_constrained()),
+                    Some(|time: Scalar| get_dirichlet_values_constrained(time).to_vec()),
+                    None,
+                    None,
+                    &evaluation_times,
+                    optimization,
+                )?;
+                let (deformation_gradients, _) =
+                    $constitutive_model::new($constitutive_model_parameters)
+                        .solve_uniaxial(|_| 1.0, evaluation_times.0)?;
+                nodal_coordinates_history
+                    .iter()
+                    .zip(deformation_gradients.iter())
+                    .try_for_each(|(nodal_coordinates, deformation_gradient)| {
+                        nodal_coordinates
+                            .iter()
+                            .zip(get_reference_coordinates_block().iter())
+                            .try_for_each(|(nodal_coordinate, reference_nodal_coordinate)| {
+                                assert_eq_within_tols(
+                                    nodal_coordinate,
+                                    &(deformation_gradient
+                                        * (reference_nodal_coordinate
+                                            - ReferenceCoordinate::new([-0.5; 3]))
+                                        + CurrentCoordinate::new([-0.5; 3])),
+                                )
+                            })
+                    })?;
+                Ok(())
+            } else {
+                Ok(())
+            }
+        }
+        mod solve {
+            use super::*;
+            #[test]
+            fn gradient_descent() -> Result<(), TestError> {
+                solve(Optimization::GradientDescent(GradientDescent {
+                    ..Default::default()
+                }))
+            }
+            #[test]
+            fn newton_raphson() -> Result<(), TestError> {
+                solve(Optimization::NewtonRaphson(NewtonRaphson {
+                    check_minimum: false,
+                    max_steps: 6,
+                    ..Default::default()
+                }))
+            }
+            #[test]
+            fn newton_raphson_gmres() -> Result<(), TestError> {
+                solve(Optimization::NewtonRaphson(NewtonRaphson {
+                    check_minimum: false,
+                    linear_solver: LinearSolver::Iterative(IterativeSolver {
+                        method: IterativeMethod::Gmres,
+                        preconditioner: Preconditioner::IncompleteLu,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }))
+            }
+        }
     };
 }
 pub(crate) use test_finite_element_block_with_elastic_hyperviscous_constitutive_model;
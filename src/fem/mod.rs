@@ -41,10 +41,11 @@ use crate::{
         Constitutive, ConstitutiveError, Parameters,
     },
     math::{
-        tensor_rank_1_zero, ContractSecondFourthIndicesWithFirstIndicesOf, Tensor, TensorArray,
-        TensorRank1, TensorRank1List, TensorRank1List2D, TensorRank1Vec, TensorRank2,
-        TensorRank2List, TensorRank2List2D, TensorRank2Vec2D, TensorRank3, TensorRank3List,
-        TensorRank3List2D, TensorRank3List3D, TensorVec, ONE_SIXTH, ONE_TWENTY_FOURTH,
+        tensor_rank_1_zero, ContractSecondFourthIndicesWithFirstIndicesOf, SparseMatrix, Tensor,
+        TensorArray, TensorRank0List, TensorRank1, TensorRank1List, TensorRank1List2D,
+        TensorRank1Vec, TensorRank2, TensorRank2List, TensorRank2List2D, TensorRank3,
+        TensorRank3List, TensorRank3List2D, TensorRank3List3D, TensorVec, ONE_SIXTH,
+        ONE_TWENTY_FOURTH,
     },
     mechanics::{
         Coordinates, CurrentCoordinates, DeformationGradient, DeformationGradientRate,
@@ -56,10 +57,11 @@ use crate::{
 };
 
 type NodalCoordinatesBlock = TensorRank1Vec<3, 1>;
+type NodalCoordinatesHistory = Vec<NodalCoordinatesBlock>;
 type ReferenceNodalCoordinatesBlock = TensorRank1Vec<3, 0>;
 type NodalVelocitiesBlock = TensorRank1Vec<3, 1>;
 type NodalForcesBlock = TensorRank1Vec<3, 1>;
-type NodalStiffnessesBlock = TensorRank2Vec2D<3, 1, 1>;
+type NodalStiffnessesBlock = SparseMatrix;
 
 type Basis<const I: usize> = Vectors<I, 2>;
 type Bases<const I: usize, const P: usize> = TensorRank1List2D<3, I, 2, P>;
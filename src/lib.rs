@@ -40,6 +40,38 @@ fn get_defeat_message<'a>() -> &'a str {
     }
 }
 
+#[cfg(feature = "math")]
+/// Writes an error as a headline followed by its details.
+///
+/// The headline and details are colored if the `color` feature is enabled.
+fn write_error(f: &mut std::fmt::Formatter, headline: &str, details: &str) -> std::fmt::Result {
+    if cfg!(feature = "color") {
+        write!(f, "\x1b[1;91m{}\x1b[0;91m\n{}\x1b[0m", headline, details)
+    } else {
+        write!(f, "{}\n{}", headline, details)
+    }
+}
+
+#[cfg(feature = "math")]
+/// Writes an error for debugging, which also includes a defeat message if the `color` feature is enabled.
+fn write_error_debug(
+    f: &mut std::fmt::Formatter,
+    headline: &str,
+    details: &str,
+) -> std::fmt::Result {
+    if cfg!(feature = "color") {
+        write!(
+            f,
+            "\n\x1b[1;91m{}\x1b[0;91m\n{}\n\x1b[0;2;31m{}\x1b[0m\n",
+            headline,
+            details,
+            get_defeat_message()
+        )
+    } else {
+        write!(f, "{}\n{}", headline, details)
+    }
+}
+
 #[allow(dead_code)]
 fn get_victory_message<'a>() -> &'a str {
     match get_random_number() {
@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod test;
+
+use super::{Scalar, Tensor, TensorRank0};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A complex number $`a + bi`$.
+///
+/// Perturbing a real argument along the imaginary axis by a tiny step $`h`$ yields the derivative of a real-analytic function as $`f'(x)=\mathrm{Im}[f(x+ih)]/h`$ without subtractive cancellation.
+/// Comparisons only consider the real part.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex {
+    pub imaginary: TensorRank0,
+    pub real: TensorRank0,
+}
+
+impl Complex {
+    /// Returns a complex number given its real and imaginary parts.
+    pub fn new(real: TensorRank0, imaginary: TensorRank0) -> Self {
+        Self { imaginary, real }
+    }
+}
+
+impl Display for Complex {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} + {}i", self.real, self.imaginary)
+    }
+}
+
+impl PartialOrd for Complex {
+    fn partial_cmp(&self, complex: &Self) -> Option<Ordering> {
+        self.real.partial_cmp(&complex.real)
+    }
+}
+
+impl Scalar for Complex {
+    fn from_f64(value: TensorRank0) -> Self {
+        Self::new(value, 0.0)
+    }
+    fn one() -> Self {
+        Self::new(1.0, 0.0)
+    }
+    fn powi(self, n: i32) -> Self {
+        let mut base = if n < 0 { Self::one() / self } else { self };
+        let mut exponent = n.unsigned_abs();
+        let mut power = Self::one();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                power *= base
+            }
+            base *= base;
+            exponent /= 2;
+        }
+        power
+    }
+    fn sqrt(self) -> Self {
+        //
+        // Principal branch, written so that a tiny imaginary part is carried exactly.
+        //
+        let modulus = self.real.hypot(self.imaginary);
+        if self.real >= 0.0 {
+            let real = ((modulus + self.real) / 2.0).sqrt();
+            if real == 0.0 {
+                Self::zero()
+            } else {
+                Self::new(real, self.imaginary / (2.0 * real))
+            }
+        } else {
+            let imaginary = ((modulus - self.real) / 2.0)
+                .sqrt()
+                .copysign(self.imaginary);
+            Self::new(self.imaginary / (2.0 * imaginary), imaginary)
+        }
+    }
+    fn to_f64(self) -> TensorRank0 {
+        self.real
+    }
+    fn zero() -> Self {
+        Self::default()
+    }
+}
+
+impl Tensor<Complex> for Complex {
+    type Item = Complex;
+    fn copy(&self) -> Self {
+        *self
+    }
+    fn full_contraction(&self, complex: &Self) -> Self {
+        *self * *complex
+    }
+    fn flattened(&self) -> Vec<Self> {
+        vec![*self]
+    }
+    fn set_flattened(&mut self, entries: &mut impl Iterator<Item = Self>) {
+        *self = entries
+            .next()
+            .expect("Not enough entries to set the flattened tensor.")
+    }
+    fn flat_index(&self, _indices: &[usize]) -> usize {
+        0
+    }
+    fn get_at(&self, _indices: &[usize]) -> &Self {
+        self
+    }
+    fn get_at_mut(&mut self, _indices: &[usize]) -> &mut Self {
+        self
+    }
+    fn is_zero(&self) -> bool {
+        self == &Self::zero()
+    }
+    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
+        std::iter::once(self)
+    }
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
+        std::iter::once(self)
+    }
+}
+
+impl Product for Complex {
+    fn product<Ii>(iter: Ii) -> Self
+    where
+        Ii: Iterator<Item = Self>,
+    {
+        iter.fold(Self::one(), |product, complex| product * complex)
+    }
+}
+
+impl Sum for Complex {
+    fn sum<Ii>(iter: Ii) -> Self
+    where
+        Ii: Iterator<Item = Self>,
+    {
+        iter.fold(Self::zero(), |sum, complex| sum + complex)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+    fn add(self, complex: Self) -> Self::Output {
+        Self::new(self.real + complex.real, self.imaginary + complex.imaginary)
+    }
+}
+
+impl Add<&Self> for Complex {
+    type Output = Self;
+    fn add(self, complex: &Self) -> Self::Output {
+        self + *complex
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, complex: Self) {
+        *self = *self + complex
+    }
+}
+
+impl AddAssign<&Self> for Complex {
+    fn add_assign(&mut self, complex: &Self) {
+        *self = *self + *complex
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+    fn div(self, complex: Self) -> Self::Output {
+        let denominator = complex.real * complex.real + complex.imaginary * complex.imaginary;
+        Self::new(
+            (self.real * complex.real + self.imaginary * complex.imaginary) / denominator,
+            (self.imaginary * complex.real - self.real * complex.imaginary) / denominator,
+        )
+    }
+}
+
+impl DivAssign for Complex {
+    fn div_assign(&mut self, complex: Self) {
+        *self = *self / complex
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+    fn mul(self, complex: Self) -> Self::Output {
+        Self::new(
+            self.real * complex.real - self.imaginary * complex.imaginary,
+            self.real * complex.imaginary + self.imaginary * complex.real,
+        )
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, complex: Self) {
+        *self = *self * complex
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.real, -self.imaginary)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+    fn sub(self, complex: Self) -> Self::Output {
+        Self::new(self.real - complex.real, self.imaginary - complex.imaginary)
+    }
+}
+
+impl Sub<&Self> for Complex {
+    type Output = Self;
+    fn sub(self, complex: &Self) -> Self::Output {
+        self - *complex
+    }
+}
+
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, complex: Self) {
+        *self = *self - complex
+    }
+}
+
+impl SubAssign<&Self> for Complex {
+    fn sub_assign(&mut self, complex: &Self) {
+        *self = *self - *complex
+    }
+}
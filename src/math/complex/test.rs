@@ -0,0 +1,96 @@
+use super::{
+    super::{
+        test::{assert_eq_within_tols, TestError},
+        Rank2, Scalar, TensorArray, TensorRank0, TensorRank2,
+    },
+    Complex,
+};
+
+const STEP: TensorRank0 = 1e-20;
+
+fn get_tensor_rank_2() -> TensorRank2<3, 1, 0> {
+    TensorRank2::new([[1.0, 4.0, 6.0], [7.0, 2.0, 5.0], [9.0, 8.0, 3.0]])
+}
+
+fn get_variable(value: TensorRank0) -> Complex {
+    Complex::new(value, STEP)
+}
+
+#[test]
+fn divide() -> Result<(), TestError> {
+    let complex = Complex::one() / get_variable(2.0);
+    assert_eq_within_tols(&complex.real, &0.5)?;
+    assert_eq_within_tols(&(complex.imaginary / STEP), &-0.25)
+}
+
+#[test]
+fn multiply() -> Result<(), TestError> {
+    let complex = get_variable(2.0) * get_variable(3.0);
+    assert_eq_within_tols(&complex.real, &6.0)?;
+    assert_eq_within_tols(&(complex.imaginary / STEP), &5.0)
+}
+
+#[test]
+fn powi() -> Result<(), TestError> {
+    let complex = get_variable(2.0).powi(-3);
+    assert_eq_within_tols(&complex.real, &0.125)?;
+    assert_eq_within_tols(&(complex.imaginary / STEP), &(-3.0 / 16.0))
+}
+
+#[test]
+fn sqrt() -> Result<(), TestError> {
+    let complex = get_variable(4.0).sqrt();
+    assert_eq_within_tols(&complex.real, &2.0)?;
+    assert_eq_within_tols(&(complex.imaginary / STEP), &0.25)?;
+    let complex = Complex::new(-4.0, 0.0).sqrt();
+    assert_eq_within_tols(&complex.real, &0.0)?;
+    assert_eq_within_tols(&complex.imaginary, &2.0)
+}
+
+#[test]
+fn tangent_of_determinant() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let tangent = tensor_rank_2.inverse_transpose() * tensor_rank_2.determinant();
+    (0..3).try_for_each(|i| {
+        (0..3).try_for_each(|j| {
+            let mut perturbed = TensorRank2::<3, 1, 0, Complex>::new(
+                tensor_rank_2
+                    .as_array()
+                    .map(|row| row.map(|entry| Complex::new(entry, 0.0))),
+            );
+            perturbed[i][j].imaginary = STEP;
+            assert_eq_within_tols(&(perturbed.determinant().imaginary / STEP), &tangent[i][j])
+        })
+    })
+}
+
+#[test]
+fn tangent_of_inverse() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let inverse = tensor_rank_2.inverse();
+    (0..3).try_for_each(|k| {
+        (0..3).try_for_each(|l| {
+            let mut perturbed = TensorRank2::<3, 1, 0, Complex>::new(
+                tensor_rank_2
+                    .as_array()
+                    .map(|row| row.map(|entry| Complex::new(entry, 0.0))),
+            );
+            perturbed[k][l].imaginary = STEP;
+            let perturbed_inverse = perturbed.inverse();
+            (0..3).try_for_each(|i| {
+                (0..3).try_for_each(|j| {
+                    assert_eq_within_tols(
+                        &(perturbed_inverse[i][j].imaginary / STEP),
+                        &(-inverse[i][k] * inverse[l][j]),
+                    )
+                })
+            })
+        })
+    })
+}
+
+#[test]
+fn trace() {
+    let tensor_rank_2 = TensorRank2::<3, 1, 0, Complex>::identity();
+    assert_eq!(tensor_rank_2.trace(), Complex::new(3.0, 0.0))
+}
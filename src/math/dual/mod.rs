@@ -0,0 +1,461 @@
+#[cfg(test)]
+mod test;
+
+use super::{Scalar, Tensor, TensorRank0};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A dual number $`a + a'\varepsilon`$ with $`\varepsilon^2=0`$.
+///
+/// Dual numbers are scalars, so tensors of dual numbers carry their directional derivative alongside them.
+/// Products obey the product rule, so evaluating a function with dual arguments yields its exact directional derivative.
+/// Comparisons only consider the value.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual<T = TensorRank0> {
+    pub derivative: T,
+    pub value: T,
+}
+
+/// A hyper-dual number $`a + a_1\varepsilon_1 + a_2\varepsilon_2 + a_{12}\varepsilon_1\varepsilon_2`$.
+///
+/// The outer derivative holds $`a_1+a_{12}\varepsilon_2`$, so evaluating a function with hyper-dual arguments yields its exact mixed second derivative.
+pub type HyperDual<T = TensorRank0> = Dual<Dual<T>>;
+
+impl<T> Dual<T>
+where
+    T: Scalar,
+{
+    /// Returns a dual number with a zero derivative.
+    pub fn constant(value: T) -> Self {
+        Self {
+            derivative: T::zero(),
+            value,
+        }
+    }
+    /// Returns a dual number given its value and derivative.
+    pub fn new(value: T, derivative: T) -> Self {
+        Self { derivative, value }
+    }
+}
+
+/// Common methods for numbers.
+pub trait Number
+where
+    Self: Scalar
+        + Add<TensorRank0, Output = Self>
+        + Div<TensorRank0, Output = Self>
+        + Mul<TensorRank0, Output = Self>
+        + Sub<TensorRank0, Output = Self>,
+{
+    /// Returns the exponential of the number.
+    fn exp(self) -> Self;
+    /// Returns the natural logarithm of the number.
+    fn ln(self) -> Self;
+    /// Returns the number raised to a floating-point power.
+    fn powf(self, exponent: TensorRank0) -> Self;
+    /// Returns the reciprocal of the number.
+    fn recip(self) -> Self;
+}
+
+impl Number for TensorRank0 {
+    fn exp(self) -> Self {
+        TensorRank0::exp(self)
+    }
+    fn ln(self) -> Self {
+        TensorRank0::ln(self)
+    }
+    fn powf(self, exponent: TensorRank0) -> Self {
+        TensorRank0::powf(self, exponent)
+    }
+    fn recip(self) -> Self {
+        TensorRank0::recip(self)
+    }
+}
+
+impl<T> Number for Dual<T>
+where
+    T: Number,
+{
+    fn exp(self) -> Self {
+        let value = self.value.exp();
+        Self {
+            derivative: self.derivative * value,
+            value,
+        }
+    }
+    fn ln(self) -> Self {
+        Self {
+            derivative: self.derivative * self.value.recip(),
+            value: self.value.ln(),
+        }
+    }
+    fn powf(self, exponent: TensorRank0) -> Self {
+        Self {
+            derivative: self.derivative * self.value.powf(exponent - 1.0) * exponent,
+            value: self.value.powf(exponent),
+        }
+    }
+    fn recip(self) -> Self {
+        let value = self.value.recip();
+        Self {
+            derivative: -self.derivative * value.powi(2),
+            value,
+        }
+    }
+}
+
+impl<T> Scalar for Dual<T>
+where
+    T: Scalar,
+{
+    fn from_f64(value: TensorRank0) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            Self::one()
+        } else {
+            Self {
+                derivative: self.derivative * self.value.powi(n - 1) * T::from_f64(n.into()),
+                value: self.value.powi(n),
+            }
+        }
+    }
+    fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+        Self {
+            derivative: self.derivative / (value + value),
+            value,
+        }
+    }
+    fn to_f64(self) -> TensorRank0 {
+        self.value.to_f64()
+    }
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+}
+
+impl<T> Display for Dual<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.derivative)
+    }
+}
+
+impl<T> PartialOrd for Dual<T>
+where
+    T: Scalar,
+{
+    fn partial_cmp(&self, dual: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&dual.value)
+    }
+}
+
+impl<T> Tensor<Dual<T>> for Dual<T>
+where
+    T: Scalar,
+{
+    type Item = Self;
+    fn copy(&self) -> Self {
+        *self
+    }
+    fn full_contraction(&self, dual: &Self) -> Self {
+        *self * *dual
+    }
+    fn flattened(&self) -> Vec<Self> {
+        vec![*self]
+    }
+    fn set_flattened(&mut self, entries: &mut impl Iterator<Item = Self>) {
+        *self = entries
+            .next()
+            .expect("Not enough entries to set the flattened tensor.")
+    }
+    fn flat_index(&self, _indices: &[usize]) -> usize {
+        0
+    }
+    fn get_at(&self, _indices: &[usize]) -> &Self {
+        self
+    }
+    fn get_at_mut(&mut self, _indices: &[usize]) -> &mut Self {
+        self
+    }
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.derivative.is_zero()
+    }
+    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
+        std::iter::once(self)
+    }
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
+        std::iter::once(self)
+    }
+}
+
+impl<T> Product for Dual<T>
+where
+    T: Scalar,
+{
+    fn product<Ii>(iter: Ii) -> Self
+    where
+        Ii: Iterator<Item = Self>,
+    {
+        iter.fold(Self::one(), |product, dual| product * dual)
+    }
+}
+
+impl<T> Sum for Dual<T>
+where
+    T: Scalar,
+{
+    fn sum<Ii>(iter: Ii) -> Self
+    where
+        Ii: Iterator<Item = Self>,
+    {
+        iter.fold(Self::zero(), |sum, dual| sum + dual)
+    }
+}
+
+impl<T> Add for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn add(self, dual: Self) -> Self::Output {
+        Self {
+            derivative: self.derivative + dual.derivative,
+            value: self.value + dual.value,
+        }
+    }
+}
+
+impl<T> Add<&Self> for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn add(self, dual: &Self) -> Self::Output {
+        self + *dual
+    }
+}
+
+impl<T> Add<TensorRank0> for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn add(self, tensor_rank_0: TensorRank0) -> Self::Output {
+        Self {
+            derivative: self.derivative,
+            value: self.value + T::from_f64(tensor_rank_0),
+        }
+    }
+}
+
+impl<T> Add<Dual<T>> for TensorRank0
+where
+    T: Scalar,
+{
+    type Output = Dual<T>;
+    fn add(self, dual: Dual<T>) -> Self::Output {
+        dual + self
+    }
+}
+
+impl<T> AddAssign for Dual<T>
+where
+    T: Scalar,
+{
+    fn add_assign(&mut self, dual: Self) {
+        self.derivative += dual.derivative;
+        self.value += dual.value;
+    }
+}
+
+impl<T> AddAssign<&Self> for Dual<T>
+where
+    T: Scalar,
+{
+    fn add_assign(&mut self, dual: &Self) {
+        *self += *dual
+    }
+}
+
+impl<T> Div for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn div(self, dual: Self) -> Self::Output {
+        let value = self.value / dual.value;
+        Self {
+            derivative: (self.derivative - value * dual.derivative) / dual.value,
+            value,
+        }
+    }
+}
+
+impl<T> Div<TensorRank0> for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn div(self, tensor_rank_0: TensorRank0) -> Self::Output {
+        let tensor_rank_0 = T::from_f64(tensor_rank_0);
+        Self {
+            derivative: self.derivative / tensor_rank_0,
+            value: self.value / tensor_rank_0,
+        }
+    }
+}
+
+impl<T> Div<Dual<T>> for TensorRank0
+where
+    T: Scalar,
+{
+    type Output = Dual<T>;
+    fn div(self, dual: Dual<T>) -> Self::Output {
+        Dual::from_f64(self) / dual
+    }
+}
+
+impl<T> DivAssign for Dual<T>
+where
+    T: Scalar,
+{
+    fn div_assign(&mut self, dual: Self) {
+        *self = *self / dual
+    }
+}
+
+impl<T> Mul for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn mul(self, dual: Self) -> Self::Output {
+        Self {
+            derivative: self.derivative * dual.value + self.value * dual.derivative,
+            value: self.value * dual.value,
+        }
+    }
+}
+
+impl<T> Mul<TensorRank0> for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn mul(self, tensor_rank_0: TensorRank0) -> Self::Output {
+        let tensor_rank_0 = T::from_f64(tensor_rank_0);
+        Self {
+            derivative: self.derivative * tensor_rank_0,
+            value: self.value * tensor_rank_0,
+        }
+    }
+}
+
+impl<T> Mul<Dual<T>> for TensorRank0
+where
+    T: Scalar,
+{
+    type Output = Dual<T>;
+    fn mul(self, dual: Dual<T>) -> Self::Output {
+        dual * self
+    }
+}
+
+impl<T> MulAssign for Dual<T>
+where
+    T: Scalar,
+{
+    fn mul_assign(&mut self, dual: Self) {
+        *self = *self * dual
+    }
+}
+
+impl<T> Neg for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            derivative: -self.derivative,
+            value: -self.value,
+        }
+    }
+}
+
+impl<T> Sub for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn sub(self, dual: Self) -> Self::Output {
+        Self {
+            derivative: self.derivative - dual.derivative,
+            value: self.value - dual.value,
+        }
+    }
+}
+
+impl<T> Sub<&Self> for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn sub(self, dual: &Self) -> Self::Output {
+        self - *dual
+    }
+}
+
+impl<T> Sub<TensorRank0> for Dual<T>
+where
+    T: Scalar,
+{
+    type Output = Self;
+    fn sub(self, tensor_rank_0: TensorRank0) -> Self::Output {
+        Self {
+            derivative: self.derivative,
+            value: self.value - T::from_f64(tensor_rank_0),
+        }
+    }
+}
+
+impl<T> Sub<Dual<T>> for TensorRank0
+where
+    T: Scalar,
+{
+    type Output = Dual<T>;
+    fn sub(self, dual: Dual<T>) -> Self::Output {
+        -dual + self
+    }
+}
+
+impl<T> SubAssign for Dual<T>
+where
+    T: Scalar,
+{
+    fn sub_assign(&mut self, dual: Self) {
+        self.derivative -= dual.derivative;
+        self.value -= dual.value;
+    }
+}
+
+impl<T> SubAssign<&Self> for Dual<T>
+where
+    T: Scalar,
+{
+    fn sub_assign(&mut self, dual: &Self) {
+        *self -= *dual
+    }
+}
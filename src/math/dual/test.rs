@@ -0,0 +1,210 @@
+use super::{
+    super::{
+        test::{assert_eq_within_tols, TestError},
+        Rank2, Scalar, TensorArray, TensorRank2,
+    },
+    Dual, HyperDual, Number, Tensor, TensorRank0,
+};
+use std::array::from_fn;
+
+fn get_tensor_rank_2() -> TensorRank2<3, 1, 0> {
+    TensorRank2::new([[1.0, 4.0, 6.0], [7.0, 2.0, 5.0], [9.0, 8.0, 3.0]])
+}
+
+fn get_tensor_rank_2_derivative() -> TensorRank2<3, 1, 0> {
+    TensorRank2::new([[0.3, -0.1, 0.2], [0.0, 0.5, -0.4], [0.1, 0.2, 0.6]])
+}
+
+fn get_dual_tensor_rank_2(
+    value: &TensorRank2<3, 1, 0>,
+    derivative: &TensorRank2<3, 1, 0>,
+) -> TensorRank2<3, 1, 0, Dual> {
+    TensorRank2::new(from_fn(|i| {
+        from_fn(|j| Dual::new(value[i][j], derivative[i][j]))
+    }))
+}
+
+fn get_hyper_dual_tensor_rank_2(
+    value: &TensorRank2<3, 1, 0>,
+    derivative_1: &TensorRank2<3, 1, 0>,
+    derivative_2: &TensorRank2<3, 1, 0>,
+) -> TensorRank2<3, 1, 0, HyperDual> {
+    TensorRank2::new(from_fn(|i| {
+        from_fn(|j| {
+            HyperDual::new(
+                Dual::new(value[i][j], derivative_2[i][j]),
+                Dual::constant(derivative_1[i][j]),
+            )
+        })
+    }))
+}
+
+fn get_derivative<const I: usize, const J: usize>(
+    tensor_rank_2: &TensorRank2<3, I, J, Dual>,
+) -> TensorRank2<3, I, J> {
+    TensorRank2::new(
+        tensor_rank_2
+            .as_array()
+            .map(|row| row.map(|entry| entry.derivative)),
+    )
+}
+
+fn get_value<const I: usize, const J: usize>(
+    tensor_rank_2: &TensorRank2<3, I, J, Dual>,
+) -> TensorRank2<3, I, J> {
+    TensorRank2::new(
+        tensor_rank_2
+            .as_array()
+            .map(|row| row.map(|entry| entry.value)),
+    )
+}
+
+fn get_variable(value: TensorRank0) -> Dual {
+    Dual::new(value, 1.0)
+}
+
+#[test]
+fn add() -> Result<(), TestError> {
+    let dual = get_variable(2.0) + get_variable(3.0) + 1.0;
+    assert_eq_within_tols(&dual.value, &6.0)?;
+    assert_eq_within_tols(&dual.derivative, &2.0)
+}
+
+#[test]
+fn constant() {
+    assert_eq!(Dual::constant(2.0).derivative, 0.0)
+}
+
+#[test]
+fn determinant() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let determinant = get_dual_tensor_rank_2(&tensor_rank_2, &tensor_rank_2).determinant();
+    assert_eq_within_tols(&determinant.value, &tensor_rank_2.determinant())?;
+    assert_eq_within_tols(
+        &determinant.derivative,
+        &(3.0 * tensor_rank_2.determinant()),
+    )
+}
+
+#[test]
+fn determinant_hyper_dual() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let determinant =
+        get_hyper_dual_tensor_rank_2(&tensor_rank_2, &tensor_rank_2, &tensor_rank_2).determinant();
+    assert_eq_within_tols(
+        &determinant.derivative.derivative,
+        &(6.0 * tensor_rank_2.determinant()),
+    )
+}
+
+#[test]
+fn div() -> Result<(), TestError> {
+    let dual = get_variable(2.0) / Dual::new(4.0, 3.0);
+    assert_eq_within_tols(&dual.value, &0.5)?;
+    assert_eq_within_tols(&dual.derivative, &((4.0 - 2.0 * 3.0) / 16.0))
+}
+
+#[test]
+fn exp() -> Result<(), TestError> {
+    assert_eq_within_tols(&get_variable(0.7).exp().derivative, &0.7_f64.exp())
+}
+
+#[test]
+fn inverse() -> Result<(), TestError> {
+    let tensor_rank_2 =
+        get_dual_tensor_rank_2(&get_tensor_rank_2(), &get_tensor_rank_2_derivative());
+    let product = &tensor_rank_2 * tensor_rank_2.inverse();
+    assert_eq_within_tols(&get_value(&product), &TensorRank2::identity())?;
+    assert!(get_derivative(&product).norm() < 1e-12);
+    Ok(())
+}
+
+#[test]
+fn inverse_hyper_dual() {
+    let tensor_rank_2 = get_hyper_dual_tensor_rank_2(
+        &get_tensor_rank_2(),
+        &get_tensor_rank_2_derivative(),
+        &get_tensor_rank_2_derivative(),
+    );
+    (&tensor_rank_2 * tensor_rank_2.inverse())
+        .iter()
+        .flat_map(|row| row.iter())
+        .for_each(|entry| {
+            assert!(entry.derivative.value.abs() < 1e-12);
+            assert!(entry.derivative.derivative.abs() < 1e-12)
+        })
+}
+
+#[test]
+fn ln() -> Result<(), TestError> {
+    assert_eq_within_tols(&get_variable(0.7).ln().derivative, &(1.0 / 0.7))
+}
+
+#[test]
+fn mul() -> Result<(), TestError> {
+    let dual = get_variable(2.0) * Dual::new(3.0, 5.0) * 2.0;
+    assert_eq_within_tols(&dual.value, &12.0)?;
+    assert_eq_within_tols(&dual.derivative, &(2.0 * (3.0 + 2.0 * 5.0)))
+}
+
+#[test]
+fn mul_tensor_rank_2() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let tensor_rank_2_derivative = get_tensor_rank_2_derivative();
+    let dual = get_dual_tensor_rank_2(&tensor_rank_2, &tensor_rank_2_derivative);
+    assert_eq_within_tols(
+        &get_derivative(&(dual.transpose() * &dual)),
+        &(tensor_rank_2_derivative.transpose() * &tensor_rank_2
+            + tensor_rank_2.transpose() * &tensor_rank_2_derivative),
+    )
+}
+
+#[test]
+fn powf() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &get_variable(0.7).powf(2.5).derivative,
+        &(2.5 * 0.7_f64.powf(1.5)),
+    )
+}
+
+#[test]
+fn powi() -> Result<(), TestError> {
+    assert_eq_within_tols(&get_variable(0.7).powi(3).derivative, &(3.0 * 0.49))
+}
+
+#[test]
+fn powi_hyper_dual() -> Result<(), TestError> {
+    let hyper_dual = HyperDual::new(get_variable(0.7), Dual::new(1.0, 0.0)).powi(3);
+    assert_eq_within_tols(&hyper_dual.value.value, &0.343)?;
+    assert_eq_within_tols(&hyper_dual.value.derivative, &(3.0 * 0.49))?;
+    assert_eq_within_tols(&hyper_dual.derivative.value, &(3.0 * 0.49))?;
+    assert_eq_within_tols(&hyper_dual.derivative.derivative, &(6.0 * 0.7))
+}
+
+#[test]
+fn recip() -> Result<(), TestError> {
+    assert_eq_within_tols(&get_variable(0.7).recip().derivative, &(-1.0 / 0.49))
+}
+
+#[test]
+fn sqrt() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &get_variable(0.7).sqrt().derivative,
+        &(0.5 / 0.7_f64.sqrt()),
+    )
+}
+
+#[test]
+fn sub() -> Result<(), TestError> {
+    let dual = 1.0 - get_variable(2.0) - Dual::new(3.0, 5.0);
+    assert_eq_within_tols(&dual.value, &-4.0)?;
+    assert_eq_within_tols(&dual.derivative, &-6.0)
+}
+
+#[test]
+fn trace() -> Result<(), TestError> {
+    let trace =
+        get_dual_tensor_rank_2(&get_tensor_rank_2(), &get_tensor_rank_2_derivative()).trace();
+    assert_eq_within_tols(&trace.value, &6.0)?;
+    assert_eq_within_tols(&trace.derivative, &1.4)
+}
@@ -1,98 +1,81 @@
 #[cfg(test)]
 mod test;
 
+mod ode15s;
 mod ode1be;
 mod ode23;
+mod ode45;
 
-// Explicit, six-stage, fifth-order, variable-step, Runge-Kutta method ([Dormand and Prince, 1980](https://doi.org/10.1016/0771-050X(80)90013-3)).
-// mod ode45;
-
+pub use ode15s::Ode15s;
 pub use ode1be::Ode1be;
 pub use ode23::Ode23;
+pub use ode45::Ode45;
 
 use super::{Tensor, TensorArray, TensorRank0, TensorRank0List};
-use crate::get_defeat_message;
+use crate::{write_error, write_error_debug};
 use std::{
+    error::Error,
     fmt,
-    iter::Peekable,
+    iter::{Copied, Peekable},
     ops::{Div, Mul, Sub},
+    slice::Iter,
 };
 
-type EvalTimes<const W: usize> = Peekable<std::array::IntoIter<TensorRank0, W>>;
+type EvalTimes<'a> = Peekable<Copied<Iter<'a, TensorRank0>>>;
 
 /// Base trait for ordinary differential equation solvers.
-pub trait OdeSolver<Y, U, const W: usize>
+pub trait OdeSolver<Y>
 where
     Self: fmt::Debug,
     Y: Tensor,
-    U: Tensor<Item = Y>,
 {
     /// Setup for ordinary differential equation solvers.
     fn setup<'a>(
-        &'a self,
+        &self,
         initial_time: TensorRank0,
-        initial_condition: Y,
-        evaluation_times: &TensorRank0List<W>,
-        solution: &'a mut U,
-    ) -> Result<
-        (
-            EvalTimes<W>,
-            TensorRank0,
-            TensorRank0,
-            Y,
-            impl Iterator<Item = &'a mut <U as Tensor>::Item>,
-        ),
-        IntegrationError<W>,
-    >
-    where
-        Y: 'a,
-    {
-        let dt;
-        let y = initial_condition.copy();
-        let t = initial_time.copy();
-        let mut eval_times = evaluation_times.0.into_iter().peekable();
-        let mut y_sol = solution.iter_mut();
-        for check_times in evaluation_times.0.windows(2) {
+        initial_condition: &Y,
+        evaluation_times: &'a [TensorRank0],
+        internal_steps: bool,
+    ) -> Result<(EvalTimes<'a>, TensorRank0, History<Y>), IntegrationError> {
+        for check_times in evaluation_times.windows(2) {
             if check_times[1] - check_times[0] <= 0.0 {
                 return Err(IntegrationError::EvaluationTimesNotStrictlyIncreasing(
-                    evaluation_times.copy(),
+                    evaluation_times.to_vec(),
                     format!("{:?}", &self),
                 ));
             }
         }
-        if eval_times.next_if_eq(&initial_time).is_some() {
-            if W == 1 {
-                return Err(IntegrationError::EvaluationTimesNoFinalTime(
-                    evaluation_times.copy(),
-                    format!("{:?}", &self),
-                ));
-            } else {
-                dt = eval_times.peek().ok_or("not ok")? - initial_time;
-                *y_sol.next().ok_or("not ok")? = initial_condition;
+        let mut eval_times = evaluation_times.iter().copied().peekable();
+        let mut history = History::new();
+        if eval_times.next_if_eq(&initial_time).is_some() || internal_steps {
+            history.push((initial_time, initial_condition.copy()))
+        }
+        match eval_times.peek() {
+            Some(&eval_time) if eval_time > initial_time => {
+                Ok((eval_times, eval_time - initial_time, history))
             }
-        } else if eval_times.peek().ok_or("not ok")? > &initial_time {
-            dt = eval_times.peek().ok_or("not ok")? - initial_time;
-        } else {
-            return Err(IntegrationError::EvaluationTimesPrecedeInitialTime(
-                evaluation_times.copy(),
+            Some(_) => Err(IntegrationError::EvaluationTimesPrecedeInitialTime(
+                evaluation_times.to_vec(),
                 initial_time,
                 format!("{:?}", &self),
-            ));
-        };
-        Ok((eval_times, dt, t, y, y_sol))
+            )),
+            None => Err(IntegrationError::EvaluationTimesNoFinalTime(
+                evaluation_times.to_vec(),
+                format!("{:?}", &self),
+            )),
+        }
     }
 }
 
-impl<A, Y, U, const W: usize> OdeSolver<Y, U, W> for A
+impl<A, Y> OdeSolver<Y> for A
 where
     A: std::fmt::Debug,
     Y: Tensor,
-    U: Tensor<Item = Y>,
 {
 }
 
-/// Base trait for explicit ordinary differential equation solvers.
-pub trait Explicit<Y, U, const W: usize>: OdeSolver<Y, U, W>
+/// Base trait for explicit ordinary differential equation solvers with a fixed number of evaluation times.
+pub trait Explicit<Y, U, const W: usize>: ExplicitVec<Y>
 where
     Y: Tensor,
     for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
@@ -109,11 +92,82 @@ where
         initial_time: TensorRank0,
         initial_condition: Y,
         evaluation_times: &TensorRank0List<W>,
-    ) -> Result<U, IntegrationError<W>>;
+    ) -> Result<U, IntegrationError> {
+        let (solution, _, _) = self.integrate_with_events(
+            function,
+            initial_time,
+            initial_condition,
+            evaluation_times,
+            &[],
+        )?;
+        Ok(solution)
+    }
+    /// Solves an initial value problem by explicitly integrating a system of ordinary differential equations while locating events.
+    ///
+    /// ```math
+    /// \frac{dy}{dt} = f(t, y),\quad y(t_0) = y_0,\quad g_i(t, y) = 0
+    /// ```
+    ///
+    /// Also returns the number of evaluation times reached, which is less than `W` if a terminal event occurred.
+    /// The solution at the remaining evaluation times is then not computed, and the terminal event is the last occurrence.
+    fn integrate_with_events(
+        &self,
+        function: impl Fn(&TensorRank0, &Y) -> Y,
+        initial_time: TensorRank0,
+        initial_condition: Y,
+        evaluation_times: &TensorRank0List<W>,
+        events: &[Event<Y>],
+    ) -> Result<(U, usize, Occurrences<Y>), IntegrationError> {
+        let (history, occurrences) = self.integrate_vec(
+            function,
+            initial_time,
+            initial_condition,
+            &evaluation_times.0,
+            events,
+            false,
+        )?;
+        let length = history.len();
+        Ok((collect_solution(history), length, occurrences))
+    }
+}
+
+impl<A, Y, U, const W: usize> Explicit<Y, U, W> for A
+where
+    A: ExplicitVec<Y>,
+    Y: Tensor,
+    for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+    U: Tensor<Item = Y> + TensorArray,
+{
+}
+
+/// Base trait for explicit ordinary differential equation solvers with any number of evaluation times.
+pub trait ExplicitVec<Y>: OdeSolver<Y>
+where
+    Y: Tensor,
+    for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+{
+    /// Solves an initial value problem by explicitly integrating a system of ordinary differential equations while locating events.
+    ///
+    /// ```math
+    /// \frac{dy}{dt} = f(t, y),\quad y(t_0) = y_0,\quad g_i(t, y) = 0
+    /// ```
+    ///
+    /// The history contains the solution at each of the evaluation times, where the final evaluation time is the final time.
+    /// If `internal_steps` is `true`, it also contains the initial condition and the solution after each accepted internal step,
+    /// so that only the final time needs to be given as an evaluation time.
+    fn integrate_vec(
+        &self,
+        function: impl Fn(&TensorRank0, &Y) -> Y,
+        initial_time: TensorRank0,
+        initial_condition: Y,
+        evaluation_times: &[TensorRank0],
+        events: &[Event<Y>],
+        internal_steps: bool,
+    ) -> Result<(History<Y>, Occurrences<Y>), IntegrationError>;
 }
 
-/// Base trait for implicit ordinary differential equation solvers.
-pub trait Implicit<Y, J, U, const W: usize>: OdeSolver<Y, U, W>
+/// Base trait for implicit ordinary differential equation solvers with a fixed number of evaluation times.
+pub trait Implicit<Y, J, U, const W: usize>: ImplicitVec<Y, J>
 where
     Y: Tensor + Div<J, Output = Y>,
     for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
@@ -132,89 +186,285 @@ where
         initial_time: TensorRank0,
         initial_condition: Y,
         evaluation_times: &TensorRank0List<W>,
-    ) -> Result<U, IntegrationError<W>>;
+    ) -> Result<U, IntegrationError> {
+        let (solution, _, _) = self.integrate_with_events(
+            function,
+            jacobian,
+            initial_time,
+            initial_condition,
+            evaluation_times,
+            &[],
+        )?;
+        Ok(solution)
+    }
+    /// Solves an initial value problem by implicitly integrating a system of ordinary differential equations while locating events.
+    ///
+    /// ```math
+    /// \frac{dy}{dt} = f(t, y),\quad y(t_0) = y_0,\quad \frac{\partial f}{\partial y} = J(t, y),\quad g_i(t, y) = 0
+    /// ```
+    ///
+    /// Also returns the number of evaluation times reached, which is less than `W` if a terminal event occurred.
+    /// The solution at the remaining evaluation times is then not computed, and the terminal event is the last occurrence.
+    fn integrate_with_events(
+        &self,
+        function: impl Fn(&TensorRank0, &Y) -> Y,
+        jacobian: impl Fn(&TensorRank0, &Y) -> J,
+        initial_time: TensorRank0,
+        initial_condition: Y,
+        evaluation_times: &TensorRank0List<W>,
+        events: &[Event<Y>],
+    ) -> Result<(U, usize, Occurrences<Y>), IntegrationError> {
+        let (history, occurrences) = self.integrate_vec(
+            function,
+            jacobian,
+            initial_time,
+            initial_condition,
+            &evaluation_times.0,
+            events,
+            false,
+        )?;
+        let length = history.len();
+        Ok((collect_solution(history), length, occurrences))
+    }
 }
 
-/// Possible errors encountered when integrating.
-pub enum IntegrationError<const W: usize> {
-    EvaluationTimesNoFinalTime(TensorRank0List<W>, String),
-    EvaluationTimesNotStrictlyIncreasing(TensorRank0List<W>, String),
-    EvaluationTimesPrecedeInitialTime(TensorRank0List<W>, TensorRank0, String),
+impl<A, Y, J, U, const W: usize> Implicit<Y, J, U, W> for A
+where
+    A: ImplicitVec<Y, J>,
+    Y: Tensor + Div<J, Output = Y>,
+    for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+    J: Tensor + TensorArray,
+    U: Tensor<Item = Y> + TensorArray,
+{
 }
 
-impl<const W: usize> From<&str> for IntegrationError<W> {
-    fn from(string: &str) -> Self {
-        todo!("{}", string)
+/// Base trait for implicit ordinary differential equation solvers with any number of evaluation times.
+pub trait ImplicitVec<Y, J>: OdeSolver<Y>
+where
+    Y: Tensor + Div<J, Output = Y>,
+    for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+    J: Tensor + TensorArray,
+{
+    /// Solves an initial value problem by implicitly integrating a system of ordinary differential equations while locating events.
+    ///
+    /// ```math
+    /// \frac{dy}{dt} = f(t, y),\quad y(t_0) = y_0,\quad \frac{\partial f}{\partial y} = J(t, y),\quad g_i(t, y) = 0
+    /// ```
+    ///
+    /// The history contains the solution at each of the evaluation times, where the final evaluation time is the final time.
+    /// If `internal_steps` is `true`, it also contains the initial condition and the solution after each accepted internal step,
+    /// so that only the final time needs to be given as an evaluation time.
+    #[allow(clippy::too_many_arguments)]
+    fn integrate_vec(
+        &self,
+        function: impl Fn(&TensorRank0, &Y) -> Y,
+        jacobian: impl Fn(&TensorRank0, &Y) -> J,
+        initial_time: TensorRank0,
+        initial_condition: Y,
+        evaluation_times: &[TensorRank0],
+        events: &[Event<Y>],
+        internal_steps: bool,
+    ) -> Result<(History<Y>, Occurrences<Y>), IntegrationError>;
+}
+
+/// Solution history when integrating, as the time and the solution.
+pub type History<Y> = Vec<(TensorRank0, Y)>;
+
+fn collect_solution<Y, U>(history: History<Y>) -> U
+where
+    Y: Tensor,
+    U: Tensor<Item = Y> + TensorArray,
+{
+    let mut solution = U::zero();
+    solution
+        .iter_mut()
+        .zip(history)
+        .for_each(|(y_sol, (_, y))| *y_sol = y);
+    solution
+}
+
+fn record<Y>(
+    history: &mut History<Y>,
+    eval_times: &mut EvalTimes,
+    time_stop: TensorRank0,
+    internal_steps: bool,
+    interpolate: impl Fn(&TensorRank0) -> Y,
+) {
+    while let Some(eval_time) = eval_times.next_if(|&eval_time| time_stop >= eval_time) {
+        history.push((eval_time, interpolate(&eval_time)))
+    }
+    if internal_steps
+        && eval_times.peek().is_some()
+        && history.last().is_none_or(|(time, _)| time < &time_stop)
+    {
+        history.push((time_stop, interpolate(&time_stop)))
     }
 }
 
-impl<const W: usize> fmt::Debug for IntegrationError<W> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = match self {
-            Self::EvaluationTimesNoFinalTime(evaluation_times, integrator) => {
-                format!(
-                    "\x1b[1;91mEvaluation times must include a final time.\x1b[0;91m\n\
-                     From evaluation times: {}.\n\
-                     In integrator: {}.",
-                    evaluation_times, integrator
-                )
-            }
-            Self::EvaluationTimesNotStrictlyIncreasing(evaluation_times, integrator) => {
-                format!(
-                    "\x1b[1;91mEvaluation times must be strictly increasing.\x1b[0;91m\n\
-                     From evaluation times: {}.\n\
-                     In integrator: {}.",
-                    evaluation_times, integrator
-                )
-            }
-            Self::EvaluationTimesPrecedeInitialTime(evaluation_times, initial_time, integrator) => {
-                format!(
-                    "\x1b[1;91mEvaluation times precede the initial time.\x1b[0;91m\n\
-                     From evaluation times: {}.\n\
-                     With initial time: {}.\n\
-                     In integrator: {}.",
-                    evaluation_times, initial_time, integrator
-                )
+/// Event for ordinary differential equation solvers.
+pub struct Event<'a, Y> {
+    /// Event function, where the event occurs when it crosses or reaches zero after the initial time.
+    pub function: &'a dyn Fn(&TensorRank0, &Y) -> TensorRank0,
+    /// Whether to stop integrating when the event occurs.
+    pub terminal: bool,
+}
+
+/// Events that occurred when integrating, as the index of the event, the time, and the solution.
+pub type Occurrences<Y> = Vec<(usize, TensorRank0, Y)>;
+
+/// Number of subintervals of each step searched for events, so that events crossing zero more than once within a step are resolved.
+const EVENT_SUBINTERVALS: usize = 8;
+
+fn locate_events<Y>(
+    events: &[Event<Y>],
+    time: TensorRank0,
+    y: &Y,
+    time_trial: TensorRank0,
+    y_trial: &Y,
+    interpolate: impl Fn(&TensorRank0) -> Y,
+) -> (Occurrences<Y>, Option<TensorRank0>)
+where
+    Y: Tensor,
+{
+    let solution = |t: &TensorRank0| {
+        if t == &time {
+            y.copy()
+        } else if t == &time_trial {
+            y_trial.copy()
+        } else {
+            interpolate(t)
+        }
+    };
+    let times: Vec<TensorRank0> = (0..=EVENT_SUBINTERVALS)
+        .map(|i| match i {
+            0 => time,
+            EVENT_SUBINTERVALS => time_trial,
+            _ => {
+                time + (time_trial - time) * (i as TensorRank0)
+                    / (EVENT_SUBINTERVALS as TensorRank0)
             }
-        };
-        write!(
-            f,
-            "\n{}\n\x1b[0;2;31m{}\x1b[0m\n",
-            error,
-            get_defeat_message()
-        )
+        })
+        .collect();
+    let mut occurrences: Occurrences<Y> = events
+        .iter()
+        .enumerate()
+        .flat_map(|(index, event)| {
+            let g = |t: &TensorRank0| (event.function)(t, &solution(t));
+            let values: Vec<TensorRank0> = times.iter().map(g).collect();
+            times
+                .windows(2)
+                .zip(values.windows(2))
+                .filter_map(|(t, g_t)| {
+                    if g_t[0] == 0.0 {
+                        None
+                    } else if g_t[1] == 0.0 {
+                        Some(t[1])
+                    } else if g_t[0].signum() != g_t[1].signum() {
+                        let mut time_0 = t[0];
+                        let mut time_1 = t[1];
+                        let mut time_m = 0.5 * (time_0 + time_1);
+                        while time_0 < time_m && time_m < time_1 {
+                            let g_m = g(&time_m);
+                            if g_m == 0.0 {
+                                time_1 = time_m;
+                                break;
+                            } else if g_m.signum() == g_t[0].signum() {
+                                time_0 = time_m
+                            } else {
+                                time_1 = time_m
+                            }
+                            time_m = 0.5 * (time_0 + time_1);
+                        }
+                        Some(time_1)
+                    } else {
+                        None
+                    }
+                })
+                .map(|t| (index, t, solution(&t)))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    occurrences.sort_by(|a, b| a.1.total_cmp(&b.1));
+    match occurrences
+        .iter()
+        .position(|(index, _, _)| events[*index].terminal)
+    {
+        Some(position) => {
+            occurrences.truncate(position + 1);
+            let terminal_time = occurrences[position].1;
+            (occurrences, Some(terminal_time))
+        }
+        None => (occurrences, None),
     }
 }
 
-impl<const W: usize> fmt::Display for IntegrationError<W> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = match self {
-            Self::EvaluationTimesNoFinalTime(evaluation_times, integrator) => {
+/// Possible errors encountered when integrating.
+pub enum IntegrationError {
+    EvaluationTimesNoFinalTime(Vec<TensorRank0>, String),
+    EvaluationTimesNotStrictlyIncreasing(Vec<TensorRank0>, String),
+    EvaluationTimesPrecedeInitialTime(Vec<TensorRank0>, TensorRank0, String),
+    MinimumStepSizeReached(TensorRank0, String),
+    NonFiniteErrorEstimate(TensorRank0, String),
+}
+
+impl IntegrationError {
+    fn message(&self) -> (String, String) {
+        match self {
+            Self::EvaluationTimesNoFinalTime(evaluation_times, integrator) => (
+                "Evaluation times must include a final time.".to_string(),
                 format!(
-                    "\x1b[1;91mEvaluation times must include a final time.\x1b[0;91m\n\
-                     From evaluation times: {}.\n\
+                    "From evaluation times: {:?}.\n\
                      In integrator: {}.",
                     evaluation_times, integrator
-                )
-            }
-            Self::EvaluationTimesNotStrictlyIncreasing(evaluation_times, integrator) => {
+                ),
+            ),
+            Self::EvaluationTimesNotStrictlyIncreasing(evaluation_times, integrator) => (
+                "Evaluation times must be strictly increasing.".to_string(),
                 format!(
-                    "\x1b[1;91mEvaluation times must be strictly increasing.\x1b[0;91m\n\
-                     From evaluation times: {}.\n\
+                    "From evaluation times: {:?}.\n\
                      In integrator: {}.",
                     evaluation_times, integrator
-                )
-            }
+                ),
+            ),
             Self::EvaluationTimesPrecedeInitialTime(evaluation_times, initial_time, integrator) => {
-                format!(
-                    "\x1b[1;91mEvaluation times precede the initial time.\x1b[0;91m\n\
-                     From evaluation times: {}.\n\
+                (
+                    "Evaluation times precede the initial time.".to_string(),
+                    format!(
+                        "From evaluation times: {:?}.\n\
                      With initial time: {}.\n\
                      In integrator: {}.",
-                    evaluation_times, initial_time, integrator
+                        evaluation_times, initial_time, integrator
+                    ),
                 )
             }
-        };
-        write!(f, "{}\x1b[0m", error)
+            Self::MinimumStepSizeReached(dt_min, integrator) => (
+                format!("Minimum time step ({:?}) reached.", dt_min),
+                format!("In integrator: {}.", integrator),
+            ),
+            Self::NonFiniteErrorEstimate(time, integrator) => (
+                "Error estimate is not finite.".to_string(),
+                format!(
+                    "At time: {}.\n\
+                     In integrator: {}.",
+                    time, integrator
+                ),
+            ),
+        }
+    }
+}
+
+impl fmt::Debug for IntegrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (headline, details) = self.message();
+        write_error_debug(f, &headline, &details)
+    }
+}
+
+impl fmt::Display for IntegrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (headline, details) = self.message();
+        write_error(f, &headline, &details)
     }
 }
+
+impl Error for IntegrationError {}
@@ -0,0 +1,292 @@
+#[cfg(test)]
+mod test;
+
+use super::{
+    super::{
+        optimize::{FirstOrder, NewtonRaphson, Optimization, SecondOrder},
+        Hessian, Tensor, TensorArray, TensorRank0,
+    },
+    locate_events, record, Event, History, ImplicitVec, IntegrationError, Occurrences, OdeSolver,
+};
+use crate::{ABS_TOL, REL_TOL};
+use std::ops::{Div, Mul, Sub};
+
+/// Implicit, variable-order, variable-step, backward differentiation formula method.[^cite]
+///
+/// [^cite]: L.F. Shampine and M.W. Reichelt, [SIAM J. Sci. Comput. **18**, 1 (1997)](https://doi.org/10.1137/S1064827594276424).
+///
+/// ```math
+/// \frac{dy}{dt} = f(t, y)
+/// ```
+/// ```math
+/// t_{n+1} = t_n + h
+/// ```
+/// ```math
+/// \sum_{j=0}^k \ell_j'(t_{n+1})\,y_{n+1-j} = f(t_{n+1}, y_{n+1})
+/// ```
+/// ```math
+/// e_{n+1} = \frac{h}{t_{n+1} - t_{n-k}}\left(y_{n+1} - y_{n+1}^{(0)}\right)
+/// ```
+///
+/// Here $`\ell_j`$ are the Lagrange polynomials through the times $`t_{n+1},\ldots,t_{n+1-k}`$ and $`y_{n+1}^{(0)}`$ is the predictor extrapolated from $`y_n,\ldots,y_{n-k}`$.
+/// After each accepted step, the order among $`k-1`$, $`k`$, and $`k+1`$ whose error estimate allows the largest next step is selected.
+/// The order is lowered after each step rejected for its error, and the step is reduced when the nonlinear solve fails.
+/// An error is returned if the nonlinear solve keeps failing until the step falls below its minimum value.
+#[derive(Debug)]
+pub struct Ode15s {
+    /// Absolute error tolerance.
+    pub abs_tol: TensorRank0,
+    /// Multiplying factor when decreasing time steps.
+    pub dec_fac: TensorRank0,
+    /// Minimum value for the time step.
+    pub dt_min: TensorRank0,
+    /// Multiplying factor when increasing time steps.
+    pub inc_fac: TensorRank0,
+    /// Maximum order of the method.
+    pub max_order: usize,
+    /// Optimization algorithm for equation solving.
+    pub optimization: Optimization,
+    /// Relative error tolerance.
+    pub rel_tol: TensorRank0,
+}
+
+impl Default for Ode15s {
+    fn default() -> Self {
+        Self {
+            abs_tol: ABS_TOL,
+            dec_fac: 0.5,
+            dt_min: ABS_TOL,
+            inc_fac: 1.1,
+            max_order: 5,
+            optimization: Optimization::NewtonRaphson(NewtonRaphson {
+                check_minimum: false,
+                ..Default::default()
+            }),
+            rel_tol: REL_TOL,
+        }
+    }
+}
+
+impl<Y, J> ImplicitVec<Y, J> for Ode15s
+where
+    Y: Tensor + Div<J, Output = Y>,
+    for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+    J: Hessian + Tensor + TensorArray,
+{
+    fn integrate_vec(
+        &self,
+        function: impl Fn(&TensorRank0, &Y) -> Y,
+        jacobian: impl Fn(&TensorRank0, &Y) -> J,
+        initial_time: TensorRank0,
+        initial_condition: Y,
+        evaluation_times: &[TensorRank0],
+        events: &[Event<Y>],
+        internal_steps: bool,
+    ) -> Result<(History<Y>, Occurrences<Y>), IntegrationError> {
+        let mut e;
+        let mut error_factor;
+        let mut gamma;
+        let mut k_1 = function(&initial_time, &initial_condition);
+        let mut nodes;
+        let mut occurrences = Vec::new();
+        let mut order = 1;
+        let mut psi;
+        let mut t_trial;
+        let mut weights;
+        let mut y_predicted;
+        let mut y_trial;
+        let identity = J::identity();
+        let (mut eval_times, mut dt, mut history) = self.setup(
+            initial_time,
+            &initial_condition,
+            evaluation_times,
+            internal_steps,
+        )?;
+        let mut times = vec![initial_time];
+        let mut ys = vec![initial_condition];
+        while eval_times.peek().is_some() {
+            t_trial = times[0] + dt;
+            nodes = [t_trial]
+                .into_iter()
+                .chain(times.iter().take(order).copied())
+                .collect::<Vec<TensorRank0>>();
+            weights = lagrange_derivative_weights(&nodes);
+            gamma = 1.0 / weights[0];
+            psi = ys
+                .iter()
+                .zip(weights.iter().skip(1))
+                .fold(&ys[0] * 0.0, |psi, (y_j, weight_j)| {
+                    psi - y_j * (weight_j * gamma)
+                });
+            if times.len() > order {
+                weights = lagrange_weights(&times[..=order], t_trial);
+                y_predicted = ys
+                    .iter()
+                    .zip(weights.iter())
+                    .fold(&ys[0] * 0.0, |y_predicted, (y_j, weight_j)| {
+                        y_predicted + y_j * *weight_j
+                    });
+                error_factor = dt / (t_trial - times[order]);
+            } else {
+                y_predicted = &k_1 * dt + &ys[0];
+                error_factor = 0.5;
+            }
+            let residual =
+                |y_trial: &Y| Ok(y_trial - &psi - &(&function(&t_trial, y_trial) * gamma));
+            let residual_jacobian =
+                |y_trial: &Y| Ok(jacobian(&t_trial, y_trial) * -gamma + &identity);
+            let Ok(solution) = (match &self.optimization {
+                Optimization::ConjugateGradient(conjugate_gradient) => {
+                    conjugate_gradient.minimize(residual, y_predicted.copy(), None, None)
+                }
+                Optimization::GradientDescent(gradient_descent) => {
+                    gradient_descent.minimize(residual, y_predicted.copy(), None, None)
+                }
+                Optimization::LimitedMemoryBfgs(limited_memory_bfgs) => {
+                    limited_memory_bfgs.minimize(residual, y_predicted.copy(), None, None)
+                }
+                Optimization::NewtonRaphson(newton_raphson) => newton_raphson.minimize(
+                    residual,
+                    residual_jacobian,
+                    y_predicted.copy(),
+                    None,
+                    None,
+                ),
+            }) else {
+                dt *= self.dec_fac;
+                if dt < self.dt_min {
+                    return Err(IntegrationError::MinimumStepSizeReached(
+                        self.dt_min,
+                        format!("{:?}", &self),
+                    ));
+                }
+                continue;
+            };
+            y_trial = solution;
+            e = ((&y_trial - &y_predicted) * error_factor).norm();
+            if e < self.abs_tol || e / y_trial.norm() < self.rel_tol {
+                let dense_output = |time: &TensorRank0| {
+                    let weights = lagrange_weights(&nodes, *time);
+                    ys.iter()
+                        .zip(weights.iter().skip(1))
+                        .fold(&y_trial * weights[0], |y_eval, (y_j, weight_j)| {
+                            y_eval + y_j * *weight_j
+                        })
+                };
+                let (mut found, terminal_time) =
+                    locate_events(events, times[0], &ys[0], t_trial, &y_trial, dense_output);
+                record(
+                    &mut history,
+                    &mut eval_times,
+                    terminal_time.unwrap_or(t_trial),
+                    internal_steps,
+                    dense_output,
+                );
+                occurrences.append(&mut found);
+                if terminal_time.is_some() {
+                    break;
+                }
+                order = self.select_order(order, e, &times, &ys, t_trial, &y_trial, dt);
+                k_1 = function(&t_trial, &y_trial);
+                times.insert(0, t_trial);
+                ys.insert(0, y_trial);
+                times.truncate(self.max_order + 1);
+                ys.truncate(self.max_order + 1);
+                dt *= self.inc_fac;
+            } else {
+                if order > 1 {
+                    order -= 1;
+                }
+                dt *= self.dec_fac;
+            }
+        }
+        Ok((history, occurrences))
+    }
+}
+
+impl Ode15s {
+    /// Selects the order allowing the largest next step from the error estimates at the neighboring orders.
+    #[allow(clippy::too_many_arguments)]
+    fn select_order<Y>(
+        &self,
+        order: usize,
+        error: TensorRank0,
+        times: &[TensorRank0],
+        ys: &[Y],
+        t_trial: TensorRank0,
+        y_trial: &Y,
+        dt: TensorRank0,
+    ) -> usize
+    where
+        Y: Tensor,
+        for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+    {
+        let tolerance = self.abs_tol.max(self.rel_tol * y_trial.norm());
+        let step_ratio = |order: usize, error: TensorRank0| {
+            (tolerance / error).powf(1.0 / (order as TensorRank0 + 1.0))
+        };
+        let estimate = |order: usize| {
+            let weights = lagrange_weights(&times[..=order], t_trial);
+            let y_predicted = ys
+                .iter()
+                .zip(weights.iter())
+                .fold(y_trial * 0.0, |y_predicted, (y_j, weight_j)| {
+                    y_predicted + y_j * *weight_j
+                });
+            ((y_trial - &y_predicted) * (dt / (t_trial - times[order]))).norm()
+        };
+        let mut selected = (order, step_ratio(order, error));
+        if order > 1 && times.len() > order {
+            let ratio = step_ratio(order - 1, estimate(order - 1));
+            if ratio > selected.1 {
+                selected = (order - 1, ratio)
+            }
+        }
+        if order < self.max_order && times.len() > order + 1 {
+            let ratio = step_ratio(order + 1, estimate(order + 1));
+            if ratio > selected.1 {
+                selected = (order + 1, ratio)
+            }
+        }
+        selected.0
+    }
+}
+
+fn lagrange_derivative_weights(nodes: &[TensorRank0]) -> Vec<TensorRank0> {
+    (0..nodes.len())
+        .map(|i| {
+            if i == 0 {
+                nodes[1..]
+                    .iter()
+                    .map(|node_j| 1.0 / (nodes[0] - node_j))
+                    .sum()
+            } else {
+                nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(j, node_j)| {
+                        if j == 0 {
+                            1.0 / (nodes[i] - node_j)
+                        } else {
+                            (nodes[0] - node_j) / (nodes[i] - node_j)
+                        }
+                    })
+                    .product()
+            }
+        })
+        .collect()
+}
+
+fn lagrange_weights(nodes: &[TensorRank0], time: TensorRank0) -> Vec<TensorRank0> {
+    (0..nodes.len())
+        .map(|i| {
+            nodes
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, node_j)| (time - node_j) / (nodes[i] - node_j))
+                .product()
+        })
+        .collect()
+}
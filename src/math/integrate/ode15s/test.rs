@@ -0,0 +1,338 @@
+use super::{
+    super::{
+        super::{
+            optimize::{GradientDescent, NewtonRaphson, Optimization},
+            test::TestError,
+            Tensor, TensorArray, TensorRank0, TensorRank0List, TensorRank1, TensorRank1List,
+            TensorRank2,
+        },
+        test::{test_events_and_internal_steps, zero_to_tau},
+        Implicit,
+    },
+    ImplicitVec, Ode15s,
+};
+
+const LENGTH: usize = 33;
+const TOLERANCE: TensorRank0 = 1e-8;
+
+#[test]
+#[should_panic(expected = "Evaluation times must be strictly increasing.")]
+fn evaluation_times_not_strictly_increasing() {
+    let mut evaluation_times = zero_to_tau::<LENGTH>();
+    evaluation_times[3] = evaluation_times[2];
+    let _: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Evaluation times precede the initial time.")]
+fn evaluation_times_precede_initial_time() {
+    let mut evaluation_times = zero_to_tau::<LENGTH>();
+    evaluation_times[0] = -1.0;
+    let _: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Evaluation times must include a final time.")]
+fn evaluation_times_no_final_time() {
+    let _: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &TensorRank0List::new([0.0]),
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Minimum time step (1e-12) reached.")]
+fn minimum_step_size_reached() {
+    let _: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, _: &TensorRank0| TensorRank0::NAN,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &zero_to_tau::<LENGTH>(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn first_order_tensor_rank_0_one_evaluation_time_after_initial_time() -> Result<(), TestError> {
+    let evaluation_times = TensorRank0List::new([1.0]);
+    let solution: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )?;
+    evaluation_times
+        .iter()
+        .zip(solution.iter())
+        .for_each(|(t, y)| {
+            assert!(((-t).exp() - y).abs() < TOLERANCE || ((-t).exp() / y - 1.0).abs() < TOLERANCE)
+        });
+    Ok(())
+}
+
+#[test]
+fn first_order_tensor_rank_0_first_evaluation_time() -> Result<(), TestError> {
+    let mut evaluation_times = zero_to_tau::<LENGTH>();
+    evaluation_times[0] = 1e-8;
+    evaluation_times[3] = evaluation_times[2] + 1e-8;
+    evaluation_times[4] = evaluation_times[3] + 1e-8;
+    evaluation_times[5] = evaluation_times[4] + 1e-8;
+    let solution: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )?;
+    evaluation_times
+        .iter()
+        .zip(solution.iter())
+        .for_each(|(t, y)| {
+            assert!(((-t).exp() - y).abs() < TOLERANCE || ((-t).exp() / y - 1.0).abs() < TOLERANCE)
+        });
+    Ok(())
+}
+
+#[test]
+fn first_order_tensor_rank_0_nearby_evaluation_times() -> Result<(), TestError> {
+    let mut evaluation_times = zero_to_tau::<LENGTH>();
+    evaluation_times[3] = evaluation_times[2] + 1e-10;
+    evaluation_times[4] = evaluation_times[3] + 1e-10;
+    evaluation_times[5] = evaluation_times[4] + 1e-10;
+    let solution: TensorRank0List<LENGTH> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )?;
+    evaluation_times
+        .iter()
+        .zip(solution.iter())
+        .for_each(|(t, y)| {
+            assert!(((-t).exp() - y).abs() < TOLERANCE || ((-t).exp() / y - 1.0).abs() < TOLERANCE)
+        });
+    Ok(())
+}
+
+test_events_and_internal_steps!(
+    Ode15s {
+        ..Default::default()
+    },
+    |_: &TensorRank0, _: &TensorRank0| -1.0
+);
+
+macro_rules! test_ode15s {
+    ($optimization: expr) => {
+        #[test]
+        fn first_order_tensor_rank_0() -> Result<(), TestError> {
+            let evaluation_times = zero_to_tau::<LENGTH>();
+            let solution: TensorRank0List<LENGTH> = Ode15s {
+                optimization: $optimization,
+                ..Default::default()
+            }
+            .integrate(
+                |_: &TensorRank0, y: &TensorRank0| -y,
+                |_: &TensorRank0, _: &TensorRank0| -1.0,
+                0.0,
+                1.0,
+                &evaluation_times,
+            )?;
+            evaluation_times
+                .iter()
+                .zip(solution.iter())
+                .for_each(|(t, y)| {
+                    assert!(
+                        ((-t).exp() - y).abs() < TOLERANCE
+                            || ((-t).exp() / y - 1.0).abs() < TOLERANCE
+                    )
+                });
+            Ok(())
+        }
+        #[test]
+        fn second_order_tensor_rank_0() -> Result<(), TestError> {
+            let evaluation_times = zero_to_tau::<LENGTH>();
+            let solution: TensorRank1List<2, 1, LENGTH> = Ode15s {
+                optimization: $optimization,
+                ..Default::default()
+            }
+            .integrate(
+                |_: &TensorRank0, y: &TensorRank1<2, 1>| TensorRank1::new([y[1], -y[0]]),
+                |_: &TensorRank0, _: &TensorRank1<2, 1>| {
+                    TensorRank2::new([[0.0, -1.0], [1.0, 0.0]])
+                },
+                0.0,
+                TensorRank1::new([0.0, 1.0]),
+                &evaluation_times,
+            )?;
+            evaluation_times
+                .iter()
+                .zip(solution.iter())
+                .for_each(|(t, y)| {
+                    assert!(
+                        (t.sin() - y[0]).abs() < TOLERANCE
+                            || (t.sin() / y[0] - 1.0).abs() < TOLERANCE
+                    )
+                });
+            Ok(())
+        }
+        #[test]
+        fn third_order_tensor_rank_0() -> Result<(), TestError> {
+            let evaluation_times = zero_to_tau::<LENGTH>();
+            let solution: TensorRank1List<3, 1, LENGTH> = Ode15s {
+                optimization: $optimization,
+                ..Default::default()
+            }
+            .integrate(
+                |_: &TensorRank0, y: &TensorRank1<3, 1>| TensorRank1::new([y[1], y[2], -y[1]]),
+                |_: &TensorRank0, _: &TensorRank1<3, 1>| {
+                    TensorRank2::new([[0.0, 0.0, 0.0], [1.0, 0.0, -1.0], [0.0, 1.0, 0.0]])
+                },
+                0.0,
+                TensorRank1::new([0.0, 1.0, 0.0]),
+                &evaluation_times,
+            )?;
+            evaluation_times
+                .iter()
+                .zip(solution.iter())
+                .for_each(|(t, y)| {
+                    assert!(
+                        (t.sin() - y[0]).abs() < TOLERANCE
+                            || (t.sin() / y[0] - 1.0).abs() < TOLERANCE
+                    )
+                });
+            Ok(())
+        }
+        #[test]
+        fn fourth_order_tensor_rank_0() -> Result<(), TestError> {
+            let evaluation_times = zero_to_tau::<LENGTH>();
+            let solution: TensorRank1List<4, 1, LENGTH> = Ode15s {
+                optimization: $optimization,
+                ..Default::default()
+            }
+            .integrate(
+                |_: &TensorRank0, y: &TensorRank1<4, 1>| TensorRank1::new([y[1], y[2], y[3], y[0]]),
+                |_: &TensorRank0, _: &TensorRank1<4, 1>| {
+                    TensorRank2::new([
+                        [0.0, 0.0, 0.0, 1.0],
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                    ])
+                },
+                0.0,
+                TensorRank1::new([0.0, 1.0, 0.0, -1.0]),
+                &evaluation_times,
+            )?;
+            evaluation_times
+                .iter()
+                .zip(solution.iter())
+                .for_each(|(t, y)| {
+                    assert!(
+                        (t.sin() - y[0]).abs() < TOLERANCE
+                            || (t.sin() / y[0] - 1.0).abs() < TOLERANCE
+                    )
+                });
+            Ok(())
+        }
+    };
+}
+
+mod gradient_descent {
+    use super::*;
+    test_ode15s!(Optimization::GradientDescent(GradientDescent {
+        ..Default::default()
+    }));
+}
+
+mod newton_raphson {
+    use super::*;
+    test_ode15s!(Optimization::NewtonRaphson(NewtonRaphson {
+        check_minimum: false,
+        ..Default::default()
+    }));
+}
+
+#[test]
+fn nonlinear_solve_failure_reduces_step() -> Result<(), TestError> {
+    let evaluation_times = TensorRank0List::new([0.5]);
+    let solution: TensorRank0List<1> = Ode15s {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| y.powi(2),
+        |_: &TensorRank0, y: &TensorRank0| 2.0 * y,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )?;
+    assert!((solution[0] - 2.0).abs() < TOLERANCE || (solution[0] / 2.0 - 1.0).abs() < TOLERANCE);
+    Ok(())
+}
+
+#[test]
+fn select_order_higher() {
+    let times = [0.3, 0.2, 0.1, 0.0];
+    let ys = times.map(|t: TensorRank0| t.powi(2));
+    assert_eq!(
+        Ode15s {
+            ..Default::default()
+        }
+        .select_order(1, 1e-3, &times, &ys, 0.4, &0.16, 0.1),
+        2
+    );
+}
+
+#[test]
+fn select_order_lower() {
+    let times = [0.3, 0.2, 0.1, 0.0];
+    let ys = times.map(|t: TensorRank0| 1.0 + t);
+    assert_eq!(
+        Ode15s {
+            ..Default::default()
+        }
+        .select_order(2, 1e-3, &times, &ys, 0.4, &1.4, 0.1),
+        1
+    );
+}
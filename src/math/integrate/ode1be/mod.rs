@@ -4,15 +4,18 @@ mod test;
 use super::{
     super::{
         optimize::{FirstOrder, NewtonRaphson, Optimization, SecondOrder},
-        Hessian, Tensor, TensorArray, TensorRank0, TensorRank0List,
+        Hessian, Tensor, TensorArray, TensorRank0,
     },
-    Implicit, IntegrationError, OdeSolver,
+    locate_events, record, Event, History, ImplicitVec, IntegrationError, Occurrences, OdeSolver,
 };
 use crate::{ABS_TOL, REL_TOL};
 use std::ops::{Div, Mul, Sub};
 
 /// Implicit, single-stage, first-order, variable-step, Runge-Kutta method.[^cite]
 ///
+/// The step is reduced when the nonlinear solve fails,
+/// and an error is returned if the nonlinear solve keeps failing until the step falls below its minimum value.
+///
 /// [^cite]: Also known as the backward Euler method.
 #[derive(Debug)]
 pub struct Ode1be {
@@ -20,6 +23,8 @@ pub struct Ode1be {
     pub abs_tol: TensorRank0,
     /// Multiplying factor when decreasing time steps.
     pub dec_fac: TensorRank0,
+    /// Minimum value for the time step.
+    pub dt_min: TensorRank0,
     /// Multiplying factor when increasing time steps.
     pub inc_fac: TensorRank0,
     /// Optimization algorithm for equation solving.
@@ -33,6 +38,7 @@ impl Default for Ode1be {
         Self {
             abs_tol: ABS_TOL,
             dec_fac: 0.5,
+            dt_min: ABS_TOL,
             inc_fac: 1.1,
             optimization: Optimization::NewtonRaphson(NewtonRaphson {
                 check_minimum: false,
@@ -43,72 +49,90 @@ impl Default for Ode1be {
     }
 }
 
-impl<Y, J, U, const W: usize> Implicit<Y, J, U, W> for Ode1be
+impl<Y, J> ImplicitVec<Y, J> for Ode1be
 where
     Y: Tensor + Div<J, Output = Y>,
     for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
     J: Hessian + Tensor + TensorArray,
-    U: Tensor<Item = Y> + TensorArray,
 {
-    fn integrate(
+    fn integrate_vec(
         &self,
         function: impl Fn(&TensorRank0, &Y) -> Y,
         jacobian: impl Fn(&TensorRank0, &Y) -> J,
         initial_time: TensorRank0,
         initial_condition: Y,
-        evaluation_times: &TensorRank0List<W>,
-    ) -> Result<U, IntegrationError<W>> {
+        evaluation_times: &[TensorRank0],
+        events: &[Event<Y>],
+        internal_steps: bool,
+    ) -> Result<(History<Y>, Occurrences<Y>), IntegrationError> {
         let mut e;
         let mut k_1 = function(&initial_time, &initial_condition);
         let mut k_2;
-        let mut solution = U::zero();
+        let mut occurrences = Vec::new();
+        let mut t = initial_time;
         let mut t_trial;
         let mut y_trial;
         let identity = J::identity();
-        {
-            let (mut eval_times, mut dt, mut t, mut y, mut y_sol) = self.setup(
-                initial_time,
-                initial_condition,
-                evaluation_times,
-                &mut solution,
-            )?;
-            while eval_times.peek().is_some() {
-                t_trial = t + dt;
-                y_trial = match &self.optimization {
-                    Optimization::GradientDescent(gradient_descent) => gradient_descent
-                        .minimize(
-                            |y_trial: &Y| Ok(y_trial - &y - &(&function(&t_trial, y_trial) * dt)),
-                            y.copy(),
-                            None,
-                            None,
-                        )
-                        .unwrap(),
-                    Optimization::NewtonRaphson(newton_raphson) => newton_raphson
-                        .minimize(
-                            |y_trial: &Y| Ok(y_trial - &y - &(&function(&t_trial, y_trial) * dt)),
-                            |y_trial: &Y| Ok(jacobian(&t_trial, y_trial) * -dt + &identity),
-                            y.copy(),
-                            None,
-                            None,
-                        )
-                        .unwrap(),
-                };
-                k_2 = function(&t_trial, &y_trial);
-                e = ((&k_2 - &k_1) * (dt / 2.0)).norm();
-                if e < self.abs_tol || e / y_trial.norm() < self.rel_tol {
-                    while let Some(eval_time) = eval_times.next_if(|&eval_time| t > eval_time) {
-                        *y_sol.next().ok_or("not ok")? =
-                            (&y_trial - &y) / dt * (eval_time - t) + &y;
-                    }
-                    k_1 = k_2;
-                    t += dt;
-                    dt *= self.inc_fac;
-                    y = y_trial;
-                } else {
-                    dt *= self.dec_fac;
+        let (mut eval_times, mut dt, mut history) = self.setup(
+            initial_time,
+            &initial_condition,
+            evaluation_times,
+            internal_steps,
+        )?;
+        let mut y = initial_condition;
+        while eval_times.peek().is_some() {
+            t_trial = t + dt;
+            let residual = |y_trial: &Y| Ok(y_trial - &y - &(&function(&t_trial, y_trial) * dt));
+            let residual_jacobian = |y_trial: &Y| Ok(jacobian(&t_trial, y_trial) * -dt + &identity);
+            let Ok(solution) = (match &self.optimization {
+                Optimization::ConjugateGradient(conjugate_gradient) => {
+                    conjugate_gradient.minimize(residual, y.copy(), None, None)
+                }
+                Optimization::GradientDescent(gradient_descent) => {
+                    gradient_descent.minimize(residual, y.copy(), None, None)
+                }
+                Optimization::LimitedMemoryBfgs(limited_memory_bfgs) => {
+                    limited_memory_bfgs.minimize(residual, y.copy(), None, None)
+                }
+                Optimization::NewtonRaphson(newton_raphson) => {
+                    newton_raphson.minimize(residual, residual_jacobian, y.copy(), None, None)
+                }
+            }) else {
+                dt *= self.dec_fac;
+                if dt < self.dt_min {
+                    return Err(IntegrationError::MinimumStepSizeReached(
+                        self.dt_min,
+                        format!("{:?}", &self),
+                    ));
+                }
+                continue;
+            };
+            y_trial = solution;
+            k_2 = function(&t_trial, &y_trial);
+            e = ((&k_2 - &k_1) * (dt / 2.0)).norm();
+            if e < self.abs_tol || e / y_trial.norm() < self.rel_tol {
+                let interpolate = |time: &TensorRank0| (&y_trial - &y) / dt * (time - t) + &y;
+                let (mut found, terminal_time) =
+                    locate_events(events, t, &y, t_trial, &y_trial, interpolate);
+                record(
+                    &mut history,
+                    &mut eval_times,
+                    terminal_time.unwrap_or(t_trial),
+                    internal_steps,
+                    interpolate,
+                );
+                occurrences.append(&mut found);
+                if terminal_time.is_some() {
+                    break;
                 }
+                k_1 = k_2;
+                t += dt;
+                dt *= self.inc_fac;
+                y = y_trial;
+            } else {
+                dt *= self.dec_fac;
             }
         }
-        Ok(solution)
+        Ok((history, occurrences))
     }
 }
@@ -6,9 +6,10 @@ use super::{
             Tensor, TensorArray, TensorRank0, TensorRank0List, TensorRank1, TensorRank1List,
             TensorRank2,
         },
-        test::zero_to_tau,
+        test::{test_events_and_internal_steps, zero_to_tau},
+        Implicit,
     },
-    Implicit, Ode1be,
+    ImplicitVec, IntegrationError, Ode1be,
 };
 
 const LENGTH: usize = 33;
@@ -139,6 +140,13 @@ fn first_order_tensor_rank_0_nearby_evaluation_times() -> Result<(), TestError>
     Ok(())
 }
 
+test_events_and_internal_steps!(
+    Ode1be {
+        ..Default::default()
+    },
+    |_: &TensorRank0, _: &TensorRank0| -1.0
+);
+
 macro_rules! test_ode1be {
     ($optimization: expr) => {
         #[test]
@@ -269,3 +277,44 @@ mod newton_raphson {
         ..Default::default()
     }));
 }
+
+#[test]
+fn nonlinear_solve_failure_reduces_step() -> Result<(), TestError> {
+    let evaluation_times = TensorRank0List::new([0.5]);
+    let solution: TensorRank0List<1> = Ode1be {
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| y.powi(2),
+        |_: &TensorRank0, y: &TensorRank0| 2.0 * y,
+        0.0,
+        1.0,
+        &evaluation_times,
+    )?;
+    assert!((solution[0] / 2.0 - 1.0).abs() < 1e-2);
+    Ok(())
+}
+
+#[test]
+fn minimum_step_size_reached() {
+    let evaluation_times = TensorRank0List::new([1.0]);
+    let result: Result<TensorRank0List<1>, _> = Ode1be {
+        optimization: Optimization::NewtonRaphson(NewtonRaphson {
+            check_minimum: false,
+            max_steps: 0,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+    .integrate(
+        |_: &TensorRank0, y: &TensorRank0| -y,
+        |_: &TensorRank0, _: &TensorRank0| -1.0,
+        0.0,
+        1.0,
+        &evaluation_times,
+    );
+    assert!(matches!(
+        result,
+        Err(IntegrationError::MinimumStepSizeReached(..))
+    ))
+}
@@ -2,8 +2,8 @@
 mod test;
 
 use super::{
-    super::{Tensor, TensorArray, TensorRank0, TensorRank0List},
-    Explicit, IntegrationError, OdeSolver,
+    super::{Tensor, TensorRank0},
+    locate_events, record, Event, ExplicitVec, History, IntegrationError, Occurrences, OdeSolver,
 };
 use crate::{ABS_TOL, REL_TOL};
 use std::ops::{Mul, Sub};
@@ -59,53 +59,64 @@ impl Default for Ode23 {
     }
 }
 
-impl<Y, U, const W: usize> Explicit<Y, U, W> for Ode23
+impl<Y> ExplicitVec<Y> for Ode23
 where
     Y: Tensor,
     for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
-    U: Tensor<Item = Y> + TensorArray,
 {
-    fn integrate(
+    fn integrate_vec(
         &self,
         function: impl Fn(&TensorRank0, &Y) -> Y,
         initial_time: TensorRank0,
         initial_condition: Y,
-        evaluation_times: &TensorRank0List<W>,
-    ) -> Result<U, IntegrationError<W>> {
+        evaluation_times: &[TensorRank0],
+        events: &[Event<Y>],
+        internal_steps: bool,
+    ) -> Result<(History<Y>, Occurrences<Y>), IntegrationError> {
         let mut e;
         let mut k_1 = function(&initial_time, &initial_condition);
         let mut k_2;
         let mut k_3;
         let mut k_4;
-        let mut solution = U::zero();
+        let mut occurrences = Vec::new();
+        let mut t = initial_time;
         let mut y_trial;
-        {
-            let (mut eval_times, mut dt, mut t, mut y, mut y_sol) = self.setup(
-                initial_time,
-                initial_condition,
-                evaluation_times,
-                &mut solution,
-            )?;
-            while eval_times.peek().is_some() {
-                k_2 = function(&(t + 0.5 * dt), &(&k_1 * (0.5 * dt) + &y));
-                k_3 = function(&(t + 0.75 * dt), &(&k_2 * (0.75 * dt) + &y));
-                y_trial = (&k_1 * 2.0 + &k_2 * 3.0 + &k_3 * 4.0) * (dt / 9.0) + &y;
-                k_4 = function(&(t + dt), &y_trial);
-                e = ((&k_1 * -5.0 + k_2 * 6.0 + k_3 * 8.0 + &k_4 * -9.0) * (dt / 72.0)).norm();
-                if e < self.abs_tol || e / y_trial.norm() < self.rel_tol {
-                    while let Some(eval_time) = eval_times.next_if(|&eval_time| t > eval_time) {
-                        *y_sol.next().ok_or("not ok")? =
-                            (&y_trial - &y) / dt * (eval_time - t) + &y;
-                    }
-                    k_1 = k_4;
-                    t += dt;
-                    dt *= self.inc_fac;
-                    y = y_trial;
-                } else {
-                    dt *= self.dec_fac;
+        let (mut eval_times, mut dt, mut history) = self.setup(
+            initial_time,
+            &initial_condition,
+            evaluation_times,
+            internal_steps,
+        )?;
+        let mut y = initial_condition;
+        while eval_times.peek().is_some() {
+            k_2 = function(&(t + 0.5 * dt), &(&k_1 * (0.5 * dt) + &y));
+            k_3 = function(&(t + 0.75 * dt), &(&k_2 * (0.75 * dt) + &y));
+            y_trial = (&k_1 * 2.0 + &k_2 * 3.0 + &k_3 * 4.0) * (dt / 9.0) + &y;
+            k_4 = function(&(t + dt), &y_trial);
+            e = ((&k_1 * -5.0 + k_2 * 6.0 + k_3 * 8.0 + &k_4 * -9.0) * (dt / 72.0)).norm();
+            if e < self.abs_tol || e / y_trial.norm() < self.rel_tol {
+                let interpolate = |time: &TensorRank0| (&y_trial - &y) / dt * (time - t) + &y;
+                let (mut found, terminal_time) =
+                    locate_events(events, t, &y, t + dt, &y_trial, interpolate);
+                record(
+                    &mut history,
+                    &mut eval_times,
+                    terminal_time.unwrap_or(t + dt),
+                    internal_steps,
+                    interpolate,
+                );
+                occurrences.append(&mut found);
+                if terminal_time.is_some() {
+                    break;
                 }
+                k_1 = k_4;
+                t += dt;
+                dt *= self.inc_fac;
+                y = y_trial;
+            } else {
+                dt *= self.dec_fac;
             }
         }
-        Ok(solution)
+        Ok((history, occurrences))
     }
 }
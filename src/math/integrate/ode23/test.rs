@@ -4,9 +4,10 @@ use super::{
             test::TestError, Tensor, TensorArray, TensorRank0, TensorRank0List, TensorRank1,
             TensorRank1List,
         },
-        test::zero_to_tau,
+        test::{test_events_and_internal_steps, zero_to_tau},
+        Explicit,
     },
-    Explicit, Ode23,
+    ExplicitVec, Ode23,
 };
 
 const LENGTH: usize = 33;
@@ -152,6 +153,10 @@ fn first_order_tensor_rank_0_nearby_evaluation_times() -> Result<(), TestError>
     Ok(())
 }
 
+test_events_and_internal_steps!(Ode23 {
+    ..Default::default()
+});
+
 #[test]
 fn second_order_tensor_rank_0() -> Result<(), TestError> {
     let evaluation_times = zero_to_tau::<LENGTH>();
@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod test;
+
+use super::{
+    super::{Tensor, TensorRank0},
+    locate_events, record, Event, ExplicitVec, History, IntegrationError, Occurrences, OdeSolver,
+};
+use crate::{ABS_TOL, REL_TOL};
+use std::ops::{Mul, Sub};
+
+const C_2: TensorRank0 = 0.2;
+const C_3: TensorRank0 = 0.3;
+const C_4: TensorRank0 = 0.8;
+const C_5: TensorRank0 = 8.0 / 9.0;
+
+const A_2_1: TensorRank0 = 0.2;
+const A_3_1: TensorRank0 = 3.0 / 40.0;
+const A_3_2: TensorRank0 = 9.0 / 40.0;
+const A_4_1: TensorRank0 = 44.0 / 45.0;
+const A_4_2: TensorRank0 = -56.0 / 15.0;
+const A_4_3: TensorRank0 = 32.0 / 9.0;
+const A_5_1: TensorRank0 = 19372.0 / 6561.0;
+const A_5_2: TensorRank0 = -25360.0 / 2187.0;
+const A_5_3: TensorRank0 = 64448.0 / 6561.0;
+const A_5_4: TensorRank0 = -212.0 / 729.0;
+const A_6_1: TensorRank0 = 9017.0 / 3168.0;
+const A_6_2: TensorRank0 = -355.0 / 33.0;
+const A_6_3: TensorRank0 = 46732.0 / 5247.0;
+const A_6_4: TensorRank0 = 49.0 / 176.0;
+const A_6_5: TensorRank0 = -5103.0 / 18656.0;
+const A_7_1: TensorRank0 = 35.0 / 384.0;
+const A_7_3: TensorRank0 = 500.0 / 1113.0;
+const A_7_4: TensorRank0 = 125.0 / 192.0;
+const A_7_5: TensorRank0 = -2187.0 / 6784.0;
+const A_7_6: TensorRank0 = 11.0 / 84.0;
+
+const E_1: TensorRank0 = 71.0 / 57600.0;
+const E_3: TensorRank0 = -71.0 / 16695.0;
+const E_4: TensorRank0 = 71.0 / 1920.0;
+const E_5: TensorRank0 = -17253.0 / 339200.0;
+const E_6: TensorRank0 = 22.0 / 525.0;
+const E_7: TensorRank0 = -1.0 / 40.0;
+
+const P_1: [TensorRank0; 4] = [
+    1.0,
+    -8048581381.0 / 2820520608.0,
+    8663915743.0 / 2820520608.0,
+    -12715105075.0 / 11282082432.0,
+];
+const P_3: [TensorRank0; 4] = [
+    0.0,
+    131558114200.0 / 32700410799.0,
+    -68118460800.0 / 10900136933.0,
+    87487479700.0 / 32700410799.0,
+];
+const P_4: [TensorRank0; 4] = [
+    0.0,
+    -1754552775.0 / 470086768.0,
+    14199869525.0 / 1410260304.0,
+    -10690763975.0 / 1880347072.0,
+];
+const P_5: [TensorRank0; 4] = [
+    0.0,
+    127303824393.0 / 49829197408.0,
+    -318862633887.0 / 49829197408.0,
+    701980252875.0 / 199316789632.0,
+];
+const P_6: [TensorRank0; 4] = [
+    0.0,
+    -282668133.0 / 205662961.0,
+    2019193451.0 / 616988883.0,
+    -1453857185.0 / 822651844.0,
+];
+const P_7: [TensorRank0; 4] = [
+    0.0,
+    40617522.0 / 29380423.0,
+    -110615467.0 / 29380423.0,
+    69997945.0 / 29380423.0,
+];
+
+/// Explicit, six-stage, fifth-order, variable-step, Runge-Kutta method.[^cite]
+///
+/// [^cite]: J.R. Dormand and P.J. Prince, [J. Comput. Appl. Math. **6**, 19 (1980)](https://doi.org/10.1016/0771-050X(80)90013-3).
+///
+/// ```math
+/// \frac{dy}{dt} = f(t, y)
+/// ```
+/// ```math
+/// t_{n+1} = t_n + h
+/// ```
+/// ```math
+/// k_1 = f(t_n, y_n)
+/// ```
+/// ```math
+/// k_2 = f(t_n + \tfrac{1}{5} h, y_n + \tfrac{1}{5} h k_1)
+/// ```
+/// ```math
+/// k_3 = f(t_n + \tfrac{3}{10} h, y_n + \tfrac{3}{40} h k_1 + \tfrac{9}{40} h k_2)
+/// ```
+/// ```math
+/// k_4 = f(t_n + \tfrac{4}{5} h, y_n + \tfrac{44}{45} h k_1 - \tfrac{56}{15} h k_2 + \tfrac{32}{9} h k_3)
+/// ```
+/// ```math
+/// k_5 = f(t_n + \tfrac{8}{9} h, y_n + \tfrac{19372}{6561} h k_1 - \tfrac{25360}{2187} h k_2 + \tfrac{64448}{6561} h k_3 - \tfrac{212}{729} h k_4)
+/// ```
+/// ```math
+/// k_6 = f(t_n + h, y_n + \tfrac{9017}{3168} h k_1 - \tfrac{355}{33} h k_2 + \tfrac{46732}{5247} h k_3 + \tfrac{49}{176} h k_4 - \tfrac{5103}{18656} h k_5)
+/// ```
+/// ```math
+/// y_{n+1} = y_n + h\left(\frac{35}{384}\,k_1 + \frac{500}{1113}\,k_3 + \frac{125}{192}\,k_4 - \frac{2187}{6784}\,k_5 + \frac{11}{84}\,k_6\right)
+/// ```
+/// ```math
+/// k_7 = f(t_{n+1}, y_{n+1})
+/// ```
+/// ```math
+/// e_{n+1} = h\left(\frac{71}{57600}\,k_1 - \frac{71}{16695}\,k_3 + \frac{71}{1920}\,k_4 - \frac{17253}{339200}\,k_5 + \frac{22}{525}\,k_6 - \frac{1}{40}\,k_7\right)
+/// ```
+///
+/// Since $`k_7`$ becomes $`k_1`$ of the next step, only six function evaluations are needed per step.
+/// The solution at the evaluation times is obtained from the fourth-order continuous extension of the method.
+#[derive(Debug)]
+pub struct Ode45 {
+    /// Absolute error tolerance.
+    pub abs_tol: TensorRank0,
+    /// Multiplying factor when decreasing time steps.
+    pub dec_fac: TensorRank0,
+    /// Minimum value for the time step.
+    pub dt_min: TensorRank0,
+    /// Multiplying factor when increasing time steps.
+    pub inc_fac: TensorRank0,
+    /// Relative error tolerance.
+    pub rel_tol: TensorRank0,
+}
+
+impl Default for Ode45 {
+    fn default() -> Self {
+        Self {
+            abs_tol: ABS_TOL,
+            dec_fac: 0.5,
+            dt_min: ABS_TOL,
+            inc_fac: 1.1,
+            rel_tol: REL_TOL,
+        }
+    }
+}
+
+impl<Y> ExplicitVec<Y> for Ode45
+where
+    Y: Tensor,
+    for<'a> &'a Y: Mul<TensorRank0, Output = Y> + Sub<&'a Y, Output = Y>,
+{
+    fn integrate_vec(
+        &self,
+        function: impl Fn(&TensorRank0, &Y) -> Y,
+        initial_time: TensorRank0,
+        initial_condition: Y,
+        evaluation_times: &[TensorRank0],
+        events: &[Event<Y>],
+        internal_steps: bool,
+    ) -> Result<(History<Y>, Occurrences<Y>), IntegrationError> {
+        let mut e;
+        let mut k_1 = function(&initial_time, &initial_condition);
+        let mut k_2;
+        let mut k_3;
+        let mut k_4;
+        let mut k_5;
+        let mut k_6;
+        let mut k_7;
+        let mut occurrences = Vec::new();
+        let mut t = initial_time;
+        let mut y_trial;
+        let (mut eval_times, mut dt, mut history) = self.setup(
+            initial_time,
+            &initial_condition,
+            evaluation_times,
+            internal_steps,
+        )?;
+        let mut y = initial_condition;
+        while eval_times.peek().is_some() {
+            k_2 = function(&(t + C_2 * dt), &(&k_1 * (A_2_1 * dt) + &y));
+            k_3 = function(&(t + C_3 * dt), &((&k_1 * A_3_1 + &k_2 * A_3_2) * dt + &y));
+            k_4 = function(
+                &(t + C_4 * dt),
+                &((&k_1 * A_4_1 + &k_2 * A_4_2 + &k_3 * A_4_3) * dt + &y),
+            );
+            k_5 = function(
+                &(t + C_5 * dt),
+                &((&k_1 * A_5_1 + &k_2 * A_5_2 + &k_3 * A_5_3 + &k_4 * A_5_4) * dt + &y),
+            );
+            k_6 = function(
+                &(t + dt),
+                &((&k_1 * A_6_1 + &k_2 * A_6_2 + &k_3 * A_6_3 + &k_4 * A_6_4 + &k_5 * A_6_5) * dt
+                    + &y),
+            );
+            y_trial = (&k_1 * A_7_1 + &k_3 * A_7_3 + &k_4 * A_7_4 + &k_5 * A_7_5 + &k_6 * A_7_6)
+                * dt
+                + &y;
+            k_7 = function(&(t + dt), &y_trial);
+            e = ((&k_1 * E_1 + &k_3 * E_3 + &k_4 * E_4 + &k_5 * E_5 + &k_6 * E_6 + &k_7 * E_7)
+                * dt)
+                .norm();
+            if !e.is_finite() {
+                return Err(IntegrationError::NonFiniteErrorEstimate(
+                    t,
+                    format!("{:?}", &self),
+                ));
+            }
+            if e < self.abs_tol || e / y_trial.norm() < self.rel_tol {
+                let dense_output = |time: &TensorRank0| {
+                    let theta = (time - t) / dt;
+                    (&k_1 * interpolant(&P_1, theta)
+                        + &k_3 * interpolant(&P_3, theta)
+                        + &k_4 * interpolant(&P_4, theta)
+                        + &k_5 * interpolant(&P_5, theta)
+                        + &k_6 * interpolant(&P_6, theta)
+                        + &k_7 * interpolant(&P_7, theta))
+                        * dt
+                        + &y
+                };
+                let (mut found, terminal_time) =
+                    locate_events(events, t, &y, t + dt, &y_trial, dense_output);
+                record(
+                    &mut history,
+                    &mut eval_times,
+                    terminal_time.unwrap_or(t + dt),
+                    internal_steps,
+                    dense_output,
+                );
+                occurrences.append(&mut found);
+                if terminal_time.is_some() {
+                    break;
+                }
+                k_1 = k_7;
+                t += dt;
+                dt *= self.inc_fac;
+                y = y_trial;
+            } else {
+                dt *= self.dec_fac;
+                if dt < self.dt_min {
+                    return Err(IntegrationError::MinimumStepSizeReached(
+                        self.dt_min,
+                        format!("{:?}", &self),
+                    ));
+                }
+            }
+        }
+        Ok((history, occurrences))
+    }
+}
+
+fn interpolant(coefficients: &[TensorRank0; 4], theta: TensorRank0) -> TensorRank0 {
+    theta
+        * (coefficients[0]
+            + theta * (coefficients[1] + theta * (coefficients[2] + theta * coefficients[3])))
+}
@@ -8,10 +8,166 @@ pub fn zero_to_tau<const W: usize>() -> TensorRank0List<W> {
         .collect()
 }
 
-impl<const W: usize> From<IntegrationError<W>> for TestError {
-    fn from(error: IntegrationError<W>) -> TestError {
+impl From<IntegrationError> for TestError {
+    fn from(error: IntegrationError) -> TestError {
         TestError {
             message: format!("{}", error),
         }
     }
 }
+
+macro_rules! test_events_and_internal_steps {
+    ($integrator: expr $(, $jacobian: expr)?) => {
+        mod events_and_internal_steps {
+            use super::*;
+            use crate::math::integrate::{Event, Occurrences};
+            use std::f64::consts::{LN_2, TAU};
+            fn assert_solution(t: &TensorRank0, y: &TensorRank0) {
+                assert!(((-t).exp() - y).abs() < TOLERANCE || ((-t).exp() / y - 1.0).abs() < TOLERANCE)
+            }
+            #[test]
+            fn events() -> Result<(), TestError> {
+                let evaluation_times = zero_to_tau::<LENGTH>();
+                let (solution, length, occurrences): (
+                    TensorRank0List<LENGTH>,
+                    usize,
+                    Occurrences<TensorRank0>,
+                ) = $integrator.integrate_with_events(
+                    |_: &TensorRank0, y: &TensorRank0| -y,
+                    $($jacobian,)?
+                    0.0,
+                    1.0,
+                    &evaluation_times,
+                    &[
+                        Event {
+                            function: &|_: &TensorRank0, y: &TensorRank0| y - 0.5,
+                            terminal: false,
+                        },
+                        Event {
+                            function: &|t: &TensorRank0, _: &TensorRank0| t - 0.5,
+                            terminal: false,
+                        },
+                    ],
+                )?;
+                assert_eq!(length, LENGTH);
+                assert_eq!(occurrences.len(), 2);
+                assert_eq!(occurrences[0].0, 1);
+                assert!((occurrences[0].1 - 0.5).abs() < TOLERANCE);
+                assert_eq!(occurrences[1].0, 0);
+                assert!((occurrences[1].1 - LN_2).abs() < TOLERANCE);
+                evaluation_times
+                    .iter()
+                    .zip(solution.iter())
+                    .for_each(|(t, y)| assert_solution(t, y));
+                Ok(())
+            }
+            #[test]
+            fn events_within_one_step() -> Result<(), TestError> {
+                let (_, occurrences) = $integrator.integrate_vec(
+                    |_: &TensorRank0, y: &TensorRank0| -y,
+                    $($jacobian,)?
+                    0.0,
+                    1.0,
+                    &[1.0],
+                    &[
+                        Event {
+                            function: &|t: &TensorRank0, _: &TensorRank0| (t - 0.3) * (t - 0.45),
+                            terminal: false,
+                        },
+                        Event {
+                            function: &|t: &TensorRank0, _: &TensorRank0| t * (t - 0.6),
+                            terminal: false,
+                        },
+                    ],
+                    false,
+                )?;
+                assert_eq!(occurrences.len(), 3);
+                occurrences
+                    .iter()
+                    .zip([(0, 0.3), (0, 0.45), (1, 0.6)])
+                    .for_each(|((index, time, _), (event, event_time))| {
+                        assert_eq!(index, &event);
+                        assert!((time - event_time).abs() < TOLERANCE)
+                    });
+                Ok(())
+            }
+            #[test]
+            fn terminal_event() -> Result<(), TestError> {
+                let evaluation_times = zero_to_tau::<LENGTH>();
+                let (solution, length, occurrences): (
+                    TensorRank0List<LENGTH>,
+                    usize,
+                    Occurrences<TensorRank0>,
+                ) = $integrator.integrate_with_events(
+                    |_: &TensorRank0, y: &TensorRank0| -y,
+                    $($jacobian,)?
+                    0.0,
+                    1.0,
+                    &evaluation_times,
+                    &[Event {
+                        function: &|_: &TensorRank0, y: &TensorRank0| y - 0.5,
+                        terminal: true,
+                    }],
+                )?;
+                assert_eq!(occurrences.len(), 1);
+                assert_eq!(occurrences[0].0, 0);
+                assert!((occurrences[0].1 - LN_2).abs() < TOLERANCE);
+                assert!((occurrences[0].2 - 0.5).abs() < TOLERANCE);
+                assert_eq!(
+                    length,
+                    evaluation_times.iter().filter(|t| t <= &&LN_2).count()
+                );
+                evaluation_times
+                    .iter()
+                    .zip(solution.iter())
+                    .take(length)
+                    .for_each(|(t, y)| assert_solution(t, y));
+                Ok(())
+            }
+            #[test]
+            fn vec() -> Result<(), TestError> {
+                let evaluation_times = zero_to_tau::<LENGTH>().0.to_vec();
+                let (history, occurrences) = $integrator.integrate_vec(
+                    |_: &TensorRank0, y: &TensorRank0| -y,
+                    $($jacobian,)?
+                    0.0,
+                    1.0,
+                    &evaluation_times,
+                    &[],
+                    false,
+                )?;
+                assert!(occurrences.is_empty());
+                assert_eq!(history.len(), evaluation_times.len());
+                history
+                    .iter()
+                    .zip(evaluation_times.iter())
+                    .for_each(|((t, y), time)| {
+                        assert_eq!(t, time);
+                        assert_solution(t, y)
+                    });
+                Ok(())
+            }
+            #[test]
+            fn internal_steps() -> Result<(), TestError> {
+                let (history, _) = $integrator.integrate_vec(
+                    |_: &TensorRank0, y: &TensorRank0| -y,
+                    $($jacobian,)?
+                    0.0,
+                    1.0,
+                    &[TAU],
+                    &[],
+                    true,
+                )?;
+                assert!(history.len() > 2);
+                assert_eq!(history[0].0, 0.0);
+                assert_eq!(history[history.len() - 1].0, TAU);
+                history
+                    .windows(2)
+                    .for_each(|pair| assert!(pair[1].0 > pair[0].0));
+                history.iter().for_each(|(t, y)| assert_solution(t, y));
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use test_events_and_internal_steps;
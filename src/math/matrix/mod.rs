@@ -1,2 +1,3 @@
+pub mod sparse;
 pub mod square;
 pub mod vector;
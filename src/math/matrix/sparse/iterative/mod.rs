@@ -0,0 +1,502 @@
+#[cfg(test)]
+mod test;
+
+use super::{SparseMatrix, SparseRows};
+use crate::{
+    math::{tensor::TensorError, TensorRank0},
+    REL_TOL,
+};
+
+/// Possible iterative methods for linear systems.
+#[derive(Debug)]
+pub enum IterativeMethod {
+    /// For symmetric positive-definite matrices.
+    ConjugateGradient,
+    /// For general matrices.
+    Gmres,
+    /// For symmetric matrices.
+    Minres,
+}
+
+/// Possible preconditioners for iterative methods.
+#[derive(Debug)]
+pub enum Preconditioner {
+    /// Incomplete Cholesky factorization with the sparsity pattern of the matrix.
+    IncompleteCholesky,
+    /// Incomplete LU factorization with the sparsity pattern of the matrix.
+    IncompleteLu,
+    /// Inverse of the diagonal of the matrix.
+    Jacobi,
+    None,
+}
+
+/// Preconditioned iterative solver for sparse linear systems.
+#[derive(Debug)]
+pub struct IterativeSolver {
+    /// Maximum number of steps.
+    pub max_steps: usize,
+    /// Iterative method.
+    pub method: IterativeMethod,
+    /// Preconditioner.
+    pub preconditioner: Preconditioner,
+    /// Relative error tolerance on the residual norm.
+    pub rel_tol: TensorRank0,
+    /// Number of steps between restarts of GMRES.
+    pub restart: usize,
+}
+
+impl Default for IterativeSolver {
+    fn default() -> Self {
+        Self {
+            max_steps: 1000,
+            method: IterativeMethod::Gmres,
+            preconditioner: Preconditioner::IncompleteLu,
+            rel_tol: REL_TOL,
+            restart: 50,
+        }
+    }
+}
+
+impl IterativeSolver {
+    /// Solves the linear system with the given row-major dense matrix and right-hand side.
+    ///
+    /// The nonzero entries and the diagonal are stored in a sparse matrix that is passed to [`solve`](Self::solve).
+    pub fn solve_dense(
+        &self,
+        entries: &[TensorRank0],
+        rhs: &[TensorRank0],
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        let size = rhs.len();
+        assert_eq!(entries.len(), size * size);
+        let sparsity: Vec<Vec<usize>> = entries
+            .chunks(size)
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(j, entry)| i == j || entry != &0.0)
+                    .map(|(j, _)| j)
+                    .collect()
+            })
+            .collect();
+        let mut matrix = SparseMatrix::zero(&sparsity);
+        sparsity.iter().enumerate().for_each(|(i, columns)| {
+            columns
+                .iter()
+                .for_each(|&j| matrix[[i, j]] = entries[size * i + j])
+        });
+        self.solve(&matrix, rhs)
+    }
+    /// Solves the linear system with the given matrix and right-hand side.
+    pub fn solve(
+        &self,
+        matrix: &SparseMatrix,
+        rhs: &[TensorRank0],
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        let preconditioner = Factorization::new(matrix, &self.preconditioner)?;
+        let tolerance = self.rel_tol * norm(rhs);
+        match self.method {
+            IterativeMethod::ConjugateGradient => {
+                self.conjugate_gradient(matrix, &preconditioner, rhs, tolerance)
+            }
+            IterativeMethod::Gmres => self.gmres(matrix, &preconditioner, rhs, tolerance),
+            IterativeMethod::Minres => self.minres(matrix, &preconditioner, rhs, tolerance),
+        }
+    }
+    fn conjugate_gradient(
+        &self,
+        matrix: &SparseMatrix,
+        preconditioner: &Factorization,
+        rhs: &[TensorRank0],
+        tolerance: TensorRank0,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        let mut solution = vec![0.0; rhs.len()];
+        let mut residual = rhs.to_vec();
+        let mut preconditioned = preconditioner.apply(&residual);
+        let mut direction = preconditioned.clone();
+        let mut product = dot(&residual, &preconditioned);
+        for _ in 0..self.max_steps {
+            if norm(&residual) <= tolerance {
+                return Ok(solution);
+            }
+            let matrix_direction = matrix.multiply(&direction);
+            let curvature = dot(&direction, &matrix_direction);
+            if curvature <= 0.0 {
+                return Err(TensorError::NotPositiveDefinite);
+            }
+            let step_size = product / curvature;
+            axpy(&mut solution, step_size, &direction);
+            axpy(&mut residual, -step_size, &matrix_direction);
+            preconditioned = preconditioner.apply(&residual);
+            let new_product = dot(&residual, &preconditioned);
+            let beta = new_product / product;
+            product = new_product;
+            direction.iter_mut().zip(preconditioned.iter()).for_each(
+                |(direction_i, preconditioned_i)| {
+                    *direction_i = preconditioned_i + beta * *direction_i
+                },
+            );
+        }
+        if norm(&residual) <= tolerance {
+            Ok(solution)
+        } else {
+            Err(TensorError::MaximumStepsReached(self.max_steps))
+        }
+    }
+    fn gmres(
+        &self,
+        matrix: &SparseMatrix,
+        preconditioner: &Factorization,
+        rhs: &[TensorRank0],
+        tolerance: TensorRank0,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        //
+        // The preconditioner is applied on the right so that the true residual norm is minimized.
+        // The Hessenberg matrix is reduced to upper triangular form by Givens rotations as it is built.
+        //
+        let restart = self.restart.max(1);
+        let mut solution = vec![0.0; rhs.len()];
+        let mut steps = 0;
+        loop {
+            let mut residual = matrix.multiply(&solution);
+            residual
+                .iter_mut()
+                .zip(rhs.iter())
+                .for_each(|(residual_i, rhs_i)| *residual_i = rhs_i - *residual_i);
+            let residual_norm = norm(&residual);
+            if residual_norm <= tolerance {
+                return Ok(solution);
+            } else if steps >= self.max_steps {
+                return Err(TensorError::MaximumStepsReached(self.max_steps));
+            }
+            let mut basis = vec![residual
+                .iter()
+                .map(|residual_i| residual_i / residual_norm)
+                .collect::<Vec<_>>()];
+            let mut hessenberg: Vec<Vec<TensorRank0>> = Vec::new();
+            let mut rotations: Vec<(TensorRank0, TensorRank0)> = Vec::new();
+            let mut projected = vec![residual_norm];
+            while basis.len() <= restart && steps < self.max_steps {
+                let j = basis.len() - 1;
+                let mut vector = matrix.multiply(&preconditioner.apply(&basis[j]));
+                let mut column: Vec<TensorRank0> = basis
+                    .iter()
+                    .map(|basis_i| {
+                        let entry = dot(&vector, basis_i);
+                        axpy(&mut vector, -entry, basis_i);
+                        entry
+                    })
+                    .collect();
+                let vector_norm = norm(&vector);
+                column.push(vector_norm);
+                rotations
+                    .iter()
+                    .enumerate()
+                    .for_each(|(i, (cosine, sine))| {
+                        let entry = cosine * column[i] + sine * column[i + 1];
+                        column[i + 1] = cosine * column[i + 1] - sine * column[i];
+                        column[i] = entry
+                    });
+                let hypotenuse = column[j].hypot(column[j + 1]);
+                let (cosine, sine) = if hypotenuse == 0.0 {
+                    (1.0, 0.0)
+                } else {
+                    (column[j] / hypotenuse, column[j + 1] / hypotenuse)
+                };
+                column[j] = hypotenuse;
+                column.pop();
+                projected.push(-sine * projected[j]);
+                projected[j] *= cosine;
+                rotations.push((cosine, sine));
+                hessenberg.push(column);
+                steps += 1;
+                if projected[j + 1].abs() <= tolerance || vector_norm == 0.0 {
+                    break;
+                }
+                basis.push(
+                    vector
+                        .iter()
+                        .map(|vector_i| vector_i / vector_norm)
+                        .collect(),
+                );
+            }
+            let size = hessenberg.len();
+            let mut coefficients = vec![0.0; size];
+            (0..size).rev().for_each(|i| {
+                coefficients[i] = (projected[i]
+                    - (i + 1..size)
+                        .map(|j| hessenberg[j][i] * coefficients[j])
+                        .sum::<TensorRank0>())
+                    / hessenberg[i][i]
+            });
+            let mut update = vec![0.0; rhs.len()];
+            coefficients
+                .iter()
+                .zip(basis.iter())
+                .for_each(|(coefficient, basis_i)| axpy(&mut update, *coefficient, basis_i));
+            axpy(&mut solution, 1.0, &preconditioner.apply(&update));
+            if solution.iter().any(|entry| !entry.is_finite()) {
+                return Err(TensorError::Singular);
+            }
+        }
+    }
+    fn minres(
+        &self,
+        matrix: &SparseMatrix,
+        preconditioner: &Factorization,
+        rhs: &[TensorRank0],
+        tolerance: TensorRank0,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        //
+        // The method of Paige and Saunders, which requires a symmetric positive-definite preconditioner.
+        // The residual is monitored in the norm induced by the inverse of the preconditioner,
+        // so the tolerance is made relative to the corresponding norm of the right-hand side.
+        //
+        let mut solution = vec![0.0; rhs.len()];
+        let mut residual_1 = rhs.to_vec();
+        let mut residual_2 = rhs.to_vec();
+        let mut vector = preconditioner.apply(rhs);
+        let initial_beta = preconditioned_norm(&residual_1, &vector)?;
+        if initial_beta == 0.0 {
+            return Ok(solution);
+        }
+        let tolerance = tolerance / norm(rhs) * initial_beta;
+        let mut beta = initial_beta;
+        let mut old_beta = 0.0;
+        let mut delta_bar = 0.0;
+        let mut epsilon = 0.0;
+        let mut phi_bar = initial_beta;
+        let mut cosine = -1.0;
+        let mut sine = 0.0;
+        let mut direction = vec![0.0; rhs.len()];
+        let mut direction_2 = vec![0.0; rhs.len()];
+        for step in 0..self.max_steps {
+            let lanczos: Vec<TensorRank0> = vector.iter().map(|entry| entry / beta).collect();
+            vector = matrix.multiply(&lanczos);
+            if step > 0 {
+                axpy(&mut vector, -beta / old_beta, &residual_1);
+            }
+            let alpha = dot(&lanczos, &vector);
+            axpy(&mut vector, -alpha / beta, &residual_2);
+            residual_1 = residual_2;
+            residual_2 = vector;
+            vector = preconditioner.apply(&residual_2);
+            old_beta = beta;
+            beta = preconditioned_norm(&residual_2, &vector)?;
+            let old_epsilon = epsilon;
+            let delta = cosine * delta_bar + sine * alpha;
+            let gamma_bar = sine * delta_bar - cosine * alpha;
+            epsilon = sine * beta;
+            delta_bar = -cosine * beta;
+            let gamma = gamma_bar.hypot(beta).max(TensorRank0::EPSILON);
+            cosine = gamma_bar / gamma;
+            sine = beta / gamma;
+            let phi = cosine * phi_bar;
+            phi_bar *= sine;
+            let direction_1 = direction_2;
+            direction_2 = direction;
+            direction = lanczos
+                .iter()
+                .zip(direction_1.iter().zip(direction_2.iter()))
+                .map(|(lanczos_i, (direction_1_i, direction_2_i))| {
+                    (lanczos_i - old_epsilon * direction_1_i - delta * direction_2_i) / gamma
+                })
+                .collect();
+            axpy(&mut solution, phi, &direction);
+            if phi_bar <= tolerance || beta == 0.0 {
+                return Ok(solution);
+            }
+        }
+        Err(TensorError::MaximumStepsReached(self.max_steps))
+    }
+}
+
+const MAXIMUM_SHIFTS: usize = 25;
+
+enum Factorization {
+    Cholesky(SparseRows),
+    Diagonal(Vec<TensorRank0>),
+    Identity,
+    Lu(SparseRows),
+}
+
+impl Factorization {
+    fn new(matrix: &SparseMatrix, preconditioner: &Preconditioner) -> Result<Self, TensorError> {
+        match preconditioner {
+            Preconditioner::IncompleteCholesky => {
+                //
+                // The diagonal is increasingly shifted when the factorization breaks down,
+                // which happens for positive-definite matrices that are not M-matrices.
+                // The factorization succeeds once the shifted matrix is diagonally dominant,
+                // so the matrix is reported as indefinite if the maximum shift is exceeded.
+                //
+                if (0..matrix.len()).any(|i| matrix[[i, i]] <= 0.0) {
+                    return Err(TensorError::NotPositiveDefinite);
+                }
+                let mut shift = 0.0;
+                for _ in 0..=MAXIMUM_SHIFTS {
+                    if let Ok(rows) = incomplete_cholesky(matrix, shift) {
+                        return Ok(Self::Cholesky(rows));
+                    }
+                    shift = (2.0 * shift).max(1e-3)
+                }
+                Err(TensorError::NotPositiveDefinite)
+            }
+            Preconditioner::IncompleteLu => {
+                //
+                // The unit diagonal of the lower factor is omitted, and fill-in outside the sparsity pattern is discarded.
+                //
+                let mut rows = matrix.rows();
+                for i in 0..rows.len() {
+                    let columns: Vec<usize> = rows[i].range(..i).map(|(&k, _)| k).collect();
+                    for k in columns {
+                        let pivot = rows[k].get(&k).copied().unwrap_or(0.0);
+                        if pivot == 0.0 {
+                            return Err(TensorError::Singular);
+                        }
+                        let factor = rows[i][&k] / pivot;
+                        rows[i].insert(k, factor);
+                        let updates: Vec<(usize, TensorRank0)> = rows[k]
+                            .range(k + 1..)
+                            .filter(|(j, _)| rows[i].contains_key(j))
+                            .map(|(&j, row_k_j)| (j, factor * row_k_j))
+                            .collect();
+                        updates
+                            .into_iter()
+                            .for_each(|(j, update)| *rows[i].get_mut(&j).unwrap() -= update);
+                    }
+                    if rows[i].get(&i).copied().unwrap_or(0.0) == 0.0 {
+                        return Err(TensorError::Singular);
+                    }
+                }
+                Ok(Self::Lu(rows))
+            }
+            Preconditioner::Jacobi => (0..matrix.len())
+                .map(|i| {
+                    let diagonal = matrix[[i, i]];
+                    if diagonal == 0.0 {
+                        Err(TensorError::Singular)
+                    } else {
+                        Ok(1.0 / diagonal)
+                    }
+                })
+                .collect::<Result<_, _>>()
+                .map(Self::Diagonal),
+            Preconditioner::None => Ok(Self::Identity),
+        }
+    }
+    fn apply(&self, vector: &[TensorRank0]) -> Vec<TensorRank0> {
+        match self {
+            Self::Cholesky(rows) => {
+                let mut solution = vector.to_vec();
+                (0..rows.len()).for_each(|i| {
+                    solution[i] = (solution[i]
+                        - rows[i]
+                            .range(..i)
+                            .map(|(&j, row_i_j)| row_i_j * solution[j])
+                            .sum::<TensorRank0>())
+                        / rows[i][&i]
+                });
+                (0..rows.len()).rev().for_each(|i| {
+                    solution[i] /= rows[i][&i];
+                    let entry = solution[i];
+                    rows[i]
+                        .range(..i)
+                        .for_each(|(&j, row_i_j)| solution[j] -= row_i_j * entry)
+                });
+                solution
+            }
+            Self::Diagonal(inverse) => vector
+                .iter()
+                .zip(inverse.iter())
+                .map(|(vector_i, inverse_i)| vector_i * inverse_i)
+                .collect(),
+            Self::Identity => vector.to_vec(),
+            Self::Lu(rows) => {
+                let mut solution = vector.to_vec();
+                (0..rows.len()).for_each(|i| {
+                    solution[i] -= rows[i]
+                        .range(..i)
+                        .map(|(&j, row_i_j)| row_i_j * solution[j])
+                        .sum::<TensorRank0>()
+                });
+                (0..rows.len()).rev().for_each(|i| {
+                    solution[i] = (solution[i]
+                        - rows[i]
+                            .range(i + 1..)
+                            .map(|(&j, row_i_j)| row_i_j * solution[j])
+                            .sum::<TensorRank0>())
+                        / rows[i][&i]
+                });
+                solution
+            }
+        }
+    }
+}
+
+fn incomplete_cholesky(
+    matrix: &SparseMatrix,
+    shift: TensorRank0,
+) -> Result<SparseRows, TensorError> {
+    //
+    // The lower factor has the sparsity pattern of the lower triangle of the matrix.
+    //
+    let mut rows: SparseRows = matrix
+        .rows()
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| row.into_iter().filter(|&(j, _)| j <= i).collect())
+        .collect();
+    for i in 0..rows.len() {
+        let columns: Vec<usize> = rows[i].range(..i).map(|(&k, _)| k).collect();
+        for k in columns {
+            let sum: TensorRank0 = rows[i]
+                .range(..k)
+                .filter_map(|(j, row_i_j)| rows[k].get(j).map(|row_k_j| row_i_j * row_k_j))
+                .sum();
+            let entry = (rows[i][&k] - sum) / rows[k][&k];
+            rows[i].insert(k, entry);
+        }
+        let pivot = (1.0 + shift) * rows[i].get(&i).copied().unwrap_or(0.0)
+            - rows[i]
+                .range(..i)
+                .map(|(_, row_i_k)| row_i_k.powi(2))
+                .sum::<TensorRank0>();
+        if pivot <= 0.0 {
+            return Err(TensorError::NotPositiveDefinite);
+        }
+        rows[i].insert(i, pivot.sqrt());
+    }
+    Ok(rows)
+}
+
+fn axpy(vector: &mut [TensorRank0], scale: TensorRank0, other: &[TensorRank0]) {
+    vector
+        .iter_mut()
+        .zip(other.iter())
+        .for_each(|(vector_i, other_i)| *vector_i += scale * other_i)
+}
+
+fn dot(vector: &[TensorRank0], other: &[TensorRank0]) -> TensorRank0 {
+    vector
+        .iter()
+        .zip(other.iter())
+        .map(|(vector_i, other_i)| vector_i * other_i)
+        .sum()
+}
+
+fn norm(vector: &[TensorRank0]) -> TensorRank0 {
+    dot(vector, vector).sqrt()
+}
+
+fn preconditioned_norm(
+    residual: &[TensorRank0],
+    preconditioned: &[TensorRank0],
+) -> Result<TensorRank0, TensorError> {
+    let product = dot(residual, preconditioned);
+    if product < 0.0 {
+        Err(TensorError::NotPositiveDefinite)
+    } else {
+        Ok(product.sqrt())
+    }
+}
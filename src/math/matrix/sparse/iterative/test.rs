@@ -0,0 +1,234 @@
+use super::{
+    super::{SparseMatrix, SquareMatrix, Vector},
+    IterativeMethod, IterativeSolver, Preconditioner, TensorError, TensorRank0,
+};
+use crate::math::{Tensor, TensorVec};
+
+const LEN: usize = 25;
+
+fn tridiagonal(lower: TensorRank0, diagonal: TensorRank0, upper: TensorRank0) -> SparseMatrix {
+    let mut sparse_matrix = SparseMatrix::zero(
+        &(0..LEN)
+            .map(|i| (i.saturating_sub(1)..(i + 2).min(LEN)).collect())
+            .collect::<Vec<_>>(),
+    );
+    (0..LEN).for_each(|i| {
+        sparse_matrix[[i, i]] = diagonal;
+        if i > 0 {
+            sparse_matrix[[i, i - 1]] = lower
+        }
+        if i + 1 < LEN {
+            sparse_matrix[[i, i + 1]] = upper
+        }
+    });
+    sparse_matrix
+}
+
+fn indefinite() -> SparseMatrix {
+    let mut sparse_matrix = tridiagonal(-1.0, 3.0, -1.0);
+    (0..LEN)
+        .step_by(2)
+        .for_each(|i| sparse_matrix[[i, i]] = -3.0);
+    sparse_matrix
+}
+
+fn nonsymmetric() -> SparseMatrix {
+    tridiagonal(-1.5, 3.0, -0.5)
+}
+
+fn symmetric() -> SparseMatrix {
+    tridiagonal(-1.0, 3.0, -1.0)
+}
+
+fn solve(
+    sparse_matrix: SparseMatrix,
+    method: IterativeMethod,
+    preconditioner: Preconditioner,
+) -> Result<(), TensorError> {
+    let exact: Vector = (0..LEN).map(|i| 1.0 + i as TensorRank0).collect();
+    let rhs = &sparse_matrix * &exact;
+    let solution: Vector = IterativeSolver {
+        method,
+        preconditioner,
+        ..Default::default()
+    }
+    .solve(&sparse_matrix, &rhs.iter().copied().collect::<Vec<_>>())?
+    .into_iter()
+    .collect();
+    assert!((solution - &exact).norm() < 1e-10 * exact.norm());
+    Ok(())
+}
+
+mod conjugate_gradient {
+    use super::*;
+    #[test]
+    fn incomplete_cholesky() -> Result<(), TensorError> {
+        solve(
+            symmetric(),
+            IterativeMethod::ConjugateGradient,
+            Preconditioner::IncompleteCholesky,
+        )
+    }
+    #[test]
+    fn jacobi() -> Result<(), TensorError> {
+        solve(
+            symmetric(),
+            IterativeMethod::ConjugateGradient,
+            Preconditioner::Jacobi,
+        )
+    }
+    #[test]
+    fn none() -> Result<(), TensorError> {
+        solve(
+            symmetric(),
+            IterativeMethod::ConjugateGradient,
+            Preconditioner::None,
+        )
+    }
+    #[test]
+    fn not_positive_definite() {
+        assert_eq!(
+            solve(
+                indefinite(),
+                IterativeMethod::ConjugateGradient,
+                Preconditioner::None
+            ),
+            Err(TensorError::NotPositiveDefinite)
+        )
+    }
+}
+
+mod gmres {
+    use super::*;
+    #[test]
+    fn incomplete_lu() -> Result<(), TensorError> {
+        solve(
+            nonsymmetric(),
+            IterativeMethod::Gmres,
+            Preconditioner::IncompleteLu,
+        )
+    }
+    #[test]
+    fn jacobi() -> Result<(), TensorError> {
+        solve(
+            nonsymmetric(),
+            IterativeMethod::Gmres,
+            Preconditioner::Jacobi,
+        )
+    }
+    #[test]
+    fn none() -> Result<(), TensorError> {
+        solve(nonsymmetric(), IterativeMethod::Gmres, Preconditioner::None)
+    }
+    #[test]
+    fn maximum_steps_reached() {
+        assert_eq!(
+            IterativeSolver {
+                max_steps: 3,
+                method: IterativeMethod::Gmres,
+                preconditioner: Preconditioner::None,
+                ..Default::default()
+            }
+            .solve(&nonsymmetric(), &[1.0; LEN]),
+            Err(TensorError::MaximumStepsReached(3))
+        )
+    }
+    #[test]
+    fn restart() {
+        let sparse_matrix = nonsymmetric();
+        let solution: Vector = IterativeSolver {
+            method: IterativeMethod::Gmres,
+            preconditioner: Preconditioner::None,
+            restart: 5,
+            ..Default::default()
+        }
+        .solve(&sparse_matrix, &[1.0; LEN])
+        .unwrap()
+        .into_iter()
+        .collect();
+        assert!(
+            (&sparse_matrix * &solution - Vector::new(&[1.0; LEN])).norm()
+                < 1e-10 * (LEN as TensorRank0).sqrt()
+        )
+    }
+}
+
+mod minres {
+    use super::*;
+    #[test]
+    fn incomplete_cholesky() -> Result<(), TensorError> {
+        solve(
+            symmetric(),
+            IterativeMethod::Minres,
+            Preconditioner::IncompleteCholesky,
+        )
+    }
+    #[test]
+    fn indefinite() -> Result<(), TensorError> {
+        solve(
+            super::indefinite(),
+            IterativeMethod::Minres,
+            Preconditioner::None,
+        )
+    }
+    #[test]
+    fn jacobi() -> Result<(), TensorError> {
+        solve(symmetric(), IterativeMethod::Minres, Preconditioner::Jacobi)
+    }
+}
+
+#[test]
+fn dense() {
+    let entries: Vec<TensorRank0> = SquareMatrix::from(nonsymmetric())
+        .iter()
+        .flat_map(|row| row.iter().copied().collect::<Vec<_>>())
+        .collect();
+    let exact: Vector = (0..LEN).map(|i| 1.0 + i as TensorRank0).collect();
+    let rhs = &nonsymmetric() * &exact;
+    let solution: Vector = IterativeSolver::default()
+        .solve_dense(&entries, &rhs.iter().copied().collect::<Vec<_>>())
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert!((solution - &exact).norm() < 1e-10 * exact.norm())
+}
+
+#[test]
+fn incomplete_cholesky_not_positive_definite() {
+    assert_eq!(
+        solve(
+            indefinite(),
+            IterativeMethod::ConjugateGradient,
+            Preconditioner::IncompleteCholesky
+        ),
+        Err(TensorError::NotPositiveDefinite)
+    )
+}
+
+#[test]
+fn incomplete_cholesky_maximum_shifts() {
+    let mut sparse_matrix = symmetric();
+    sparse_matrix[[1, 0]] = TensorRank0::INFINITY;
+    assert_eq!(
+        solve(
+            sparse_matrix,
+            IterativeMethod::ConjugateGradient,
+            Preconditioner::IncompleteCholesky
+        ),
+        Err(TensorError::NotPositiveDefinite)
+    )
+}
+
+#[test]
+fn jacobi_singular() {
+    let mut sparse_matrix = symmetric();
+    sparse_matrix[[0, 0]] = 0.0;
+    assert_eq!(
+        solve(
+            sparse_matrix,
+            IterativeMethod::ConjugateGradient,
+            Preconditioner::Jacobi
+        ),
+        Err(TensorError::Singular)
+    )
+}
@@ -0,0 +1,469 @@
+#[cfg(test)]
+mod test;
+
+pub mod iterative;
+
+use crate::math::{
+    tensor::TensorError, Hessian, IterativeSolver, SquareMatrix, Tensor, TensorRank0,
+    TensorRank1Vec, TensorRank2List2D, TensorRank2Vec2D, TensorVec, Vector,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    ops::{Div, Index, IndexMut, Mul},
+};
+
+type SparseRows = Vec<BTreeMap<usize, TensorRank0>>;
+
+/// A sparse matrix in compressed sparse row format.
+#[derive(Clone, Debug)]
+pub struct SparseMatrix {
+    column_indices: Vec<usize>,
+    row_offsets: Vec<usize>,
+    values: Vec<TensorRank0>,
+}
+
+impl SparseMatrix {
+    /// Adds the entries of a list of element-level blocks to the matrix.
+    ///
+    /// Block `[a][b]` is added at the rows of node `connectivity[a]` and the columns of node `connectivity[b]`.
+    pub fn assemble<const D: usize, const I: usize, const J: usize, const N: usize>(
+        &mut self,
+        connectivity: &[usize; N],
+        blocks: &TensorRank2List2D<D, I, J, N, N>,
+    ) {
+        blocks
+            .iter()
+            .zip(connectivity.iter())
+            .for_each(|(blocks_a, node_a)| {
+                blocks_a
+                    .iter()
+                    .zip(connectivity.iter())
+                    .for_each(|(block, node_b)| {
+                        block.iter().enumerate().for_each(|(i, block_i)| {
+                            block_i.iter().enumerate().for_each(|(j, block_ij)| {
+                                self[[D * node_a + i, D * node_b + j]] += block_ij
+                            })
+                        })
+                    })
+            })
+    }
+    /// Returns the zero matrix with the sparsity pattern of the given connectivity.
+    ///
+    /// Each node has `dimension` rows and columns, and nodes sharing an element are coupled.
+    pub fn from_connectivity<const N: usize>(
+        connectivity: &[[usize; N]],
+        number_of_nodes: usize,
+        dimension: usize,
+    ) -> Self {
+        let mut neighbors = vec![BTreeSet::new(); number_of_nodes];
+        connectivity.iter().for_each(|element_connectivity| {
+            element_connectivity
+                .iter()
+                .for_each(|node| neighbors[*node].extend(element_connectivity.iter().copied()))
+        });
+        Self::zero(
+            &neighbors
+                .iter()
+                .flat_map(|nodes| {
+                    (0..dimension).map(|_| {
+                        nodes
+                            .iter()
+                            .flat_map(|node| (0..dimension).map(move |j| dimension * node + j))
+                            .collect()
+                    })
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+    /// Returns `true` if the matrix contains no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the number of rows in the matrix.
+    pub fn len(&self) -> usize {
+        self.row_offsets.len() - 1
+    }
+    /// Returns the number of stored entries in the matrix.
+    pub fn number_of_entries(&self) -> usize {
+        self.values.len()
+    }
+    /// Returns the zero matrix with the given sparsity pattern.
+    ///
+    /// Each item of `sparsity` lists the columns of the stored entries in that row.
+    pub fn zero(sparsity: &[Vec<usize>]) -> Self {
+        let mut column_indices = Vec::new();
+        let mut row_offsets = vec![0];
+        sparsity.iter().for_each(|columns| {
+            column_indices.extend(columns.iter().collect::<BTreeSet<_>>());
+            row_offsets.push(column_indices.len())
+        });
+        let values = vec![0.0; column_indices.len()];
+        Self {
+            column_indices,
+            row_offsets,
+            values,
+        }
+    }
+    fn find(&self, row: usize, column: usize) -> Option<usize> {
+        let start = self.row_offsets[row];
+        self.column_indices[start..self.row_offsets[row + 1]]
+            .binary_search(&column)
+            .ok()
+            .map(|entry| start + entry)
+    }
+    fn gaussian_elimination(
+        &self,
+        permutation: &[usize],
+        rhs: &mut [Vec<TensorRank0>],
+        pivoting: bool,
+    ) -> Result<SparseRows, TensorError> {
+        //
+        // The rows and columns are first reordered by the permutation to limit the fill-in,
+        // and zero pivots are reported as singular since back substitution would produce non-finite entries.
+        //
+        let mut rows = self.permuted_rows(permutation);
+        let scale = self
+            .values
+            .iter()
+            .fold(0.0, |scale: TensorRank0, value| scale.max(value.abs()));
+        let mut columns = vec![BTreeSet::new(); self.len()];
+        rows.iter().enumerate().for_each(|(i, row)| {
+            row.keys().for_each(|&j| {
+                columns[j].insert(i);
+            })
+        });
+        for k in 0..self.len() {
+            if pivoting {
+                if let Some(p) = columns[k]
+                    .range(k..)
+                    .copied()
+                    .max_by(|&a, &b| rows[a][&k].abs().total_cmp(&rows[b][&k].abs()))
+                {
+                    if p != k {
+                        rows[k].keys().for_each(|&j| {
+                            columns[j].remove(&k);
+                        });
+                        rows[p].keys().for_each(|&j| {
+                            columns[j].remove(&p);
+                        });
+                        rows.swap(k, p);
+                        rhs.iter_mut().for_each(|rhs| rhs.swap(k, p));
+                        rows[k].keys().for_each(|&j| {
+                            columns[j].insert(k);
+                        });
+                        rows[p].keys().for_each(|&j| {
+                            columns[j].insert(p);
+                        });
+                    }
+                }
+            }
+            let pivot = rows[k].get(&k).copied().unwrap_or(0.0);
+            if pivoting {
+                if pivot.abs() <= TensorRank0::EPSILON * scale || !pivot.is_finite() {
+                    return Err(TensorError::Singular);
+                }
+            } else if pivot <= 0.0 {
+                return Err(TensorError::NotPositiveDefinite);
+            }
+            let pivot_row: Vec<(usize, TensorRank0)> = rows[k]
+                .range(k + 1..)
+                .map(|(&j, &value)| (j, value))
+                .collect();
+            let below: Vec<usize> = columns[k].range(k + 1..).copied().collect();
+            below.into_iter().for_each(|i| {
+                let factor = rows[i].remove(&k).unwrap_or(0.0) / pivot;
+                columns[k].remove(&i);
+                pivot_row.iter().for_each(|&(j, value)| {
+                    *rows[i].entry(j).or_insert_with(|| {
+                        columns[j].insert(i);
+                        0.0
+                    }) -= factor * value
+                });
+                rhs.iter_mut().for_each(|rhs| rhs[i] -= factor * rhs[k]);
+            });
+        }
+        Ok(rows)
+    }
+    fn multiply(&self, vector: &[TensorRank0]) -> Vec<TensorRank0> {
+        (0..self.len())
+            .map(|row| {
+                (self.row_offsets[row]..self.row_offsets[row + 1])
+                    .map(|entry| self.values[entry] * vector[self.column_indices[entry]])
+                    .sum()
+            })
+            .collect()
+    }
+    fn permuted_rows(&self, permutation: &[usize]) -> SparseRows {
+        let mut inverse = vec![0; self.len()];
+        permutation
+            .iter()
+            .enumerate()
+            .for_each(|(new, &old)| inverse[old] = new);
+        permutation
+            .iter()
+            .map(|&row| {
+                (self.row_offsets[row]..self.row_offsets[row + 1])
+                    .map(|entry| (inverse[self.column_indices[entry]], self.values[entry]))
+                    .collect()
+            })
+            .collect()
+    }
+    /// Returns the reverse Cuthill-McKee ordering of the rows and columns.
+    ///
+    /// Item `k` of the ordering is the original index of row and column `k` after reordering.
+    /// The ordering reduces the bandwidth of the symmetrized sparsity pattern,
+    /// which bounds the fill-in during elimination to within the band.
+    pub fn reverse_cuthill_mckee(&self) -> Vec<usize> {
+        let mut neighbors = vec![BTreeSet::new(); self.len()];
+        (0..self.len()).for_each(|row| {
+            self.column_indices[self.row_offsets[row]..self.row_offsets[row + 1]]
+                .iter()
+                .filter(|&&column| column != row)
+                .for_each(|&column| {
+                    neighbors[row].insert(column);
+                    neighbors[column].insert(row);
+                })
+        });
+        let degrees: Vec<usize> = neighbors.iter().map(|nodes| nodes.len()).collect();
+        let mut ordering = Vec::with_capacity(self.len());
+        let mut visited = vec![false; self.len()];
+        let mut queue = VecDeque::new();
+        while ordering.len() < self.len() {
+            let start = (0..self.len())
+                .filter(|&node| !visited[node])
+                .min_by_key(|&node| degrees[node])
+                .expect("An unvisited node remains.");
+            visited[start] = true;
+            queue.push_back(start);
+            while let Some(node) = queue.pop_front() {
+                ordering.push(node);
+                let mut unvisited: Vec<usize> = neighbors[node]
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| !visited[neighbor])
+                    .collect();
+                unvisited.sort_by_key(|&neighbor| degrees[neighbor]);
+                unvisited.into_iter().for_each(|neighbor| {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor)
+                });
+            }
+        }
+        ordering.reverse();
+        ordering
+    }
+    fn rows(&self) -> SparseRows {
+        (0..self.len())
+            .map(|row| {
+                (self.row_offsets[row]..self.row_offsets[row + 1])
+                    .map(|entry| (self.column_indices[entry], self.values[entry]))
+                    .collect()
+            })
+            .collect()
+    }
+    fn symmetric_part(&self) -> Self {
+        let mut rows = self.rows();
+        (0..self.len()).for_each(|row| {
+            (self.row_offsets[row]..self.row_offsets[row + 1]).for_each(|entry| {
+                *rows[self.column_indices[entry]].entry(row).or_insert(0.0) += self.values[entry]
+            })
+        });
+        let mut symmetric_part = Self::zero(
+            &rows
+                .iter()
+                .map(|row| row.keys().copied().collect())
+                .collect::<Vec<_>>(),
+        );
+        rows.iter().enumerate().for_each(|(i, row)| {
+            row.iter()
+                .for_each(|(&j, value)| symmetric_part[[i, j]] = 0.5 * value)
+        });
+        symmetric_part
+    }
+    /// Solves the linear system with the flattened right-hand side using Gaussian elimination with partial pivoting.
+    ///
+    /// The rows and columns are reordered using [`reverse_cuthill_mckee`](Self::reverse_cuthill_mckee) beforehand.
+    pub fn solve(&self, rhs: &[TensorRank0]) -> Result<Vec<TensorRank0>, TensorError> {
+        Ok(self.solve_multiple(&[rhs])?.remove(0))
+    }
+    /// Solves the linear system with each of the flattened right-hand sides, which share a single elimination.
+    pub fn solve_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> Result<Vec<Vec<TensorRank0>>, TensorError> {
+        let permutation = self.reverse_cuthill_mckee();
+        let mut permuted_rhs: Vec<Vec<TensorRank0>> = rhs
+            .iter()
+            .map(|rhs| permutation.iter().map(|&row| rhs[row]).collect())
+            .collect();
+        let rows = self.gaussian_elimination(&permutation, &mut permuted_rhs, true)?;
+        Ok(permuted_rhs
+            .into_iter()
+            .map(|permuted_rhs| {
+                let mut permuted_solution = vec![0.0; self.len()];
+                (0..self.len()).rev().for_each(|k| {
+                    permuted_solution[k] = (permuted_rhs[k]
+                        - rows[k]
+                            .range(k + 1..)
+                            .map(|(&j, value)| value * permuted_solution[j])
+                            .sum::<TensorRank0>())
+                        / rows[k][&k]
+                });
+                let mut solution = vec![0.0; self.len()];
+                permutation
+                    .iter()
+                    .zip(permuted_solution)
+                    .for_each(|(&row, entry)| solution[row] = entry);
+                solution
+            })
+            .collect())
+    }
+}
+
+impl From<SparseMatrix> for SquareMatrix {
+    fn from(sparse_matrix: SparseMatrix) -> Self {
+        let mut square_matrix = Self::zero(sparse_matrix.len());
+        (0..sparse_matrix.len()).for_each(|row| {
+            (sparse_matrix.row_offsets[row]..sparse_matrix.row_offsets[row + 1]).for_each(|entry| {
+                square_matrix[row][sparse_matrix.column_indices[entry]] =
+                    sparse_matrix.values[entry]
+            })
+        });
+        square_matrix
+    }
+}
+
+impl<const D: usize, const I: usize, const J: usize> From<SparseMatrix>
+    for TensorRank2Vec2D<D, I, J>
+{
+    fn from(sparse_matrix: SparseMatrix) -> Self {
+        let mut tensor_rank_2_vec_2d = Self::zero(sparse_matrix.len() / D);
+        (0..sparse_matrix.len()).for_each(|row| {
+            (sparse_matrix.row_offsets[row]..sparse_matrix.row_offsets[row + 1]).for_each(|entry| {
+                let column = sparse_matrix.column_indices[entry];
+                tensor_rank_2_vec_2d[row / D][column / D][row % D][column % D] =
+                    sparse_matrix.values[entry]
+            })
+        });
+        tensor_rank_2_vec_2d
+    }
+}
+
+impl Hessian for SparseMatrix {
+    fn eliminate(&mut self, indices: &[usize]) {
+        let mut eliminated = vec![false; self.len()];
+        indices.iter().for_each(|&index| eliminated[index] = true);
+        (0..self.len()).for_each(|row| {
+            (self.row_offsets[row]..self.row_offsets[row + 1]).for_each(|entry| {
+                let column = self.column_indices[entry];
+                if eliminated[row] || eliminated[column] {
+                    self.values[entry] = ((row == column) as u8) as TensorRank0
+                }
+            })
+        })
+    }
+    fn is_positive_definite(&self) -> bool {
+        //
+        // The quadratic form only depends on the symmetric part,
+        // which is positive-definite if and only if elimination without pivoting encounters only positive pivots.
+        //
+        let symmetric_part = self.symmetric_part();
+        symmetric_part
+            .gaussian_elimination(&symmetric_part.reverse_cuthill_mckee(), &mut [], false)
+            .is_ok()
+    }
+    fn solve_directly(&self, rhs: &[TensorRank0]) -> Result<Vec<TensorRank0>, TensorError> {
+        self.solve(rhs)
+    }
+    fn solve_directly_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> Result<Vec<Vec<TensorRank0>>, TensorError> {
+        self.solve_multiple(rhs)
+    }
+    fn solve_iteratively(
+        &self,
+        rhs: &[TensorRank0],
+        iterative_solver: &IterativeSolver,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        iterative_solver.solve(self, rhs)
+    }
+}
+
+impl Index<[usize; 2]> for SparseMatrix {
+    type Output = TensorRank0;
+    fn index(&self, indices: [usize; 2]) -> &Self::Output {
+        match self.find(indices[0], indices[1]) {
+            Some(entry) => &self.values[entry],
+            None => &0.0,
+        }
+    }
+}
+
+impl IndexMut<[usize; 2]> for SparseMatrix {
+    fn index_mut(&mut self, indices: [usize; 2]) -> &mut Self::Output {
+        match self.find(indices[0], indices[1]) {
+            Some(entry) => &mut self.values[entry],
+            None => panic!(
+                "Entry ({}, {}) is not in the sparsity pattern.",
+                indices[0], indices[1]
+            ),
+        }
+    }
+}
+
+/// Solves the linear system, where the entries are not finite if the sparse matrix is singular.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const D: usize, const I: usize> Div<SparseMatrix> for TensorRank1Vec<D, I> {
+    type Output = Self;
+    fn div(self, sparse_matrix: SparseMatrix) -> Self::Output {
+        let rhs: Vec<TensorRank0> = self
+            .iter()
+            .flat_map(|tensor_rank_1| tensor_rank_1.iter().copied())
+            .collect();
+        sparse_matrix
+            .solve(&rhs)
+            .unwrap_or_else(|_| vec![TensorRank0::NAN; rhs.len()])
+            .chunks(D)
+            .map(|chunk| chunk.iter().copied().collect())
+            .collect()
+    }
+}
+
+/// Solves the linear system, where the entries are not finite if the sparse matrix is singular.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div<SparseMatrix> for Vector {
+    type Output = Self;
+    fn div(self, sparse_matrix: SparseMatrix) -> Self::Output {
+        let rhs: Vec<TensorRank0> = self.iter().copied().collect();
+        sparse_matrix
+            .solve(&rhs)
+            .unwrap_or_else(|_| vec![TensorRank0::NAN; rhs.len()])
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<const D: usize, const I: usize> Mul<&TensorRank1Vec<D, I>> for &SparseMatrix {
+    type Output = TensorRank1Vec<D, I>;
+    fn mul(self, tensor_rank_1_vec: &TensorRank1Vec<D, I>) -> Self::Output {
+        self.multiply(
+            &tensor_rank_1_vec
+                .iter()
+                .flat_map(|tensor_rank_1| tensor_rank_1.iter().copied())
+                .collect::<Vec<_>>(),
+        )
+        .chunks(D)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect()
+    }
+}
+
+impl Mul<&Vector> for &SparseMatrix {
+    type Output = Vector;
+    fn mul(self, vector: &Vector) -> Self::Output {
+        self.multiply(&vector.iter().copied().collect::<Vec<_>>())
+            .into_iter()
+            .collect()
+    }
+}
@@ -0,0 +1,270 @@
+use super::{
+    Hessian, SparseMatrix, SquareMatrix, TensorRank1Vec, TensorRank2List2D, TensorVec, Vector,
+};
+use crate::math::{
+    test::{assert_eq_within_tols, TestError},
+    Tensor, TensorArray, TensorError, TensorRank0, TensorRank1,
+};
+
+fn get_sparse_matrix() -> SparseMatrix {
+    let mut sparse_matrix =
+        SparseMatrix::zero(&[vec![0, 1], vec![0, 1, 2], vec![1, 2, 3], vec![2, 3]]);
+    [
+        (0, 0, 4.0),
+        (0, 1, -1.0),
+        (1, 0, -1.0),
+        (1, 1, 4.0),
+        (1, 2, -1.0),
+        (2, 1, -1.0),
+        (2, 2, 4.0),
+        (2, 3, -1.0),
+        (3, 2, -1.0),
+        (3, 3, 4.0),
+    ]
+    .iter()
+    .for_each(|&(i, j, value)| sparse_matrix[[i, j]] = value);
+    sparse_matrix
+}
+
+fn get_sparse_matrix_needs_pivoting() -> SparseMatrix {
+    let mut sparse_matrix = SparseMatrix::zero(&[vec![1, 2], vec![0, 2], vec![0, 1]]);
+    sparse_matrix[[0, 1]] = 2.0;
+    sparse_matrix[[0, 2]] = 1.0;
+    sparse_matrix[[1, 0]] = 3.0;
+    sparse_matrix[[1, 2]] = -1.0;
+    sparse_matrix[[2, 0]] = 1.0;
+    sparse_matrix[[2, 1]] = 1.0;
+    sparse_matrix
+}
+
+fn get_sparse_matrix_shuffled_grid() -> SparseMatrix {
+    //
+    // The 5-point Laplacian on a 6x6 grid, with the nodes numbered in a scattered order.
+    //
+    let shuffle = |node: usize| (5 * node) % 36;
+    let mut sparsity = vec![vec![]; 36];
+    let mut entries = vec![];
+    (0..6).for_each(|i| {
+        (0..6).for_each(|j| {
+            let node = shuffle(6 * i + j);
+            sparsity[node].push(node);
+            entries.push((node, node, 4.0));
+            [(i + 1, j), (i, j + 1)]
+                .iter()
+                .filter(|(k, l)| k < &6 && l < &6)
+                .for_each(|(k, l)| {
+                    let neighbor = shuffle(6 * k + l);
+                    sparsity[node].push(neighbor);
+                    sparsity[neighbor].push(node);
+                    entries.push((node, neighbor, -1.0));
+                    entries.push((neighbor, node, -1.0));
+                })
+        })
+    });
+    let mut sparse_matrix = SparseMatrix::zero(&sparsity);
+    entries
+        .iter()
+        .for_each(|&(i, j, value)| sparse_matrix[[i, j]] = value);
+    sparse_matrix
+}
+
+#[test]
+fn assemble() {
+    let connectivity = [[0, 1], [1, 2]];
+    let mut sparse_matrix = SparseMatrix::from_connectivity(&connectivity, 3, 1);
+    assert_eq!(sparse_matrix.number_of_entries(), 7);
+    let blocks =
+        TensorRank2List2D::<1, 1, 1, 2, 2>::new([[[[1.0]], [[-1.0]]], [[[-1.0]], [[1.0]]]]);
+    connectivity
+        .iter()
+        .for_each(|element_connectivity| sparse_matrix.assemble(element_connectivity, &blocks));
+    assert_eq!(
+        SquareMatrix::from(sparse_matrix),
+        SquareMatrix::new(&[&[1.0, -1.0, 0.0], &[-1.0, 2.0, -1.0], &[0.0, -1.0, 1.0]])
+    );
+}
+
+#[test]
+fn divide_vector() -> Result<(), TestError> {
+    let solution = Vector::new(&[3.0, 2.0, 2.0, 3.0]) / get_sparse_matrix();
+    solution
+        .iter()
+        .zip([1.0, 1.0, 1.0, 1.0].iter())
+        .try_for_each(|(entry, value)| assert_eq_within_tols(entry, value))?;
+    Ok(())
+}
+
+#[test]
+fn divide_vector_with_pivoting() -> Result<(), TestError> {
+    let solution = Vector::new(&[5.0, 2.0, 3.0]) / get_sparse_matrix_needs_pivoting();
+    solution
+        .iter()
+        .zip([1.0, 2.0, 1.0].iter())
+        .try_for_each(|(entry, value)| assert_eq_within_tols(entry, value))?;
+    Ok(())
+}
+
+#[test]
+fn divide_tensor_rank_1_vec() -> Result<(), TestError> {
+    let solution: TensorRank1Vec<2, 1> =
+        TensorRank1Vec::new(&[[3.0, 2.0], [2.0, 3.0]]) / get_sparse_matrix();
+    solution
+        .iter()
+        .try_for_each(|entry| assert_eq_within_tols(entry, &TensorRank1::new([1.0, 1.0])))?;
+    Ok(())
+}
+
+#[test]
+fn eliminate() {
+    let mut sparse_matrix = get_sparse_matrix();
+    sparse_matrix.eliminate(&[1]);
+    assert_eq!(sparse_matrix[[0, 1]], 0.0);
+    assert_eq!(sparse_matrix[[1, 0]], 0.0);
+    assert_eq!(sparse_matrix[[1, 1]], 1.0);
+    assert_eq!(sparse_matrix[[1, 2]], 0.0);
+    assert_eq!(sparse_matrix[[2, 1]], 0.0);
+    assert_eq!(sparse_matrix[[2, 2]], 4.0);
+}
+
+#[test]
+fn index_outside_sparsity() {
+    assert_eq!(get_sparse_matrix()[[0, 3]], 0.0)
+}
+
+#[test]
+#[should_panic(expected = "Entry (0, 3) is not in the sparsity pattern.")]
+fn index_mut_outside_sparsity() {
+    get_sparse_matrix()[[0, 3]] = 1.0
+}
+
+#[test]
+fn into_square_matrix() {
+    let square_matrix: SquareMatrix = get_sparse_matrix_needs_pivoting().into();
+    assert_eq!(
+        square_matrix,
+        SquareMatrix::new(&[&[0.0, 2.0, 1.0], &[3.0, 0.0, -1.0], &[1.0, 1.0, 0.0]])
+    );
+}
+
+#[test]
+fn is_positive_definite() {
+    assert!(get_sparse_matrix().is_positive_definite());
+    assert!(!get_sparse_matrix_needs_pivoting().is_positive_definite());
+}
+
+#[test]
+fn is_positive_definite_nonsymmetric() {
+    let mut sparse_matrix = SparseMatrix::zero(&[vec![0, 1], vec![1]]);
+    sparse_matrix[[0, 0]] = 1.0;
+    sparse_matrix[[0, 1]] = 4.0;
+    sparse_matrix[[1, 1]] = 1.0;
+    assert!(!sparse_matrix.is_positive_definite());
+    sparse_matrix[[0, 1]] = 1.0;
+    assert!(sparse_matrix.is_positive_definite())
+}
+
+#[test]
+fn multiply_tensor_rank_1_vec() -> Result<(), TestError> {
+    let product = &get_sparse_matrix() * &TensorRank1Vec::<2, 1>::new(&[[1.0, 1.0], [1.0, 1.0]]);
+    assert_eq_within_tols(&product, &TensorRank1Vec::new(&[[3.0, 2.0], [2.0, 3.0]]))
+}
+
+#[test]
+fn multiply_vector() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &(&get_sparse_matrix_needs_pivoting() * &Vector::new(&[1.0, 2.0, 1.0])),
+        &Vector::new(&[5.0, 2.0, 3.0]),
+    )
+}
+
+#[test]
+fn reverse_cuthill_mckee() {
+    let sparse_matrix = get_sparse_matrix_shuffled_grid();
+    let ordering = sparse_matrix.reverse_cuthill_mckee();
+    let mut sorted = ordering.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..36).collect::<Vec<_>>());
+    let bandwidth = |permutation: &[usize]| {
+        sparse_matrix
+            .permuted_rows(permutation)
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.keys().map(move |j| i.abs_diff(*j)))
+            .max()
+            .unwrap()
+    };
+    assert!(bandwidth(&ordering) <= 7);
+    assert!(bandwidth(&(0..36).collect::<Vec<_>>()) > 7);
+}
+
+#[test]
+fn reverse_cuthill_mckee_fill_in() -> Result<(), TensorError> {
+    let sparse_matrix = get_sparse_matrix_shuffled_grid();
+    let fill_in = |permutation: &[usize]| -> Result<usize, TensorError> {
+        Ok(sparse_matrix
+            .gaussian_elimination(permutation, &mut [], true)?
+            .iter()
+            .map(|row| row.len())
+            .sum())
+    };
+    assert!(
+        fill_in(&sparse_matrix.reverse_cuthill_mckee())? < fill_in(&(0..36).collect::<Vec<_>>())?
+    );
+    Ok(())
+}
+
+#[test]
+fn size() {
+    let sparse_matrix = get_sparse_matrix();
+    assert_eq!(sparse_matrix.len(), 4);
+    assert_eq!(sparse_matrix.number_of_entries(), 10);
+    assert!(!sparse_matrix.is_empty());
+}
+
+#[test]
+fn solve_multiple() -> Result<(), TestError> {
+    let sparse_matrix = get_sparse_matrix_shuffled_grid();
+    let rhs_1: Vec<TensorRank0> = (&sparse_matrix * &Vector::new(&[1.0; 36]))
+        .iter()
+        .copied()
+        .collect();
+    let rhs_2: Vec<TensorRank0> = (&sparse_matrix * &Vector::new(&[-2.0; 36]))
+        .iter()
+        .copied()
+        .collect();
+    let solutions = sparse_matrix.solve_multiple(&[&rhs_1, &rhs_2]).unwrap();
+    assert_eq!(solutions.len(), 2);
+    solutions[0]
+        .iter()
+        .try_for_each(|entry| assert_eq_within_tols(entry, &1.0))?;
+    solutions[1]
+        .iter()
+        .try_for_each(|entry| assert_eq_within_tols(entry, &-2.0))
+}
+
+#[test]
+fn solve_reordered() -> Result<(), TestError> {
+    let sparse_matrix = get_sparse_matrix_shuffled_grid();
+    let rhs = &sparse_matrix * &Vector::new(&[1.0; 36]);
+    sparse_matrix
+        .solve(&rhs.iter().copied().collect::<Vec<_>>())
+        .unwrap()
+        .iter()
+        .try_for_each(|entry| assert_eq_within_tols(entry, &1.0))
+}
+
+#[test]
+fn solve_singular() {
+    let mut sparse_matrix = SparseMatrix::zero(&[vec![0, 1], vec![0, 1]]);
+    sparse_matrix[[0, 0]] = 1.0;
+    sparse_matrix[[0, 1]] = 2.0;
+    sparse_matrix[[1, 0]] = 2.0;
+    sparse_matrix[[1, 1]] = 4.0;
+    assert_eq!(
+        sparse_matrix.solve(&[1.0, 2.0]).unwrap_err(),
+        TensorError::Singular
+    );
+    assert!((Vector::new(&[1.0, 2.0]) / sparse_matrix)
+        .iter()
+        .all(|entry| !entry.is_finite()));
+}
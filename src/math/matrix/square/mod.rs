@@ -1,17 +1,21 @@
+#[cfg(test)]
+mod test;
+
 #[cfg(test)]
 use crate::math::test::ErrorTensor;
 
 use crate::math::{
-    tensor::TensorError, write_tensor_rank_0, Hessian, Rank2, Tensor, TensorRank0, TensorVec,
-    Vector,
+    tensor::TensorError, write_tensor_rank_0, Hessian, IterativeSolver, Rank2, Tensor, TensorRank0,
+    TensorRank1Vec, TensorVec, Vector,
 };
 use std::{
+    cmp::Ordering,
     fmt,
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
 /// A square matrix.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SquareMatrix(Vec<Vector>);
 
 #[cfg(test)]
@@ -108,10 +112,382 @@ impl IndexMut<usize> for SquareMatrix {
     }
 }
 
+impl SquareMatrix {
+    /// Returns an estimate of the condition number of the matrix in the 1-norm.
+    ///
+    /// The 1-norm of the inverse is estimated using the method of Hager,
+    /// and the condition number is infinite if the matrix is singular.
+    pub fn condition_number(&self) -> TensorRank0 {
+        let (lu, permutation) = self.lu_factorization();
+        if lu.iter().enumerate().any(|(i, lu_i)| lu_i[i] == 0.0) {
+            return TensorRank0::INFINITY;
+        }
+        let len = self.len();
+        let norm = (0..len)
+            .map(|j| {
+                self.iter()
+                    .map(|self_i| self_i[j].abs())
+                    .sum::<TensorRank0>()
+            })
+            .fold(0.0, TensorRank0::max);
+        let mut estimate = 0.0;
+        let mut vector = vec![1.0 / len as TensorRank0; len];
+        for _ in 0..5 {
+            let solution = lu_solve(&lu, &permutation, &vector);
+            estimate = solution.iter().map(|entry| entry.abs()).sum();
+            let signs: Vec<TensorRank0> = solution.iter().map(|entry| entry.signum()).collect();
+            let gradient = lu_solve_transpose(&lu, &permutation, &signs);
+            let (j, maximum) = gradient.iter().map(|entry| entry.abs()).enumerate().fold(
+                (0, 0.0),
+                |(j, maximum), (k, entry)| {
+                    if entry > maximum {
+                        (k, entry)
+                    } else {
+                        (j, maximum)
+                    }
+                },
+            );
+            let slope: TensorRank0 = gradient
+                .iter()
+                .zip(vector.iter())
+                .map(|(gradient_i, vector_i)| gradient_i * vector_i)
+                .sum();
+            if maximum <= slope {
+                break;
+            }
+            vector = vec![0.0; len];
+            vector[j] = 1.0;
+        }
+        norm * estimate
+    }
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> TensorRank0 {
+        let (lu, permutation) = self.lu_factorization();
+        let mut visited = vec![false; permutation.len()];
+        let swaps: usize = (0..permutation.len())
+            .map(|start| {
+                let mut length = 0;
+                let mut i = start;
+                while !visited[i] {
+                    visited[i] = true;
+                    i = permutation[i];
+                    length += 1;
+                }
+                length.max(1) - 1
+            })
+            .sum();
+        lu.iter()
+            .enumerate()
+            .map(|(i, lu_i)| lu_i[i])
+            .product::<TensorRank0>()
+            * (-1.0 as TensorRank0).powi(swaps as i32)
+    }
+    /// Returns the identity matrix.
+    pub fn identity(len: usize) -> Self {
+        (0..len)
+            .map(|i| (0..len).map(|j| ((i == j) as u8) as TensorRank0).collect())
+            .collect()
+    }
+    /// Returns the inverse of the matrix.
+    pub fn inverse(&self) -> Result<Self, TensorError> {
+        let (lu, permutation) = self.lu_factorization();
+        if lu.iter().enumerate().any(|(i, lu_i)| lu_i[i] == 0.0) {
+            return Err(TensorError::Singular);
+        }
+        let columns: Vec<Vec<TensorRank0>> = Self::identity(self.len())
+            .iter()
+            .map(|identity_j| {
+                lu_solve(
+                    &lu,
+                    &permutation,
+                    &identity_j.iter().copied().collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        Ok((0..self.len())
+            .map(|i| columns.iter().map(|column| column[i]).collect())
+            .collect())
+    }
+    /// Returns a least squares solution of the system with the given right-hand side.
+    ///
+    /// The solution is obtained using the QR decomposition with column pivoting,
+    /// and entries corresponding to the null space of a rank-deficient matrix are set to zero.
+    pub fn least_squares(&self, vector: &Vector) -> Vector {
+        let (tensor_q, tensor_r, columns) = self.householder(true);
+        let rhs: Vec<TensorRank0> = (0..self.len())
+            .map(|j| {
+                tensor_q
+                    .iter()
+                    .zip(vector.iter())
+                    .map(|(tensor_q_i, vector_i)| tensor_q_i[j] * vector_i)
+                    .sum()
+            })
+            .collect();
+        let tolerance = self.len() as TensorRank0
+            * TensorRank0::EPSILON
+            * tensor_r
+                .iter()
+                .next()
+                .map_or(0.0, |tensor_r_0| tensor_r_0[0].abs());
+        let rank = tensor_r
+            .iter()
+            .enumerate()
+            .take_while(|(i, tensor_r_i)| tensor_r_i[*i].abs() > tolerance)
+            .count();
+        let mut reduced = vec![0.0; self.len()];
+        (0..rank).rev().for_each(|i| {
+            reduced[i] = (rhs[i]
+                - (i + 1..rank)
+                    .map(|j| tensor_r[i][j] * reduced[j])
+                    .sum::<TensorRank0>())
+                / tensor_r[i][i]
+        });
+        let mut solution = Vector::zero(self.len());
+        columns
+            .iter()
+            .zip(reduced)
+            .for_each(|(&column, entry)| solution[column] = entry);
+        solution
+    }
+    /// Returns the LU decomposition of the matrix using partial pivoting.
+    ///
+    /// The decomposition is returned as the row permutation and the unit lower and upper triangular matrices,
+    /// where row `i` of their product is row `permutation[i]` of the matrix.
+    pub fn lu_decomposition(&self) -> Result<(Vec<usize>, Self, Self), TensorError> {
+        let (lu, permutation) = self.lu_factorization();
+        if lu.iter().enumerate().any(|(i, lu_i)| lu_i[i] == 0.0) {
+            return Err(TensorError::Singular);
+        }
+        let tensor_l = lu
+            .iter()
+            .enumerate()
+            .map(|(i, lu_i)| {
+                lu_i.iter()
+                    .enumerate()
+                    .map(|(j, lu_ij)| match j.cmp(&i) {
+                        Ordering::Less => *lu_ij,
+                        Ordering::Equal => 1.0,
+                        Ordering::Greater => 0.0,
+                    })
+                    .collect()
+            })
+            .collect();
+        let tensor_u = lu
+            .iter()
+            .enumerate()
+            .map(|(i, lu_i)| {
+                lu_i.iter()
+                    .enumerate()
+                    .map(|(j, lu_ij)| if j >= i { *lu_ij } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+        Ok((permutation, tensor_l, tensor_u))
+    }
+    /// Returns the QR decomposition of the matrix using Householder reflections.
+    pub fn qr_decomposition(&self) -> (Self, Self) {
+        let (tensor_q, tensor_r, _) = self.householder(false);
+        (tensor_q, tensor_r)
+    }
+    fn householder(&self, pivoting: bool) -> (Self, Self, Vec<usize>) {
+        let len = self.len();
+        let mut columns: Vec<usize> = (0..len).collect();
+        let mut tensor_q = Self::identity(len);
+        let mut tensor_r = self.copy();
+        for k in 0..len {
+            if pivoting {
+                let p = (k..len)
+                    .max_by(|&a, &b| {
+                        let norm = |j: usize| -> TensorRank0 {
+                            tensor_r
+                                .iter()
+                                .skip(k)
+                                .map(|tensor_r_i| tensor_r_i[j].powi(2))
+                                .sum()
+                        };
+                        norm(a).total_cmp(&norm(b))
+                    })
+                    .unwrap_or(k);
+                if p != k {
+                    columns.swap(k, p);
+                    tensor_r.iter_mut().for_each(|tensor_r_i| {
+                        let tensor_r_ik = tensor_r_i[k];
+                        tensor_r_i[k] = tensor_r_i[p];
+                        tensor_r_i[p] = tensor_r_ik;
+                    });
+                }
+            }
+            let norm = tensor_r
+                .iter()
+                .skip(k)
+                .map(|tensor_r_i| tensor_r_i[k].powi(2))
+                .sum::<TensorRank0>()
+                .sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = -norm * tensor_r[k][k].signum();
+            let mut reflector: Vec<TensorRank0> = tensor_r
+                .iter()
+                .skip(k)
+                .map(|tensor_r_i| tensor_r_i[k])
+                .collect();
+            reflector[0] -= alpha;
+            let reflector_norm_squared: TensorRank0 =
+                reflector.iter().map(|entry| entry.powi(2)).sum();
+            if reflector_norm_squared == 0.0 {
+                continue;
+            }
+            (0..len).for_each(|j| {
+                let scale = 2.0
+                    * tensor_r
+                        .iter()
+                        .skip(k)
+                        .zip(reflector.iter())
+                        .map(|(tensor_r_i, reflector_i)| tensor_r_i[j] * reflector_i)
+                        .sum::<TensorRank0>()
+                    / reflector_norm_squared;
+                tensor_r
+                    .iter_mut()
+                    .skip(k)
+                    .zip(reflector.iter())
+                    .for_each(|(tensor_r_i, reflector_i)| tensor_r_i[j] -= scale * reflector_i)
+            });
+            tensor_r[k][k] = alpha;
+            tensor_r
+                .iter_mut()
+                .skip(k + 1)
+                .for_each(|tensor_r_i| tensor_r_i[k] = 0.0);
+            tensor_q.iter_mut().for_each(|tensor_q_i| {
+                let scale = 2.0
+                    * tensor_q_i
+                        .iter()
+                        .skip(k)
+                        .zip(reflector.iter())
+                        .map(|(tensor_q_ij, reflector_j)| tensor_q_ij * reflector_j)
+                        .sum::<TensorRank0>()
+                    / reflector_norm_squared;
+                tensor_q_i
+                    .iter_mut()
+                    .skip(k)
+                    .zip(reflector.iter())
+                    .for_each(|(tensor_q_ij, reflector_j)| *tensor_q_ij -= scale * reflector_j)
+            });
+        }
+        (tensor_q, tensor_r, columns)
+    }
+    fn lu_factorization(&self) -> (Self, Vec<usize>) {
+        //
+        // The factors are stored together, with the unit diagonal of the lower factor omitted.
+        // Pivots that are negligible relative to the largest entry are set to zero and their columns are skipped,
+        // so rank-deficient matrices are reported as singular rather than factored with rounding errors.
+        //
+        let len = self.len();
+        let scale = self
+            .iter()
+            .flat_map(|self_i| self_i.iter())
+            .fold(0.0, |scale: TensorRank0, entry| scale.max(entry.abs()));
+        let mut lu = self.copy();
+        let mut permutation: Vec<usize> = (0..len).collect();
+        for k in 0..len {
+            let p = (k..len)
+                .max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs()))
+                .unwrap_or(k);
+            if p != k {
+                lu.0.swap(k, p);
+                permutation.swap(k, p);
+            }
+            if lu[k][k].abs() <= TensorRank0::EPSILON * scale || !lu[k][k].is_finite() {
+                lu[k][k] = 0.0
+            }
+            let pivot_row = lu[k].copy();
+            if pivot_row[k] != 0.0 {
+                lu.iter_mut().skip(k + 1).for_each(|lu_i| {
+                    let factor = lu_i[k] / pivot_row[k];
+                    lu_i[k] = factor;
+                    lu_i.iter_mut()
+                        .zip(pivot_row.iter())
+                        .skip(k + 1)
+                        .for_each(|(lu_ij, pivot_row_j)| *lu_ij -= factor * pivot_row_j)
+                })
+            }
+        }
+        (lu, permutation)
+    }
+}
+
+fn lu_solve(lu: &SquareMatrix, permutation: &[usize], rhs: &[TensorRank0]) -> Vec<TensorRank0> {
+    let mut solution: Vec<TensorRank0> = permutation.iter().map(|&p| rhs[p]).collect();
+    (0..solution.len())
+        .for_each(|i| solution[i] -= (0..i).map(|j| lu[i][j] * solution[j]).sum::<TensorRank0>());
+    (0..solution.len()).rev().for_each(|i| {
+        solution[i] = (solution[i]
+            - (i + 1..solution.len())
+                .map(|j| lu[i][j] * solution[j])
+                .sum::<TensorRank0>())
+            / lu[i][i]
+    });
+    solution
+}
+
+fn lu_solve_transpose(
+    lu: &SquareMatrix,
+    permutation: &[usize],
+    rhs: &[TensorRank0],
+) -> Vec<TensorRank0> {
+    let mut reduced = rhs.to_vec();
+    (0..reduced.len()).for_each(|i| {
+        reduced[i] =
+            (reduced[i] - (0..i).map(|j| lu[j][i] * reduced[j]).sum::<TensorRank0>()) / lu[i][i]
+    });
+    (0..reduced.len()).rev().for_each(|i| {
+        reduced[i] -= (i + 1..reduced.len())
+            .map(|j| lu[j][i] * reduced[j])
+            .sum::<TensorRank0>()
+    });
+    let mut solution = vec![0.0; reduced.len()];
+    permutation
+        .iter()
+        .zip(reduced)
+        .for_each(|(&p, entry)| solution[p] = entry);
+    solution
+}
+
 impl Hessian for SquareMatrix {
+    fn eliminate(&mut self, indices: &[usize]) {
+        indices.iter().for_each(|&index| {
+            self.iter_mut().for_each(|self_i| self_i[index] = 0.0);
+            self[index].iter_mut().for_each(|self_ij| *self_ij = 0.0);
+            self[index][index] = 1.0;
+        })
+    }
     fn is_positive_definite(&self) -> bool {
         self.cholesky_decomposition().is_ok()
     }
+    fn solve_directly(&self, rhs: &[TensorRank0]) -> Result<Vec<TensorRank0>, TensorError> {
+        Ok(self.solve_directly_multiple(&[rhs])?.remove(0))
+    }
+    fn solve_directly_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> Result<Vec<Vec<TensorRank0>>, TensorError> {
+        let (lu, permutation) = self.lu_factorization();
+        if lu.iter().enumerate().any(|(i, lu_i)| lu_i[i] == 0.0) {
+            Err(TensorError::Singular)
+        } else {
+            Ok(rhs
+                .iter()
+                .map(|rhs| lu_solve(&lu, &permutation, rhs))
+                .collect())
+        }
+    }
+    fn solve_iteratively(
+        &self,
+        rhs: &[TensorRank0],
+        iterative_solver: &IterativeSolver,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        iterative_solver.solve_dense(&self.flattened(), rhs)
+    }
 }
 
 impl Rank2 for SquareMatrix {
@@ -225,6 +601,15 @@ impl Tensor for SquareMatrix {
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        self.len() * indices[0] + indices[1]
+    }
+    fn get_at(&self, indices: &[usize]) -> &TensorRank0 {
+        &self[indices[0]][indices[1]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut TensorRank0 {
+        &mut self[indices[0]][indices[1]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -378,3 +763,33 @@ impl SubAssign<&Self> for SquareMatrix {
             .for_each(|(self_entry, tensor_rank_1)| *self_entry -= tensor_rank_1);
     }
 }
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const D: usize, const I: usize> Div<SquareMatrix> for TensorRank1Vec<D, I> {
+    type Output = Self;
+    fn div(self, square_matrix: SquareMatrix) -> Self::Output {
+        let (lu, permutation) = square_matrix.lu_factorization();
+        lu_solve(
+            &lu,
+            &permutation,
+            &self
+                .iter()
+                .flat_map(|tensor_rank_1| tensor_rank_1.iter().copied())
+                .collect::<Vec<_>>(),
+        )
+        .chunks(D)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect()
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl Div<SquareMatrix> for Vector {
+    type Output = Self;
+    fn div(self, square_matrix: SquareMatrix) -> Self::Output {
+        let (lu, permutation) = square_matrix.lu_factorization();
+        lu_solve(&lu, &permutation, &self.iter().copied().collect::<Vec<_>>())
+            .into_iter()
+            .collect()
+    }
+}
@@ -0,0 +1,256 @@
+use super::{Hessian, SquareMatrix, TensorError, TensorRank1Vec, TensorVec, Vector};
+use crate::math::{
+    optimize::{NewtonRaphson, OptimizeError, SecondOrder},
+    test::{assert_eq_within_tols, TestError},
+    Rank2, Tensor, TensorArray, TensorRank0, TensorRank1,
+};
+
+fn get_square_matrix() -> SquareMatrix {
+    SquareMatrix::new(&[
+        &[4.0, -1.0, 0.0, 0.0],
+        &[-1.0, 4.0, -1.0, 0.0],
+        &[0.0, -1.0, 4.0, -1.0],
+        &[0.0, 0.0, -1.0, 4.0],
+    ])
+}
+
+fn get_square_matrix_needs_pivoting() -> SquareMatrix {
+    SquareMatrix::new(&[&[0.0, 2.0, 1.0], &[3.0, 0.0, -1.0], &[1.0, 1.0, 0.0]])
+}
+
+fn get_square_matrix_singular() -> SquareMatrix {
+    SquareMatrix::new(&[&[1.0, 1.0], &[1.0, 1.0]])
+}
+
+fn get_square_matrix_rank_deficient() -> SquareMatrix {
+    SquareMatrix::new(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]])
+}
+
+fn multiply(square_matrix_a: &SquareMatrix, square_matrix_b: &SquareMatrix) -> SquareMatrix {
+    square_matrix_a
+        .iter()
+        .map(|square_matrix_a_i| {
+            (0..square_matrix_b.len())
+                .map(|j| {
+                    square_matrix_a_i
+                        .iter()
+                        .zip(square_matrix_b.iter())
+                        .map(|(square_matrix_a_ik, square_matrix_b_k)| {
+                            square_matrix_a_ik * square_matrix_b_k[j]
+                        })
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn condition_number() -> Result<(), TestError> {
+    let square_matrix = get_square_matrix();
+    let inverse = square_matrix.inverse()?;
+    let norm = |square_matrix: &SquareMatrix| {
+        (0..square_matrix.len())
+            .map(|j| {
+                square_matrix
+                    .iter()
+                    .map(|square_matrix_i| square_matrix_i[j].abs())
+                    .sum::<TensorRank0>()
+            })
+            .fold(0.0, TensorRank0::max)
+    };
+    assert_eq_within_tols(
+        &square_matrix.condition_number(),
+        &(norm(&square_matrix) * norm(&inverse)),
+    )?;
+    assert_eq_within_tols(
+        &SquareMatrix::new(&[&[1.0, 0.0], &[0.0, 1e-3]]).condition_number(),
+        &1e3,
+    )
+}
+
+#[test]
+fn condition_number_singular() {
+    assert_eq!(
+        get_square_matrix_singular().condition_number(),
+        TensorRank0::INFINITY
+    )
+}
+
+#[test]
+fn determinant() -> Result<(), TestError> {
+    assert_eq_within_tols(&get_square_matrix().determinant(), &209.0)?;
+    assert_eq_within_tols(&get_square_matrix_needs_pivoting().determinant(), &1.0)?;
+    assert_eq!(get_square_matrix_singular().determinant(), 0.0);
+    assert_eq!(get_square_matrix_rank_deficient().determinant(), 0.0);
+    Ok(())
+}
+
+#[test]
+fn divide_tensor_rank_1_vec() -> Result<(), TestError> {
+    let solution: TensorRank1Vec<2, 1> =
+        TensorRank1Vec::new(&[[3.0, 2.0], [2.0, 3.0]]) / get_square_matrix();
+    solution
+        .iter()
+        .try_for_each(|entry| assert_eq_within_tols(entry, &TensorRank1::new([1.0, 1.0])))?;
+    Ok(())
+}
+
+#[test]
+fn divide_vector() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &(Vector::new(&[3.0, 2.0, 2.0, 3.0]) / get_square_matrix()),
+        &Vector::new(&[1.0, 1.0, 1.0, 1.0]),
+    )
+}
+
+#[test]
+fn divide_vector_with_pivoting() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &(Vector::new(&[5.0, 2.0, 3.0]) / get_square_matrix_needs_pivoting()),
+        &Vector::new(&[1.0, 2.0, 1.0]),
+    )
+}
+
+#[test]
+fn divide_vector_singular() {
+    assert!((Vector::new(&[1.0, 2.0]) / get_square_matrix_singular())
+        .iter()
+        .any(|entry| !entry.is_finite()))
+}
+
+#[test]
+fn eliminate() {
+    let mut square_matrix = get_square_matrix();
+    square_matrix.eliminate(&[1]);
+    assert_eq!(
+        square_matrix,
+        SquareMatrix::new(&[
+            &[4.0, 0.0, 0.0, 0.0],
+            &[0.0, 1.0, 0.0, 0.0],
+            &[0.0, 0.0, 4.0, -1.0],
+            &[0.0, 0.0, -1.0, 4.0],
+        ])
+    )
+}
+
+#[test]
+fn inverse() -> Result<(), TestError> {
+    let square_matrix = get_square_matrix_needs_pivoting();
+    assert_eq_within_tols(
+        &multiply(&square_matrix, &square_matrix.inverse()?),
+        &SquareMatrix::identity(3),
+    )
+}
+
+#[test]
+fn inverse_singular() {
+    assert_eq!(
+        get_square_matrix_singular().inverse().err(),
+        Some(TensorError::Singular)
+    )
+}
+
+#[test]
+fn inverse_rank_deficient() {
+    assert_eq!(
+        get_square_matrix_rank_deficient().inverse().err(),
+        Some(TensorError::Singular)
+    )
+}
+
+#[test]
+fn least_squares() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &get_square_matrix_needs_pivoting().least_squares(&Vector::new(&[5.0, 2.0, 3.0])),
+        &Vector::new(&[1.0, 2.0, 1.0]),
+    )
+}
+
+#[test]
+fn least_squares_rank_deficient() -> Result<(), TestError> {
+    let square_matrix = SquareMatrix::new(&[&[0.0, 1.0], &[0.0, 1.0]]);
+    assert_eq_within_tols(
+        &square_matrix.least_squares(&Vector::new(&[1.0, 3.0])),
+        &Vector::new(&[0.0, 2.0]),
+    )
+}
+
+#[test]
+fn lu_decomposition() -> Result<(), TestError> {
+    let square_matrix = get_square_matrix_needs_pivoting();
+    let (permutation, tensor_l, tensor_u) = square_matrix.lu_decomposition()?;
+    assert_eq_within_tols(
+        &multiply(&tensor_l, &tensor_u),
+        &permutation
+            .iter()
+            .map(|&p| square_matrix[p].copy())
+            .collect(),
+    )
+}
+
+#[test]
+fn lu_decomposition_singular() {
+    assert_eq!(
+        get_square_matrix_singular().lu_decomposition().err(),
+        Some(TensorError::Singular)
+    )
+}
+
+#[test]
+fn lu_decomposition_rank_deficient() {
+    assert_eq!(
+        get_square_matrix_rank_deficient().lu_decomposition().err(),
+        Some(TensorError::Singular)
+    )
+}
+
+#[test]
+fn minimize() -> Result<(), TestError> {
+    let square_matrix = get_square_matrix();
+    let solution = NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(
+        |vector: &Vector| -> Result<Vector, OptimizeError> {
+            Ok(square_matrix
+                .iter()
+                .map(|square_matrix_i| square_matrix_i.full_contraction(vector))
+                .collect::<Vector>()
+                - Vector::new(&[3.0, 2.0, 2.0, 3.0]))
+        },
+        |_: &Vector| Ok(get_square_matrix()),
+        Vector::zero(4),
+        None,
+        None,
+    )?;
+    assert_eq_within_tols(&solution, &Vector::new(&[1.0, 1.0, 1.0, 1.0]))
+}
+
+#[test]
+fn qr_decomposition() -> Result<(), TestError> {
+    let square_matrix = get_square_matrix_needs_pivoting();
+    let (tensor_q, tensor_r) = square_matrix.qr_decomposition();
+    assert_eq_within_tols(&multiply(&tensor_q, &tensor_r), &square_matrix)?;
+    assert_eq_within_tols(
+        &multiply(&tensor_q.transpose(), &tensor_q),
+        &SquareMatrix::identity(3),
+    )?;
+    tensor_r.iter().enumerate().for_each(|(i, tensor_r_i)| {
+        tensor_r_i
+            .iter()
+            .take(i)
+            .for_each(|tensor_r_ij| assert_eq!(tensor_r_ij, &0.0))
+    });
+    Ok(())
+}
+
+#[test]
+fn solve_directly_rank_deficient() {
+    assert_eq!(
+        get_square_matrix_rank_deficient()
+            .solve_directly(&[1.0, 2.0, 3.0])
+            .err(),
+        Some(TensorError::Singular)
+    )
+}
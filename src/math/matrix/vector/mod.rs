@@ -8,7 +8,7 @@ use std::{
 };
 
 /// A vector.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Vector(Vec<TensorRank0>);
 
 #[cfg(test)]
@@ -129,6 +129,15 @@ impl Tensor for Vector {
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        indices[0]
+    }
+    fn get_at(&self, indices: &[usize]) -> &TensorRank0 {
+        &self[indices[0]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut TensorRank0 {
+        &mut self[indices[0]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
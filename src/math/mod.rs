@@ -3,6 +3,12 @@
 #[cfg(test)]
 pub mod test;
 
+/// Complex numbers for complex-step differentiation.
+pub mod complex;
+
+/// Dual numbers for automatic differentiation.
+pub mod dual;
+
 /// Special functions.
 pub mod special;
 
@@ -23,29 +29,37 @@ pub const ONE_TWENTY_FOURTH: TensorRank0 = 1.0 / 24.0;
 pub const SEVEN_THIRDS: TensorRank0 = 7.0 / 3.0;
 pub const TWO_THIRDS: TensorRank0 = 2.0 / 3.0;
 
-pub use matrix::{square::SquareMatrix, vector::Vector};
+pub use matrix::{
+    sparse::{
+        iterative::{IterativeMethod, IterativeSolver, Preconditioner},
+        SparseMatrix,
+    },
+    square::SquareMatrix,
+    vector::Vector,
+};
 pub use tensor::{
-    rank_0::{list::TensorRank0List, TensorRank0},
+    einsum::{add_assign as einsum_add_assign, for_each as einsum_for_each, Einsum, Slot, Slots},
+    rank_0::{list::TensorRank0List, Scalar, TensorRank0},
     rank_1::{
         list::TensorRank1List, list_2d::TensorRank1List2D, vec::TensorRank1Vec,
         zero as tensor_rank_1_zero, TensorRank1,
     },
     rank_2::{
-        list::TensorRank2List, list_2d::TensorRank2List2D, vec::TensorRank2Vec,
-        vec_2d::TensorRank2Vec2D, TensorRank2,
+        list::TensorRank2List, list_2d::TensorRank2List2D, symmetric::TensorRank2Symmetric,
+        vec::TensorRank2Vec, vec_2d::TensorRank2Vec2D, TensorRank2,
     },
     rank_3::{
         levi_civita, list::TensorRank3List, list_2d::TensorRank3List2D, list_3d::TensorRank3List3D,
         TensorRank3,
     },
     rank_4::{
-        list::TensorRank4List, ContractAllIndicesWithFirstIndicesOf,
-        ContractFirstSecondIndicesWithSecondIndicesOf,
+        list::TensorRank4List, symmetric::TensorRank4Symmetric,
+        ContractAllIndicesWithFirstIndicesOf, ContractFirstSecondIndicesWithSecondIndicesOf,
         ContractFirstThirdFourthIndicesWithFirstIndicesOf,
         ContractSecondFourthIndicesWithFirstIndicesOf, ContractSecondIndexWithFirstIndexOf,
         ContractThirdFourthIndicesWithFirstSecondIndicesOf, TensorRank4,
     },
-    Convert, Hessian, Rank2, Tensor, TensorArray, TensorVec,
+    Convert, Hessian, Rank2, Tensor, TensorArray, TensorError, TensorVec,
 };
 
 use std::fmt;
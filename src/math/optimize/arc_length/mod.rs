@@ -0,0 +1,232 @@
+#[cfg(test)]
+mod test;
+
+use super::{
+    super::{Hessian, Tensor, TensorError, TensorRank0},
+    constrain, prescribe, Convergence, Dirichlet, Neumann, OptimizeError,
+};
+use crate::ABS_TOL;
+
+/// Equilibrium path of load factors and solutions.
+pub type EquilibriumPath<X> = Vec<(TensorRank0, X)>;
+
+/// Arc-length continuation using the method of Riks.
+#[derive(Debug)]
+pub struct ArcLength {
+    /// Absolute error tolerance.
+    pub abs_tol: TensorRank0,
+    /// Length of each increment along the equilibrium path.
+    pub arc_length: TensorRank0,
+    /// Norm of the displacement from the initial guess at which to stop tracing the equilibrium path.
+    pub max_displacement: TensorRank0,
+    /// Maximum number of increments.
+    pub max_increments: usize,
+    /// Load factor at which to stop tracing the equilibrium path.
+    pub max_load_factor: TensorRank0,
+    /// Maximum number of corrector steps for each increment.
+    pub max_steps: usize,
+}
+
+impl Default for ArcLength {
+    fn default() -> Self {
+        Self {
+            abs_tol: ABS_TOL,
+            arc_length: 1e-1,
+            max_displacement: TensorRank0::INFINITY,
+            max_increments: 250,
+            max_load_factor: 1.0,
+            max_steps: 25,
+        }
+    }
+}
+
+impl ArcLength {
+    /// Traces the equilibrium path as the Neumann values are scaled by the load factor.
+    ///
+    /// The initial guess is taken to be in equilibrium without any load,
+    /// and the last point is corrected to lie exactly at the maximum load factor.
+    /// Tracing also stops after the maximum number of increments or beyond the maximum displacement,
+    /// in which case the path traced so far is returned and its last load factor is below the maximum.
+    pub fn trace<H, X>(
+        &self,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        hessian: impl Fn(&X) -> Result<H, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Neumann,
+    ) -> Result<EquilibriumPath<X>, OptimizeError>
+    where
+        H: Hessian,
+        X: Tensor,
+    {
+        //
+        // Each increment is predicted along the tangent to the path, choosing the direction that continues the previous increment,
+        // and corrected in the plane normal to the prediction, which is how limit points in the load factor are passed.
+        // The tangent is factored once for each corrector step to solve for both the residual and the load.
+        //
+        let mut solution = initial_guess;
+        prescribe(&mut solution, &dirichlet);
+        let initial_solution = solution.copy();
+        let eliminated: Vec<usize> = dirichlet.as_ref().map_or(Vec::new(), |bc| {
+            bc.places
+                .iter()
+                .map(|place| solution.flat_index(place))
+                .collect()
+        });
+        let mut load = solution.copy() * 0.0;
+        neumann
+            .places
+            .iter()
+            .zip(neumann.values.iter())
+            .for_each(|(place, value)| *load.get_at_mut(place) = *value);
+        constrain(&mut load, &dirichlet, &None);
+        let residual = |x: &X, load_factor: TensorRank0| -> Result<X, OptimizeError> {
+            let mut residual = jacobian(x)? - &(load.copy() * load_factor);
+            constrain(&mut residual, &dirichlet, &None);
+            Ok(residual)
+        };
+        let tangent = |x: &X| -> Result<H, OptimizeError> {
+            let mut tangent = hessian(x)?;
+            if !eliminated.is_empty() {
+                tangent.eliminate(&eliminated)
+            }
+            Ok(tangent)
+        };
+        let mut load_factor = 0.0;
+        let mut path = vec![(load_factor, solution.copy())];
+        let mut previous_step = (solution.copy() * 0.0, 1.0);
+        for _ in 0..self.max_increments {
+            let [tangent_solution] = match solve(&tangent(&solution)?, [&load]) {
+                Ok(solutions) => solutions,
+                Err(_) => {
+                    return Err(OptimizeError::SingularHessian(
+                        Convergence::default().with_solution(&solution),
+                        format!("{:?}", &self),
+                    ))
+                }
+            };
+            let mut scale = self.arc_length / (tangent_solution.norm_squared() + 1.0).sqrt();
+            if tangent_solution.full_contraction(&previous_step.0) + previous_step.1 < 0.0 {
+                scale *= -1.0
+            }
+            let predictor = (tangent_solution * scale, scale);
+            let mut trial = predictor.0.copy() + &solution;
+            let mut trial_load_factor = load_factor + predictor.1;
+            let mut converged = false;
+            let mut convergence = Convergence::default();
+            for _ in 0..self.max_steps {
+                let trial_residual = residual(&trial, trial_load_factor)?;
+                let residual_norm = trial_residual.norm();
+                convergence.residual_norms.push(residual_norm);
+                if !residual_norm.is_finite() {
+                    return Err(OptimizeError::NonFiniteResidual(
+                        convergence.with_solution(&trial),
+                        format!("{:?}", &self),
+                    ));
+                }
+                if residual_norm < self.abs_tol {
+                    converged = true;
+                    break;
+                }
+                let [residual_solution, load_solution] =
+                    match solve(&tangent(&trial)?, [&trial_residual, &load]) {
+                        Ok(solutions) => solutions,
+                        Err(_) => {
+                            return Err(OptimizeError::SingularHessian(
+                                convergence.with_solution(&trial),
+                                format!("{:?}", &self),
+                            ))
+                        }
+                    };
+                let load_factor_change = predictor.0.full_contraction(&residual_solution)
+                    / (predictor.0.full_contraction(&load_solution) + predictor.1);
+                let correction = residual_solution - load_solution * load_factor_change;
+                convergence.step_sizes.push(correction.norm());
+                trial -= correction;
+                trial_load_factor += load_factor_change;
+            }
+            if !converged {
+                return Err(OptimizeError::MaximumStepsReached(
+                    self.max_steps,
+                    convergence.with_solution(&trial),
+                    format!("{:?}", &self),
+                ));
+            }
+            if trial_load_factor >= self.max_load_factor {
+                let fraction =
+                    (self.max_load_factor - load_factor) / (trial_load_factor - load_factor);
+                let solution = (trial - &solution) * fraction + &solution;
+                path.push((
+                    self.max_load_factor,
+                    self.correct(residual, tangent, solution)?,
+                ));
+                return Ok(path);
+            }
+            previous_step = (trial.copy() - &solution, trial_load_factor - load_factor);
+            load_factor = trial_load_factor;
+            solution = trial;
+            path.push((load_factor, solution.copy()));
+            if (solution.copy() - &initial_solution).norm() > self.max_displacement {
+                break;
+            }
+        }
+        Ok(path)
+    }
+    fn correct<H, X>(
+        &self,
+        residual: impl Fn(&X, TensorRank0) -> Result<X, OptimizeError>,
+        tangent: impl Fn(&X) -> Result<H, OptimizeError>,
+        mut solution: X,
+    ) -> Result<X, OptimizeError>
+    where
+        H: Hessian,
+        X: Tensor,
+    {
+        let mut convergence = Convergence::default();
+        for _ in 0..self.max_steps {
+            let residual = residual(&solution, self.max_load_factor)?;
+            let residual_norm = residual.norm();
+            convergence.residual_norms.push(residual_norm);
+            if !residual_norm.is_finite() {
+                return Err(OptimizeError::NonFiniteResidual(
+                    convergence.with_solution(&solution),
+                    format!("{:?}", &self),
+                ));
+            }
+            if residual_norm < self.abs_tol {
+                return Ok(solution);
+            }
+            let [correction] = match solve(&tangent(&solution)?, [&residual]) {
+                Ok(solutions) => solutions,
+                Err(_) => {
+                    return Err(OptimizeError::SingularHessian(
+                        convergence.with_solution(&solution),
+                        format!("{:?}", &self),
+                    ))
+                }
+            };
+            convergence.step_sizes.push(correction.norm());
+            solution -= correction;
+        }
+        Err(OptimizeError::MaximumStepsReached(
+            self.max_steps,
+            convergence.with_solution(&solution),
+            format!("{:?}", &self),
+        ))
+    }
+}
+
+fn solve<H: Hessian, X: Tensor, const R: usize>(
+    tangent: &H,
+    rhs: [&X; R],
+) -> Result<[X; R], TensorError> {
+    let flattened = rhs.map(|rhs| rhs.flattened());
+    let mut solutions = tangent
+        .solve_directly_multiple(&flattened.each_ref().map(|rhs| rhs.as_slice()))?
+        .into_iter();
+    Ok(rhs.map(|rhs| {
+        let mut solution = rhs.copy();
+        solution.set_flattened(&mut solutions.next().unwrap().into_iter());
+        solution
+    }))
+}
@@ -0,0 +1,128 @@
+use super::{
+    super::super::{SparseMatrix, TensorRank1Vec, TensorVec},
+    ArcLength, Neumann, OptimizeError, TensorRank0,
+};
+
+const TOLERANCE: TensorRank0 = 1e-6;
+
+fn force(x: TensorRank0) -> TensorRank0 {
+    x.powi(3) - 3.0 * x.powi(2) + 2.5 * x
+}
+
+fn stiffness(x: &TensorRank1Vec<1, 1>) -> SparseMatrix {
+    let mut sparse_matrix = SparseMatrix::zero(&[vec![0]]);
+    sparse_matrix[[0, 0]] = 3.0 * x[0][0].powi(2) - 6.0 * x[0][0] + 2.5;
+    sparse_matrix
+}
+
+#[test]
+fn snap_through() {
+    let path = ArcLength {
+        ..Default::default()
+    }
+    .trace(
+        |x: &TensorRank1Vec<1, 1>| Ok(TensorRank1Vec::new(&[[force(x[0][0])]])),
+        |x: &TensorRank1Vec<1, 1>| Ok(stiffness(x)),
+        TensorRank1Vec::new(&[[0.0]]),
+        None,
+        Neumann {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        },
+    )
+    .unwrap();
+    path.iter()
+        .for_each(|(load_factor, x)| assert!((force(x[0][0]) - load_factor).abs() < TOLERANCE));
+    assert!(path
+        .windows(2)
+        .any(|points| points[1].0 < points[0].0 && points[1].1[0][0] > points[0].1[0][0]));
+    let (load_factor, x) = path.last().unwrap();
+    assert_eq!(load_factor, &1.0);
+    assert!(x[0][0] > 1.5)
+}
+
+#[test]
+fn limit_point() {
+    let path = ArcLength {
+        max_load_factor: 0.5,
+        ..Default::default()
+    }
+    .trace(
+        |x: &TensorRank1Vec<1, 1>| Ok(TensorRank1Vec::new(&[[force(x[0][0])]])),
+        |x: &TensorRank1Vec<1, 1>| Ok(stiffness(x)),
+        TensorRank1Vec::new(&[[0.0]]),
+        None,
+        Neumann {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        },
+    )
+    .unwrap();
+    let (load_factor, x) = path.last().unwrap();
+    assert_eq!(load_factor, &0.5);
+    assert!(x[0][0] < 1.0)
+}
+
+#[test]
+fn maximum_displacement_reached() {
+    let path = ArcLength {
+        max_displacement: 0.5,
+        ..Default::default()
+    }
+    .trace(
+        |x: &TensorRank1Vec<1, 1>| Ok(TensorRank1Vec::new(&[[force(x[0][0])]])),
+        |x: &TensorRank1Vec<1, 1>| Ok(stiffness(x)),
+        TensorRank1Vec::new(&[[0.0]]),
+        None,
+        Neumann {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        },
+    )
+    .unwrap();
+    let (load_factor, x) = path.last().unwrap();
+    assert!(load_factor < &1.0);
+    assert!(x[0][0] > 0.5);
+    assert!(path[path.len() - 2].1[0][0] <= 0.5)
+}
+
+#[test]
+fn maximum_increments_reached() {
+    let path = ArcLength {
+        max_increments: 3,
+        ..Default::default()
+    }
+    .trace(
+        |x: &TensorRank1Vec<1, 1>| Ok(TensorRank1Vec::new(&[[force(x[0][0])]])),
+        |x: &TensorRank1Vec<1, 1>| Ok(stiffness(x)),
+        TensorRank1Vec::new(&[[0.0]]),
+        None,
+        Neumann {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        },
+    )
+    .unwrap();
+    assert_eq!(path.len(), 4);
+    path.iter()
+        .for_each(|(load_factor, x)| assert!((force(x[0][0]) - load_factor).abs() < TOLERANCE));
+    assert!(path.last().unwrap().0 < 1.0)
+}
+
+#[test]
+fn singular_hessian() {
+    let result = ArcLength {
+        ..Default::default()
+    }
+    .trace(
+        |x: &TensorRank1Vec<1, 1>| Ok(TensorRank1Vec::new(&[[force(x[0][0])]])),
+        |_: &TensorRank1Vec<1, 1>| Ok(SparseMatrix::zero(&[vec![0]])),
+        TensorRank1Vec::new(&[[0.0]]),
+        None,
+        Neumann {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        },
+    );
+    assert!(matches!(result, Err(OptimizeError::SingularHessian(..))))
+}
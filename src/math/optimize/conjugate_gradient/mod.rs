@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod test;
+
+use super::{
+    super::{Tensor, TensorRank0},
+    change, constrain, is_minimum, prescribe, work, Convergence, Dirichlet, FirstOrder, LineSearch,
+    Neumann, OptimizeError,
+};
+use crate::ABS_TOL;
+
+/// Possible formulas for the conjugate gradient parameter.
+#[derive(Debug)]
+pub enum Beta {
+    FletcherReeves,
+    HestenesStiefel,
+    PolakRibiere,
+}
+
+/// The nonlinear conjugate gradient method.
+#[derive(Debug)]
+pub struct ConjugateGradient {
+    /// Absolute error tolerance.
+    pub abs_tol: TensorRank0,
+    /// Formula for the conjugate gradient parameter.
+    pub beta: Beta,
+    /// Whether to check if solution is minimum when given an objective.
+    pub check_minimum: bool,
+    /// Line search for the step size.
+    pub line_search: LineSearch,
+    /// Maximum number of steps.
+    pub max_steps: usize,
+}
+
+impl Default for ConjugateGradient {
+    fn default() -> Self {
+        Self {
+            abs_tol: ABS_TOL,
+            beta: Beta::PolakRibiere,
+            check_minimum: true,
+            line_search: LineSearch {
+                ..Default::default()
+            },
+            max_steps: 250,
+        }
+    }
+}
+
+impl<X: Tensor> FirstOrder<X> for ConjugateGradient {
+    fn minimize(
+        &self,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<X, OptimizeError> {
+        self.descend(
+            None::<fn(&X) -> Result<TensorRank0, OptimizeError>>,
+            jacobian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )
+    }
+    fn minimize_with_objective(
+        &self,
+        objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<(X, TensorRank0), OptimizeError> {
+        let solution = self.descend(
+            Some(&objective),
+            jacobian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )?;
+        let value = objective(&solution)? - work(&solution, &neumann);
+        Ok((solution, value))
+    }
+}
+
+impl ConjugateGradient {
+    fn descend<X: Tensor>(
+        &self,
+        objective: Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: &Option<Dirichlet>,
+        neumann: &Option<Neumann>,
+    ) -> Result<X, OptimizeError> {
+        //
+        // The initial step size minimizes a quadratic model using the curvature along the previous step.
+        // A secant step is tried when the directional derivative remains large, keeping the directions nearly conjugate.
+        // Negative parameters are replaced with zero, which restarts along the steepest descent direction.
+        //
+        let mut solution = initial_guess;
+        prescribe(&mut solution, dirichlet);
+        let objective = objective.map(|objective| {
+            move |x: &X| -> Result<TensorRank0, OptimizeError> {
+                Ok(objective(x)? - work(x, neumann))
+            }
+        });
+        let jacobian = |x: &X| -> Result<X, OptimizeError> {
+            let mut residual = jacobian(x)?;
+            constrain(&mut residual, dirichlet, neumann);
+            Ok(residual)
+        };
+        let initial_solution = solution.copy();
+        let mut step = solution.copy() * 0.0;
+        let mut residual = jacobian(&solution)?;
+        let mut direction = residual.copy() * -1.0;
+        let mut curvature = residual.norm().max(1.0);
+        let mut convergence = Convergence::default();
+        for steps in 0..self.max_steps {
+            let residual_norm = residual.norm();
+            convergence.residual_norms.push(residual_norm);
+            if !residual_norm.is_finite() {
+                return Err(OptimizeError::NonFiniteResidual(
+                    convergence.with_solution(&solution),
+                    format!("{:?}", &self),
+                ));
+            }
+            if residual_norm < self.abs_tol {
+                if self.check_minimum
+                    && !match &objective {
+                        Some(objective) => {
+                            is_minimum(objective, &solution, &initial_solution, &step, dirichlet)
+                                .map_err(|error| error.after_steps(steps))?
+                        }
+                        None => true,
+                    }
+                {
+                    return Err(OptimizeError::NotMinimum(
+                        convergence.with_solution(&solution),
+                        format!("{:?}", &self),
+                    ));
+                } else {
+                    return Ok(solution);
+                }
+            }
+            let mut slope = residual.full_contraction(&direction);
+            if slope >= 0.0 {
+                direction = residual.copy() * -1.0;
+                slope = -residual.norm_squared();
+            }
+            let value = match &objective {
+                Some(objective) => {
+                    objective(&solution).map_err(|error| error.after_steps(steps))?
+                }
+                None => 0.0,
+            };
+            let change =
+                |trial: &X| change(&objective, jacobian, &solution, &residual, value, trial);
+            let (mut trial, step_size) = match self.line_search.backtrack(
+                change,
+                &solution,
+                &direction,
+                slope,
+                -slope / (curvature * direction.norm_squared()),
+            ) {
+                Some(trial) => trial,
+                None => {
+                    return Err(OptimizeError::LineSearchFailure(
+                        convergence.with_solution(&solution),
+                        format!("{:?}", &self),
+                    ))
+                }
+            };
+            let mut trial_residual = jacobian(&trial).map_err(|error| error.after_steps(steps))?;
+            let trial_slope = trial_residual.full_contraction(&direction);
+            if trial_slope.abs() > 0.1 * slope.abs() {
+                let secant_step_size = step_size * slope / (slope - trial_slope);
+                if secant_step_size > 0.0 && secant_step_size.is_finite() {
+                    let secant_trial = direction.copy() * secant_step_size + &solution;
+                    if let Ok(secant_change) = change(&secant_trial) {
+                        if secant_change <= self.line_search.control * secant_step_size * slope {
+                            trial_residual = jacobian(&secant_trial)
+                                .map_err(|error| error.after_steps(steps))?;
+                            trial = secant_trial;
+                        }
+                    }
+                }
+            }
+            let residual_change = trial_residual.copy() - &residual;
+            step = trial.copy() - &solution;
+            convergence.step_sizes.push(step.norm());
+            let step_curvature = step.full_contraction(&residual_change) / step.norm_squared();
+            if step_curvature > 0.0 {
+                curvature = step_curvature
+            }
+            let beta = match self.beta {
+                Beta::FletcherReeves => trial_residual.norm_squared() / residual.norm_squared(),
+                Beta::HestenesStiefel => {
+                    trial_residual.full_contraction(&residual_change)
+                        / direction.full_contraction(&residual_change)
+                }
+                Beta::PolakRibiere => {
+                    trial_residual.full_contraction(&residual_change) / residual.norm_squared()
+                }
+            }
+            .max(0.0);
+            direction = direction * beta - &trial_residual;
+            residual = trial_residual;
+            solution = trial;
+        }
+        Err(OptimizeError::MaximumStepsReached(
+            self.max_steps,
+            convergence.with_solution(&solution),
+            format!("{:?}", &self),
+        ))
+    }
+}
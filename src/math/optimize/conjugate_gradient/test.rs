@@ -0,0 +1,100 @@
+use super::{
+    super::super::{Tensor, TensorRank1Vec, TensorVec},
+    Beta, ConjugateGradient, Dirichlet, FirstOrder, Neumann, TensorRank0,
+};
+
+const TOLERANCE: TensorRank0 = 1e-6;
+
+fn tridiagonal_product(x: &TensorRank1Vec<2, 1>) -> TensorRank1Vec<2, 1> {
+    let x: Vec<TensorRank0> = x.iter().flat_map(|x_a| x_a.iter().copied()).collect();
+    (0..4)
+        .map(|i| {
+            4.0 * x[i] - if i > 0 { x[i - 1] } else { 0.0 } - if i < 3 { x[i + 1] } else { 0.0 }
+        })
+        .collect::<Vec<TensorRank0>>()
+        .chunks(2)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect()
+}
+
+macro_rules! test_conjugate_gradient {
+    ($beta: expr) => {
+        fn conjugate_gradient() -> ConjugateGradient {
+            ConjugateGradient {
+                beta: $beta,
+                ..Default::default()
+            }
+        }
+        #[test]
+        fn linear() {
+            assert!(
+                conjugate_gradient()
+                    .minimize(|x: &TensorRank0| Ok(*x), 1.0, None, None)
+                    .unwrap()
+                    .abs()
+                    < TOLERANCE
+            )
+        }
+        #[test]
+        fn sin() {
+            assert!(
+                conjugate_gradient()
+                    .minimize(|x: &TensorRank0| Ok(x.sin()), 1.0, None, None)
+                    .unwrap()
+                    .abs()
+                    < TOLERANCE
+            )
+        }
+        #[test]
+        fn sin_objective() {
+            let (solution, value) = conjugate_gradient()
+                .minimize_with_objective(
+                    |x: &TensorRank0| Ok(-x.cos()),
+                    |x: &TensorRank0| Ok(x.sin()),
+                    1.0,
+                    None,
+                    None,
+                )
+                .unwrap();
+            assert!(solution.abs() < TOLERANCE);
+            assert!((value + 1.0).abs() < TOLERANCE)
+        }
+        #[test]
+        fn constrained() {
+            let solution = conjugate_gradient()
+                .minimize(
+                    |x: &TensorRank1Vec<2, 1>| Ok(tridiagonal_product(x)),
+                    TensorRank1Vec::new(&[[0.0; 2]; 2]),
+                    Some(Dirichlet {
+                        places: &[&[0, 0]],
+                        values: &[1.0],
+                    }),
+                    Some(Neumann {
+                        places: &[&[1, 1]],
+                        values: &[3.0],
+                    }),
+                )
+                .unwrap();
+            let product = tridiagonal_product(&solution);
+            assert!((solution[0][0] - 1.0).abs() < TOLERANCE);
+            assert!(product[0][1].abs() < TOLERANCE);
+            assert!(product[1][0].abs() < TOLERANCE);
+            assert!((product[1][1] - 3.0).abs() < TOLERANCE)
+        }
+    };
+}
+
+mod fletcher_reeves {
+    use super::*;
+    test_conjugate_gradient!(Beta::FletcherReeves);
+}
+
+mod hestenes_stiefel {
+    use super::*;
+    test_conjugate_gradient!(Beta::HestenesStiefel);
+}
+
+mod polak_ribiere {
+    use super::*;
+    test_conjugate_gradient!(Beta::PolakRibiere);
+}
@@ -3,7 +3,8 @@ mod test;
 
 use super::{
     super::{Tensor, TensorRank0},
-    Dirichlet, FirstOrder, Neumann, OptimizeError,
+    change, constrain, is_minimum, prescribe, work, Convergence, Dirichlet, FirstOrder, LineSearch,
+    Neumann, OptimizeError,
 };
 use crate::ABS_TOL;
 
@@ -12,6 +13,10 @@ use crate::ABS_TOL;
 pub struct GradientDescent {
     /// Absolute error tolerance.
     pub abs_tol: TensorRank0,
+    /// Whether to check if solution is minimum when given an objective.
+    pub check_minimum: bool,
+    /// Line search for the step size when given an objective.
+    pub line_search: LineSearch,
     /// Maximum number of steps.
     pub max_steps: usize,
 }
@@ -20,6 +25,10 @@ impl Default for GradientDescent {
     fn default() -> Self {
         Self {
             abs_tol: ABS_TOL,
+            check_minimum: true,
+            line_search: LineSearch {
+                ..Default::default()
+            },
             max_steps: 250,
         }
     }
@@ -32,41 +41,96 @@ impl<X: Tensor> FirstOrder<X> for GradientDescent {
         initial_guess: X,
         dirichlet: Option<Dirichlet>,
         neumann: Option<Neumann>,
+    ) -> Result<X, OptimizeError> {
+        self.descend(
+            None::<fn(&X) -> Result<TensorRank0, OptimizeError>>,
+            jacobian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )
+    }
+    fn minimize_with_objective(
+        &self,
+        objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<(X, TensorRank0), OptimizeError> {
+        let solution = self.descend(
+            Some(&objective),
+            jacobian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )?;
+        let value = objective(&solution)? - work(&solution, &neumann);
+        Ok((solution, value))
+    }
+}
+
+impl GradientDescent {
+    fn descend<X: Tensor>(
+        &self,
+        objective: Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: &Option<Dirichlet>,
+        neumann: &Option<Neumann>,
     ) -> Result<X, OptimizeError> {
         //
         // How to choose short (below, dx*dg/dg*dg) or long (dx*dx/dx*dg) steps?
         // Or even allow different options for calculating step size?
-        // Like using backtracking line search with: (1), checked decrease, (2) Armijo condition (sufficient decrease), (3) trust region, etc. (see slides).
-        // Those methods might also be abstracted to be used in multiple places, like if you make a nonlinear conjugate gradient solver.
-        // And then within the NLCG, different formulas for beta?
+        // With an objective, the step size is backtracked from the one above until the Armijo condition is satisfied.
         //
         let mut residual;
         let mut residual_change = initial_guess.copy() * 0.0;
         let mut solution = initial_guess;
+        prescribe(&mut solution, dirichlet);
+        let objective = objective.map(|objective| {
+            move |x: &X| -> Result<TensorRank0, OptimizeError> {
+                Ok(objective(x)? - work(x, neumann))
+            }
+        });
+        let jacobian = |x: &X| -> Result<X, OptimizeError> {
+            let mut residual = jacobian(x)?;
+            constrain(&mut residual, dirichlet, neumann);
+            Ok(residual)
+        };
+        let initial_solution = solution.copy();
         let mut solution_change = solution.copy();
+        let mut step = solution.copy() * 0.0;
         let mut step_size = 1e-2;
         let mut step_trial;
-        if let Some(ref bc) = dirichlet {
-            bc.places
-                .iter()
-                .zip(bc.values.iter())
-                .for_each(|(place, value)| *solution.get_at_mut(place) = *value)
-        }
-        for _ in 0..self.max_steps {
-            residual = jacobian(&solution)?;
-            if let Some(ref bc) = neumann {
-                bc.places
-                    .iter()
-                    .zip(bc.values.iter())
-                    .for_each(|(place, value)| *residual.get_at_mut(place) -= value)
+        let mut convergence = Convergence::default();
+        for steps in 0..self.max_steps {
+            residual = jacobian(&solution).map_err(|error| error.after_steps(steps))?;
+            let residual_norm = residual.norm();
+            convergence.residual_norms.push(residual_norm);
+            if !residual_norm.is_finite() {
+                return Err(OptimizeError::NonFiniteResidual(
+                    convergence.with_solution(&solution),
+                    format!("{:?}", &self),
+                ));
             }
-            if let Some(ref bc) = dirichlet {
-                bc.places
-                    .iter()
-                    .for_each(|place| *residual.get_at_mut(place) = 0.0)
-            }
-            if residual.norm() < self.abs_tol {
-                return Ok(solution);
+            if residual_norm < self.abs_tol {
+                if self.check_minimum
+                    && !match &objective {
+                        Some(objective) => {
+                            is_minimum(objective, &solution, &initial_solution, &step, dirichlet)
+                                .map_err(|error| error.after_steps(steps))?
+                        }
+                        None => true,
+                    }
+                {
+                    return Err(OptimizeError::NotMinimum(
+                        convergence.with_solution(&solution),
+                        format!("{:?}", &self),
+                    ));
+                } else {
+                    return Ok(solution);
+                }
             } else {
                 solution_change -= &solution;
                 residual_change -= &residual;
@@ -79,11 +143,38 @@ impl<X: Tensor> FirstOrder<X> for GradientDescent {
                 }
                 residual_change = residual.copy();
                 solution_change = solution.copy();
-                solution -= residual * step_size;
+                match &objective {
+                    Some(function) => {
+                        let value =
+                            function(&solution).map_err(|error| error.after_steps(steps))?;
+                        let direction = residual.copy() * -1.0;
+                        match self.line_search.backtrack(
+                            |trial: &X| {
+                                change(&objective, jacobian, &solution, &residual, value, trial)
+                            },
+                            &solution,
+                            &direction,
+                            -direction.norm_squared(),
+                            step_size,
+                        ) {
+                            Some(trial) => (solution, step_size) = trial,
+                            None => {
+                                return Err(OptimizeError::LineSearchFailure(
+                                    convergence.with_solution(&solution),
+                                    format!("{:?}", &self),
+                                ))
+                            }
+                        }
+                    }
+                    None => solution -= residual * step_size,
+                }
+                step = solution.copy() - &solution_change;
+                convergence.step_sizes.push(step.norm());
             }
         }
         Err(OptimizeError::MaximumStepsReached(
             self.max_steps,
+            convergence.with_solution(&solution),
             format!("{:?}", &self),
         ))
     }
@@ -1,4 +1,7 @@
-use super::{FirstOrder, GradientDescent, TensorRank0};
+use super::{
+    super::super::{TensorArray, TensorRank1},
+    FirstOrder, GradientDescent, OptimizeError, TensorRank0,
+};
 
 const TOLERANCE: TensorRank0 = 1e-5;
 
@@ -40,3 +43,54 @@ fn sin() {
             < TOLERANCE
     )
 }
+
+#[test]
+fn sin_objective() {
+    let (solution, value) = GradientDescent {
+        ..Default::default()
+    }
+    .minimize_with_objective(
+        |x: &TensorRank0| Ok(-x.cos()),
+        |x: &TensorRank0| Ok(x.sin()),
+        1.0,
+        None,
+        None,
+    )
+    .unwrap();
+    assert!(solution.abs() < TOLERANCE);
+    assert!((value + 1.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn line_search_failure() {
+    assert!(matches!(
+        GradientDescent {
+            ..Default::default()
+        }
+        .minimize_with_objective(
+            |x: &TensorRank0| Ok(-x.powi(2)),
+            |x: &TensorRank0| Ok(*x),
+            1.0,
+            None,
+            None
+        ),
+        Err(OptimizeError::LineSearchFailure(_, _))
+    ))
+}
+
+#[test]
+fn saddle_not_minimum() {
+    assert!(matches!(
+        GradientDescent {
+            ..Default::default()
+        }
+        .minimize_with_objective(
+            |x: &TensorRank1<2, 1>| Ok(x[0] * x[1]),
+            |x: &TensorRank1<2, 1>| Ok(TensorRank1::new([x[1], x[0]])),
+            TensorRank1::zero(),
+            None,
+            None,
+        ),
+        Err(OptimizeError::NotMinimum(_, _))
+    ))
+}
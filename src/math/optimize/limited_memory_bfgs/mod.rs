@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod test;
+
+use super::{
+    super::{Tensor, TensorRank0},
+    change, constrain, is_minimum, prescribe, work, Convergence, Dirichlet, FirstOrder, LineSearch,
+    Neumann, OptimizeError,
+};
+use crate::ABS_TOL;
+use std::collections::VecDeque;
+
+/// The limited-memory Broyden-Fletcher-Goldfarb-Shanno method.
+#[derive(Debug)]
+pub struct LimitedMemoryBfgs {
+    /// Absolute error tolerance.
+    pub abs_tol: TensorRank0,
+    /// Whether to check if solution is minimum when given an objective.
+    pub check_minimum: bool,
+    /// Line search for the step size.
+    pub line_search: LineSearch,
+    /// Maximum number of steps.
+    pub max_steps: usize,
+    /// Number of previous steps used to approximate the inverse Hessian.
+    pub memory: usize,
+}
+
+impl Default for LimitedMemoryBfgs {
+    fn default() -> Self {
+        Self {
+            abs_tol: ABS_TOL,
+            check_minimum: true,
+            line_search: LineSearch {
+                ..Default::default()
+            },
+            max_steps: 250,
+            memory: 10,
+        }
+    }
+}
+
+impl<X: Tensor> FirstOrder<X> for LimitedMemoryBfgs {
+    fn minimize(
+        &self,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<X, OptimizeError> {
+        self.descend(
+            None::<fn(&X) -> Result<TensorRank0, OptimizeError>>,
+            jacobian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )
+    }
+    fn minimize_with_objective(
+        &self,
+        objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<(X, TensorRank0), OptimizeError> {
+        let solution = self.descend(
+            Some(&objective),
+            jacobian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )?;
+        let value = objective(&solution)? - work(&solution, &neumann);
+        Ok((solution, value))
+    }
+}
+
+impl LimitedMemoryBfgs {
+    fn descend<X: Tensor>(
+        &self,
+        objective: Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: &Option<Dirichlet>,
+        neumann: &Option<Neumann>,
+    ) -> Result<X, OptimizeError> {
+        //
+        // Pairs that do not satisfy the curvature condition are skipped to keep the approximation positive-definite.
+        //
+        let mut solution = initial_guess;
+        prescribe(&mut solution, dirichlet);
+        let objective = objective.map(|objective| {
+            move |x: &X| -> Result<TensorRank0, OptimizeError> {
+                Ok(objective(x)? - work(x, neumann))
+            }
+        });
+        let jacobian = |x: &X| -> Result<X, OptimizeError> {
+            let mut residual = jacobian(x)?;
+            constrain(&mut residual, dirichlet, neumann);
+            Ok(residual)
+        };
+        let initial_solution = solution.copy();
+        let mut step = solution.copy() * 0.0;
+        let mut history: VecDeque<(X, X, TensorRank0)> = VecDeque::with_capacity(self.memory);
+        let mut residual = jacobian(&solution)?;
+        let mut convergence = Convergence::default();
+        for steps in 0..self.max_steps {
+            let residual_norm = residual.norm();
+            convergence.residual_norms.push(residual_norm);
+            if !residual_norm.is_finite() {
+                return Err(OptimizeError::NonFiniteResidual(
+                    convergence.with_solution(&solution),
+                    format!("{:?}", &self),
+                ));
+            }
+            if residual_norm < self.abs_tol {
+                if self.check_minimum
+                    && !match &objective {
+                        Some(objective) => {
+                            is_minimum(objective, &solution, &initial_solution, &step, dirichlet)
+                                .map_err(|error| error.after_steps(steps))?
+                        }
+                        None => true,
+                    }
+                {
+                    return Err(OptimizeError::NotMinimum(
+                        convergence.with_solution(&solution),
+                        format!("{:?}", &self),
+                    ));
+                } else {
+                    return Ok(solution);
+                }
+            }
+            let mut direction = residual.copy();
+            let weights: Vec<TensorRank0> = history
+                .iter()
+                .rev()
+                .map(|(step, residual_change, inverse_curvature)| {
+                    let weight = inverse_curvature * step.full_contraction(&direction);
+                    direction -= residual_change.copy() * weight;
+                    weight
+                })
+                .collect();
+            direction = direction
+                * history.back().map_or(
+                    1.0 / residual.norm().max(1.0),
+                    |(_, residual_change, inverse_curvature)| {
+                        1.0 / inverse_curvature / residual_change.norm_squared()
+                    },
+                );
+            history.iter().zip(weights.iter().rev()).for_each(
+                |((step, residual_change, inverse_curvature), weight)| {
+                    let correction =
+                        weight - inverse_curvature * residual_change.full_contraction(&direction);
+                    direction += step.copy() * correction
+                },
+            );
+            direction = direction * -1.0;
+            let mut slope = residual.full_contraction(&direction);
+            if slope >= 0.0 {
+                history.clear();
+                direction = residual.copy() * -1.0;
+                slope = -residual.norm_squared();
+            }
+            let value = match &objective {
+                Some(objective) => {
+                    objective(&solution).map_err(|error| error.after_steps(steps))?
+                }
+                None => 0.0,
+            };
+            let (trial, _) = match self.line_search.backtrack(
+                |trial: &X| change(&objective, jacobian, &solution, &residual, value, trial),
+                &solution,
+                &direction,
+                slope,
+                1.0,
+            ) {
+                Some(trial) => trial,
+                None => {
+                    return Err(OptimizeError::LineSearchFailure(
+                        convergence.with_solution(&solution),
+                        format!("{:?}", &self),
+                    ))
+                }
+            };
+            let trial_residual = jacobian(&trial).map_err(|error| error.after_steps(steps))?;
+            step = trial.copy() - &solution;
+            convergence.step_sizes.push(step.norm());
+            let residual_change = trial_residual.copy() - &residual;
+            let curvature = step.full_contraction(&residual_change);
+            if curvature > 0.0 && self.memory > 0 {
+                if history.len() == self.memory {
+                    history.pop_front();
+                }
+                history.push_back((step.copy(), residual_change, 1.0 / curvature));
+            }
+            residual = trial_residual;
+            solution = trial;
+        }
+        Err(OptimizeError::MaximumStepsReached(
+            self.max_steps,
+            convergence.with_solution(&solution),
+            format!("{:?}", &self),
+        ))
+    }
+}
@@ -0,0 +1,86 @@
+use super::{
+    super::super::{Tensor, TensorRank1Vec, TensorVec},
+    Dirichlet, FirstOrder, LimitedMemoryBfgs, Neumann, TensorRank0,
+};
+
+const TOLERANCE: TensorRank0 = 1e-6;
+
+fn tridiagonal_product(x: &TensorRank1Vec<2, 1>) -> TensorRank1Vec<2, 1> {
+    let x: Vec<TensorRank0> = x.iter().flat_map(|x_a| x_a.iter().copied()).collect();
+    (0..4)
+        .map(|i| {
+            4.0 * x[i] - if i > 0 { x[i - 1] } else { 0.0 } - if i < 3 { x[i + 1] } else { 0.0 }
+        })
+        .collect::<Vec<TensorRank0>>()
+        .chunks(2)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect()
+}
+
+#[test]
+fn linear() {
+    assert!(
+        LimitedMemoryBfgs {
+            ..Default::default()
+        }
+        .minimize(|x: &TensorRank0| Ok(*x), 1.0, None, None)
+        .unwrap()
+        .abs()
+            < TOLERANCE
+    )
+}
+
+#[test]
+fn sin() {
+    assert!(
+        LimitedMemoryBfgs {
+            ..Default::default()
+        }
+        .minimize(|x: &TensorRank0| Ok(x.sin()), 1.0, None, None)
+        .unwrap()
+        .abs()
+            < TOLERANCE
+    )
+}
+
+#[test]
+fn sin_objective() {
+    let (solution, value) = LimitedMemoryBfgs {
+        ..Default::default()
+    }
+    .minimize_with_objective(
+        |x: &TensorRank0| Ok(-x.cos()),
+        |x: &TensorRank0| Ok(x.sin()),
+        1.0,
+        None,
+        None,
+    )
+    .unwrap();
+    assert!(solution.abs() < TOLERANCE);
+    assert!((value + 1.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn constrained() {
+    let solution = LimitedMemoryBfgs {
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank1Vec<2, 1>| Ok(tridiagonal_product(x)),
+        TensorRank1Vec::new(&[[0.0; 2]; 2]),
+        Some(Dirichlet {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        }),
+        Some(Neumann {
+            places: &[&[1, 1]],
+            values: &[3.0],
+        }),
+    )
+    .unwrap();
+    let product = tridiagonal_product(&solution);
+    assert!((solution[0][0] - 1.0).abs() < TOLERANCE);
+    assert!(product[0][1].abs() < TOLERANCE);
+    assert!(product[1][0].abs() < TOLERANCE);
+    assert!((product[1][1] - 3.0).abs() < TOLERANCE)
+}
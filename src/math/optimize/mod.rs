@@ -1,14 +1,20 @@
 #[cfg(test)]
 mod test;
 
+mod arc_length;
+mod conjugate_gradient;
 mod gradient_descent;
+mod limited_memory_bfgs;
 mod newton_raphson;
 
-use super::{Hessian, Tensor, TensorRank0};
-use crate::get_defeat_message;
-use std::{fmt, ops::Div};
+use super::{Hessian, IterativeSolver, Tensor, TensorRank0};
+use crate::{write_error, write_error_debug};
+use std::{error::Error, fmt};
 
+pub use arc_length::{ArcLength, EquilibriumPath};
+pub use conjugate_gradient::{Beta, ConjugateGradient};
 pub use gradient_descent::GradientDescent;
+pub use limited_memory_bfgs::LimitedMemoryBfgs;
 pub use newton_raphson::NewtonRaphson;
 
 /// Dirichlet boundary conditions.
@@ -23,6 +29,224 @@ pub struct Neumann<'a> {
     pub values: &'a [TensorRank0],
 }
 
+/// Backtracking line search satisfying the Armijo condition.
+#[derive(Debug)]
+pub struct LineSearch {
+    /// Sufficient decrease parameter.
+    pub control: TensorRank0,
+    /// Factor to cut back the step size by.
+    pub cut_back: TensorRank0,
+    /// Maximum number of cut backs.
+    pub max_steps: usize,
+}
+
+impl Default for LineSearch {
+    fn default() -> Self {
+        Self {
+            control: 1e-4,
+            cut_back: 0.5,
+            max_steps: 25,
+        }
+    }
+}
+
+impl LineSearch {
+    /// Backtracks along a descent direction until the change in the objective satisfies the Armijo condition.
+    ///
+    /// An error or non-finite change at a trial point is treated as an insufficient decrease,
+    /// and `None` is returned if the maximum number of cut backs is reached.
+    fn backtrack<X: Tensor>(
+        &self,
+        change: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        solution: &X,
+        direction: &X,
+        slope: TensorRank0,
+        initial_step_size: TensorRank0,
+    ) -> Option<(X, TensorRank0)> {
+        let mut step_size = initial_step_size;
+        for _ in 0..=self.max_steps {
+            let trial = direction.copy() * step_size + solution;
+            match change(&trial) {
+                Ok(trial_change) if trial_change <= self.control * step_size * slope => {
+                    return Some((trial, step_size))
+                }
+                _ => step_size *= self.cut_back,
+            }
+        }
+        None
+    }
+}
+
+/// Trust region using the dogleg method.
+#[derive(Debug)]
+pub struct TrustRegion {
+    /// Minimum ratio of actual to predicted decrease to accept a step.
+    pub eta: TensorRank0,
+    /// Initial trust region radius.
+    pub initial_radius: TensorRank0,
+    /// Maximum trust region radius.
+    pub max_radius: TensorRank0,
+    /// Maximum number of radius updates per step.
+    pub max_steps: usize,
+}
+
+impl Default for TrustRegion {
+    fn default() -> Self {
+        Self {
+            eta: 1e-4,
+            initial_radius: 1.0,
+            max_radius: 1e2,
+            max_steps: 25,
+        }
+    }
+}
+
+/// Possible globalization strategies.
+#[derive(Debug)]
+pub enum Globalization {
+    None,
+    LineSearch(LineSearch),
+    TrustRegion(TrustRegion),
+}
+
+/// Possible solvers for the linear system in each step of second-order optimization algorithms.
+#[derive(Debug)]
+pub enum LinearSolver {
+    Direct,
+    Iterative(IterativeSolver),
+}
+
+fn prescribe<X: Tensor>(solution: &mut X, dirichlet: &Option<Dirichlet>) {
+    if let Some(bc) = dirichlet {
+        bc.places
+            .iter()
+            .zip(bc.values.iter())
+            .for_each(|(place, value)| *solution.get_at_mut(place) = *value)
+    }
+}
+
+fn constrain<X: Tensor>(
+    residual: &mut X,
+    dirichlet: &Option<Dirichlet>,
+    neumann: &Option<Neumann>,
+) {
+    if let Some(bc) = neumann {
+        bc.places
+            .iter()
+            .zip(bc.values.iter())
+            .for_each(|(place, value)| *residual.get_at_mut(place) -= value)
+    }
+    if let Some(bc) = dirichlet {
+        bc.places
+            .iter()
+            .for_each(|place| *residual.get_at_mut(place) = 0.0)
+    }
+}
+
+fn work<X: Tensor>(solution: &X, neumann: &Option<Neumann>) -> TensorRank0 {
+    neumann.as_ref().map_or(0.0, |bc| {
+        bc.places
+            .iter()
+            .zip(bc.values.iter())
+            .map(|(place, value)| value * solution.get_at(place))
+            .sum()
+    })
+}
+
+fn change<X: Tensor>(
+    objective: &Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+    jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+    solution: &X,
+    residual: &X,
+    value: TensorRank0,
+    trial: &X,
+) -> Result<TensorRank0, OptimizeError> {
+    //
+    // The change in the objective is estimated using the trapezoidal rule along the step
+    // when there is no objective or when the change in the objective is below its round-off.
+    //
+    if let Some(objective) = objective {
+        let change = objective(trial)? - value;
+        if change.abs() > 1e1 * TensorRank0::EPSILON * (1.0 + value.abs()) {
+            return Ok(change);
+        }
+    }
+    Ok(0.5 * (jacobian(trial)? + residual).full_contraction(&(trial.copy() - solution)))
+}
+
+fn is_minimum<X: Tensor>(
+    objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+    solution: &X,
+    initial_solution: &X,
+    step: &X,
+    dirichlet: &Option<Dirichlet>,
+) -> Result<bool, OptimizeError> {
+    //
+    // The objective must not decrease when perturbing the solution along the last step, the total displacement,
+    // a number of evenly spaced coordinate directions, or a number of pseudo-random directions,
+    // where the prescribed entries are not perturbed.
+    // The pseudo-random directions catch saddle points whose descent directions are not along any coordinate.
+    // The perturbation is large enough that the second-order change in the objective is above round-off.
+    // Each direction is only constructed when probed, so the cost is a fixed number of objective evaluations.
+    //
+    let value = objective(solution)?;
+    let tolerance = 1e1 * TensorRank0::EPSILON * (1.0 + value.abs());
+    let perturbation = TensorRank0::EPSILON.cbrt() * (1.0 + solution.norm());
+    let decreases = |direction: X| -> Result<bool, OptimizeError> {
+        let norm = direction.norm();
+        if norm > 0.0 {
+            for scale in [-perturbation / norm, perturbation / norm] {
+                if objective(&(direction.copy() * scale + solution))? < value - tolerance {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    };
+    if decreases(step.copy())? || decreases(solution.copy() - initial_solution)? {
+        return Ok(false);
+    }
+    let size = solution.flattened().len();
+    let mut free = vec![true; size];
+    if let Some(bc) = dirichlet {
+        bc.places
+            .iter()
+            .for_each(|place| free[solution.flat_index(place)] = false)
+    }
+    let masked = |entries: &mut dyn Iterator<Item = TensorRank0>| {
+        let mut direction = solution.copy();
+        direction.set_flattened(
+            &mut entries
+                .zip(free.iter())
+                .map(|(entry, &free)| if free { entry } else { 0.0 }),
+        );
+        direction
+    };
+    for coordinate in (0..size).step_by(size.div_ceil(COORDINATE_DIRECTIONS).max(1)) {
+        if decreases(masked(
+            &mut (0..size).map(|i| ((i == coordinate) as u8) as TensorRank0),
+        ))? {
+            return Ok(false);
+        }
+    }
+    let mut seed: u64 = 0x9e3779b97f4a7c15;
+    for _ in 0..RANDOM_DIRECTIONS {
+        if decreases(masked(&mut (0..size).map(|_| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as TensorRank0 / (1_u64 << 52) as TensorRank0 - 1.0
+        })))? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+const COORDINATE_DIRECTIONS: usize = 8;
+
+const RANDOM_DIRECTIONS: usize = 8;
+
 /// First-order optimization algorithms.
 pub trait FirstOrder<X: Tensor> {
     fn minimize(
@@ -32,13 +256,21 @@ pub trait FirstOrder<X: Tensor> {
         dirichlet: Option<Dirichlet>,
         neumann: Option<Neumann>,
     ) -> Result<X, OptimizeError>;
+    /// Minimizes using the objective for sufficient decrease and to check that the solution is a minimum.
+    ///
+    /// The final value of the objective less the work of any Neumann values is returned alongside the solution.
+    fn minimize_with_objective(
+        &self,
+        objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<(X, TensorRank0), OptimizeError>;
 }
 
 /// Second-order optimization algorithms.
-pub trait SecondOrder<H: Hessian, J: Tensor, X: Tensor>
-where
-    J: Div<H, Output = X>,
-{
+pub trait SecondOrder<H: Hessian, J: Tensor, X: Tensor> {
     fn minimize(
         &self,
         jacobian: impl Fn(&X) -> Result<J, OptimizeError>,
@@ -47,68 +279,185 @@ where
         dirichlet: Option<Dirichlet>,
         neumann: Option<Neumann>,
     ) -> Result<X, OptimizeError>;
+    /// Minimizes using the objective for sufficient decrease and to check that the solution is a minimum.
+    ///
+    /// The final value of the objective less the work of any Neumann values is returned alongside the solution.
+    fn minimize_with_objective(
+        &self,
+        objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<J, OptimizeError>,
+        hessian: impl Fn(&X) -> Result<H, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<(X, TensorRank0), OptimizeError>;
 }
 
 /// Possible optimization algorithms.
 #[derive(Debug)]
 pub enum Optimization {
+    ConjugateGradient(ConjugateGradient),
     GradientDescent(GradientDescent),
+    LimitedMemoryBfgs(LimitedMemoryBfgs),
     NewtonRaphson(NewtonRaphson),
 }
 
+/// Convergence diagnostics of an optimization.
+#[derive(Debug, Default, PartialEq)]
+pub struct Convergence {
+    /// Residual norm at each step.
+    pub residual_norms: Vec<TensorRank0>,
+    /// Entries of the last iterate.
+    pub solution: Vec<TensorRank0>,
+    /// Norm of the change in the solution at each step.
+    pub step_sizes: Vec<TensorRank0>,
+}
+
+impl Convergence {
+    fn with_solution<X: Tensor>(mut self, solution: &X) -> Self {
+        self.solution = solution.flattened();
+        self
+    }
+}
+
 /// Possible errors encountered when optimizing.
+///
+/// Errors encountered when evaluating the objective or its derivatives are wrapped as they are,
+/// along with the number of steps taken before the evaluation failed.
 pub enum OptimizeError {
-    MaximumStepsReached(usize, String),
-    NotMinimum(String, String),
+    Evaluation(Box<dyn Error + Send + Sync>, usize),
+    LineSearchFailure(Convergence, String),
+    LinearSolverFailure(Convergence, String),
+    MaximumStepsReached(usize, Convergence, String),
+    NonFiniteResidual(Convergence, String),
+    NotMinimum(Convergence, String),
+    SingularHessian(Convergence, String),
+    TrustRegionFailure(Convergence, String),
 }
 
-impl fmt::Debug for OptimizeError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = match self {
-            Self::MaximumStepsReached(steps, optimizer) => {
-                format!(
-                    "\x1b[1;91mMaximum number of steps ({}) reached.\x1b[0;91m\n\
-                     In optimizer: {}.",
-                    steps, optimizer
-                )
-            }
-            Self::NotMinimum(solution, optimizer) => {
-                format!(
-                    "\x1b[1;91mThe obtained solution is not a minimum.\x1b[0;91m\n\
-                     For solution: {}.\n\
-                     In optimizer: {}.",
-                    solution, optimizer
+impl OptimizeError {
+    /// Returns the convergence diagnostics, if any.
+    pub fn convergence(&self) -> Option<&Convergence> {
+        match self {
+            Self::Evaluation(_, _) => None,
+            Self::LineSearchFailure(convergence, _)
+            | Self::LinearSolverFailure(convergence, _)
+            | Self::MaximumStepsReached(_, convergence, _)
+            | Self::NonFiniteResidual(convergence, _)
+            | Self::NotMinimum(convergence, _)
+            | Self::SingularHessian(convergence, _)
+            | Self::TrustRegionFailure(convergence, _) => Some(convergence),
+        }
+    }
+    fn after_steps(self, steps: usize) -> Self {
+        match self {
+            Self::Evaluation(error, _) => Self::Evaluation(error, steps),
+            _ => self,
+        }
+    }
+    fn message(&self) -> (String, String) {
+        let (error, convergence, optimizer) = match self {
+            Self::Evaluation(error, steps) => {
+                return (
+                    "Failed to evaluate the objective or its derivatives.".to_string(),
+                    format!("{}\nAfter steps: {}.", error, steps),
                 )
             }
+            Self::LineSearchFailure(convergence, optimizer) => (
+                "The line search did not sufficiently decrease the objective.".to_string(),
+                convergence,
+                optimizer,
+            ),
+            Self::LinearSolverFailure(convergence, optimizer) => (
+                "The linear solver did not converge.".to_string(),
+                convergence,
+                optimizer,
+            ),
+            Self::MaximumStepsReached(steps, convergence, optimizer) => (
+                format!("Maximum number of steps ({}) reached.", steps),
+                convergence,
+                optimizer,
+            ),
+            Self::NonFiniteResidual(convergence, optimizer) => (
+                "The residual is not finite.".to_string(),
+                convergence,
+                optimizer,
+            ),
+            Self::NotMinimum(convergence, optimizer) => (
+                "The obtained solution is not a minimum.".to_string(),
+                convergence,
+                optimizer,
+            ),
+            Self::SingularHessian(convergence, optimizer) => (
+                "The Hessian is singular.".to_string(),
+                convergence,
+                optimizer,
+            ),
+            Self::TrustRegionFailure(convergence, optimizer) => (
+                "The trust region did not accept a step.".to_string(),
+                convergence,
+                optimizer,
+            ),
         };
-        write!(
-            f,
-            "\n{}\n\x1b[0;2;31m{}\x1b[0m\n",
+        (
             error,
-            get_defeat_message()
+            format!(
+                "After steps: {}.\n\
+                 Last residual norm: {:.6e}.\n\
+                 In optimizer: {}.",
+                convergence.residual_norms.len(),
+                convergence
+                    .residual_norms
+                    .last()
+                    .unwrap_or(&TensorRank0::NAN),
+                optimizer
+            ),
         )
     }
 }
 
+impl fmt::Debug for OptimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (headline, details) = self.message();
+        write_error_debug(f, &headline, &details)
+    }
+}
+
 impl fmt::Display for OptimizeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = match self {
-            Self::MaximumStepsReached(steps, optimizer) => {
-                format!(
-                    "\x1b[1;91mMaximum number of steps ({}) reached.\x1b[0;91m\n\
-                     In optimizer: {}.",
-                    steps, optimizer
-                )
+        let (headline, details) = self.message();
+        write_error(f, &headline, &details)
+    }
+}
+
+impl Error for OptimizeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Evaluation(error, _) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for OptimizeError {
+    fn eq(&self, other: &Self) -> bool {
+        //
+        // Wrapped evaluation errors are only known to implement Error, so they are compared by their messages.
+        //
+        match (self, other) {
+            (Self::Evaluation(a, b), Self::Evaluation(c, d)) => {
+                a.to_string() == c.to_string() && b == d
             }
-            Self::NotMinimum(solution, optimizer) => {
-                format!(
-                    "\x1b[1;91mThe obtained solution is not a minimum.\x1b[0;91m\n\
-                     For solution: {}.\n\
-                     In optimizer: {}.",
-                    solution, optimizer
-                )
+            (Self::LineSearchFailure(a, b), Self::LineSearchFailure(c, d))
+            | (Self::LinearSolverFailure(a, b), Self::LinearSolverFailure(c, d))
+            | (Self::NonFiniteResidual(a, b), Self::NonFiniteResidual(c, d))
+            | (Self::NotMinimum(a, b), Self::NotMinimum(c, d))
+            | (Self::SingularHessian(a, b), Self::SingularHessian(c, d))
+            | (Self::TrustRegionFailure(a, b), Self::TrustRegionFailure(c, d)) => a == c && b == d,
+            (Self::MaximumStepsReached(a, b, c), Self::MaximumStepsReached(d, e, f)) => {
+                a == d && b == e && c == f
             }
-        };
-        write!(f, "{}\x1b[0m", error)
+            _ => false,
+        }
     }
 }
@@ -2,11 +2,11 @@
 mod test;
 
 use super::{
-    super::{Hessian, Tensor, TensorRank0},
-    Dirichlet, Neumann, OptimizeError, SecondOrder,
+    super::{Hessian, Tensor, TensorError, TensorRank0},
+    constrain, is_minimum, prescribe, work, Convergence, Dirichlet, Globalization, LineSearch,
+    LinearSolver, Neumann, OptimizeError, SecondOrder, TrustRegion,
 };
 use crate::ABS_TOL;
-use std::ops::Div;
 
 /// The Newton-Raphson method.
 #[derive(Debug)]
@@ -15,6 +15,10 @@ pub struct NewtonRaphson {
     pub abs_tol: TensorRank0,
     /// Whether to check if solution is minimum.
     pub check_minimum: bool,
+    /// Globalization strategy.
+    pub globalization: Globalization,
+    /// Solver for the linear system in each step.
+    pub linear_solver: LinearSolver,
     /// Maximum number of steps.
     pub max_steps: usize,
 }
@@ -24,68 +28,372 @@ impl Default for NewtonRaphson {
         Self {
             abs_tol: ABS_TOL,
             check_minimum: true,
+            globalization: Globalization::None,
+            linear_solver: LinearSolver::Direct,
             max_steps: 250,
         }
     }
 }
 
-impl<H: Hessian, J: Tensor, X: Tensor> SecondOrder<H, J, X> for NewtonRaphson
-where
-    J: Div<H, Output = X>,
-{
+impl<H: Hessian, X: Tensor> SecondOrder<H, X, X> for NewtonRaphson {
     fn minimize(
         &self,
-        jacobian: impl Fn(&X) -> Result<J, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
         hessian: impl Fn(&X) -> Result<H, OptimizeError>,
         initial_guess: X,
-        _dirichlet: Option<Dirichlet>,
-        _neumann: Option<Neumann>,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
     ) -> Result<X, OptimizeError> {
+        self.globalized(
+            None::<fn(&X) -> Result<TensorRank0, OptimizeError>>,
+            jacobian,
+            hessian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )
+    }
+    fn minimize_with_objective(
+        &self,
+        objective: impl Fn(&X) -> Result<TensorRank0, OptimizeError>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        hessian: impl Fn(&X) -> Result<H, OptimizeError>,
+        initial_guess: X,
+        dirichlet: Option<Dirichlet>,
+        neumann: Option<Neumann>,
+    ) -> Result<(X, TensorRank0), OptimizeError> {
+        let solution = self.globalized(
+            Some(&objective),
+            jacobian,
+            hessian,
+            initial_guess,
+            &dirichlet,
+            &neumann,
+        )?;
+        let value = objective(&solution)? - work(&solution, &neumann);
+        Ok((solution, value))
+    }
+}
+
+impl NewtonRaphson {
+    fn globalized<H: Hessian, X>(
+        &self,
+        objective: Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        hessian: impl Fn(&X) -> Result<H, OptimizeError>,
+        initial_guess: X,
+        dirichlet: &Option<Dirichlet>,
+        neumann: &Option<Neumann>,
+    ) -> Result<X, OptimizeError>
+    where
+        X: Tensor,
+    {
         //
-        // consider having separate implementations for constrained and unconstrained optimization
-        // mostly because what you are planning for constrained optimization isn't really NewtonRaphson anymore
-        // so would have unconstrained GD, unconstrained NR, and the constrained solver for fem
-        // maybe you can pass Option<SecondOrder> into that solver?
+        // The prescribed entries are fixed by zeroing their residuals and eliminating their rows and columns from the tangent,
+        // and the applied values are subtracted from the residual and their work is subtracted from the objective.
         //
-        let mut residual;
         let mut solution = initial_guess;
-        // if let Some(ref bc) = dirichlet {
-        //     bc.places
-        //         .iter()
-        //         .zip(bc.values.iter())
-        //         .for_each(|(place, value)| *solution.get_at_mut(place) = *value)
-        // }
+        prescribe(&mut solution, dirichlet);
+        let eliminated: Vec<usize> = dirichlet.as_ref().map_or(Vec::new(), |bc| {
+            bc.places
+                .iter()
+                .map(|place| solution.flat_index(place))
+                .collect()
+        });
+        let objective = objective.map(|objective| {
+            move |x: &X| -> Result<TensorRank0, OptimizeError> {
+                Ok(objective(x)? - work(x, neumann))
+            }
+        });
+        let jacobian = |x: &X| -> Result<X, OptimizeError> {
+            let mut residual = jacobian(x)?;
+            constrain(&mut residual, dirichlet, neumann);
+            Ok(residual)
+        };
+        let hessian = |x: &X| -> Result<H, OptimizeError> {
+            let mut tangent = hessian(x)?;
+            if !eliminated.is_empty() {
+                tangent.eliminate(&eliminated)
+            }
+            Ok(tangent)
+        };
+        let mut radius = match &self.globalization {
+            Globalization::TrustRegion(trust_region) => trust_region.initial_radius,
+            _ => 0.0,
+        };
+        let initial_solution = solution.copy();
+        let mut residual;
+        let mut step = solution.copy() * 0.0;
         let mut tangent;
-        for _ in 0..self.max_steps {
-            residual = jacobian(&solution)?;
-            // if let Some(ref bc) = neumann {
-            //     bc.places
-            //         .iter()
-            //         .zip(bc.values.iter())
-            //         .for_each(|(place, value)| *residual.get_at_mut(place) -= value)
-            // }
-            // if let Some(ref bc) = dirichlet {
-            //     bc.places
-            //         .iter()
-            //         .for_each(|place| *residual.get_at_mut(place) = 0.0)
-            // }
-            tangent = hessian(&solution)?;
-            if residual.norm() < self.abs_tol {
-                if self.check_minimum && !tangent.is_positive_definite() {
+        let mut convergence = Convergence::default();
+        for steps in 0..self.max_steps {
+            residual = jacobian(&solution).map_err(|error| error.after_steps(steps))?;
+            let residual_norm = residual.norm();
+            convergence.residual_norms.push(residual_norm);
+            if !residual_norm.is_finite() {
+                return Err(OptimizeError::NonFiniteResidual(
+                    convergence.with_solution(&solution),
+                    format!("{:?}", &self),
+                ));
+            }
+            tangent = hessian(&solution).map_err(|error| error.after_steps(steps))?;
+            if residual_norm < self.abs_tol {
+                if self.check_minimum
+                    && !(tangent.is_positive_definite()
+                        && match &objective {
+                            Some(objective) => is_minimum(
+                                objective,
+                                &solution,
+                                &initial_solution,
+                                &step,
+                                dirichlet,
+                            )
+                            .map_err(|error| error.after_steps(steps))?,
+                            None => true,
+                        })
+                {
                     return Err(OptimizeError::NotMinimum(
-                        format!("{}", solution),
+                        convergence.with_solution(&solution),
                         format!("{:?}", &self),
                     ));
                 } else {
                     return Ok(solution);
                 }
             } else {
-                solution -= residual / tangent;
+                let newton = match self.newton(residual.copy(), tangent) {
+                    Ok(newton) => newton.map(|newton| newton * -1.0),
+                    Err(_) => {
+                        return Err(OptimizeError::SingularHessian(
+                            convergence.with_solution(&solution),
+                            format!("{:?}", &self),
+                        ))
+                    }
+                };
+                step = match &self.globalization {
+                    Globalization::None => {
+                        let step = match newton {
+                            Some(newton) => newton,
+                            None => {
+                                return Err(OptimizeError::LinearSolverFailure(
+                                    convergence.with_solution(&solution),
+                                    format!("{:?}", &self),
+                                ))
+                            }
+                        };
+                        if !step.norm().is_finite() {
+                            return Err(OptimizeError::SingularHessian(
+                                convergence.with_solution(&solution),
+                                format!("{:?}", &self),
+                            ));
+                        }
+                        step
+                    }
+                    Globalization::LineSearch(line_search) => {
+                        match self
+                            .line_search(
+                                line_search,
+                                &objective,
+                                jacobian,
+                                &solution,
+                                residual,
+                                newton,
+                            )
+                            .map_err(|error| error.after_steps(steps))?
+                        {
+                            Some(step) => step,
+                            None => {
+                                return Err(OptimizeError::LineSearchFailure(
+                                    convergence.with_solution(&solution),
+                                    format!("{:?}", &self),
+                                ))
+                            }
+                        }
+                    }
+                    Globalization::TrustRegion(trust_region) => {
+                        match self
+                            .trust_region(
+                                trust_region,
+                                &mut radius,
+                                &objective,
+                                jacobian,
+                                &solution,
+                                residual,
+                                newton,
+                            )
+                            .map_err(|error| error.after_steps(steps))?
+                        {
+                            Some(step) => step,
+                            None => {
+                                return Err(OptimizeError::TrustRegionFailure(
+                                    convergence.with_solution(&solution),
+                                    format!("{:?}", &self),
+                                ))
+                            }
+                        }
+                    }
+                };
+                convergence.step_sizes.push(step.norm());
+                solution += &step;
             }
         }
         Err(OptimizeError::MaximumStepsReached(
             self.max_steps,
+            convergence.with_solution(&solution),
             format!("{:?}", &self),
         ))
     }
+    fn line_search<X>(
+        &self,
+        line_search: &LineSearch,
+        objective: &Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        solution: &X,
+        residual: X,
+        newton: Option<X>,
+    ) -> Result<Option<X>, OptimizeError>
+    where
+        X: Tensor,
+    {
+        //
+        // The step is returned, or nothing if the line search fails.
+        // Without an objective, the merit function is half the residual norm squared,
+        // which decreases along the Newton direction at the rate of the residual norm squared.
+        //
+        let merit = |x: &X| match objective {
+            Some(objective) => objective(x),
+            None => Ok(0.5 * jacobian(x)?.norm_squared()),
+        };
+        let value = merit(solution)?;
+        let mut direction = newton.unwrap_or_else(|| residual.copy() * TensorRank0::NAN);
+        let mut slope = match objective {
+            Some(_) => residual.full_contraction(&direction),
+            None => -residual.norm_squared(),
+        };
+        if slope >= 0.0 || !slope.is_finite() {
+            direction = residual * -1.0;
+            slope = -direction.norm_squared();
+        }
+        Ok(line_search
+            .backtrack(
+                |trial: &X| Ok(merit(trial)? - value),
+                solution,
+                &direction,
+                slope,
+                1.0,
+            )
+            .map(|(trial, _)| trial - solution))
+    }
+    fn newton<H: Hessian, X: Tensor>(
+        &self,
+        residual: X,
+        tangent: H,
+    ) -> Result<Option<X>, TensorError> {
+        //
+        // An error is returned if the direct solver finds the tangent to be singular.
+        // Nothing is returned if the iterative solver fails,
+        // and the globalization strategies then fall back on the steepest descent direction.
+        //
+        let solution = match &self.linear_solver {
+            LinearSolver::Direct => tangent.solve_directly(&residual.flattened())?,
+            LinearSolver::Iterative(iterative_solver) => {
+                match tangent.solve_iteratively(&residual.flattened(), iterative_solver) {
+                    Ok(solution) => solution,
+                    Err(_) => return Ok(None),
+                }
+            }
+        };
+        let mut newton = residual;
+        newton.set_flattened(&mut solution.into_iter());
+        Ok(Some(newton))
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn trust_region<X>(
+        &self,
+        trust_region: &TrustRegion,
+        radius: &mut TensorRank0,
+        objective: &Option<impl Fn(&X) -> Result<TensorRank0, OptimizeError>>,
+        jacobian: impl Fn(&X) -> Result<X, OptimizeError>,
+        solution: &X,
+        residual: X,
+        newton: Option<X>,
+    ) -> Result<Option<X>, OptimizeError>
+    where
+        X: Tensor,
+    {
+        //
+        // The step is returned, or nothing if no step is accepted within the maximum number of radius updates.
+        // The curvature along the residual is approximated using a directional difference of the jacobian.
+        // Without an objective, the actual decrease is estimated using the trapezoidal rule,
+        // which assumes that the jacobian is the gradient of some objective.
+        // Steps predicted to decrease the objective by less than its round-off are accepted.
+        //
+        let newton = newton.unwrap_or_else(|| residual.copy() * TensorRank0::NAN);
+        let perturbation = TensorRank0::EPSILON.sqrt() * (1.0 + solution.norm()) / residual.norm();
+        let curvature = residual.full_contraction(
+            &(jacobian(&(residual.copy() * perturbation + solution))? - &residual),
+        ) / perturbation;
+        let value = match objective {
+            Some(objective) => objective(solution)?,
+            None => 0.0,
+        };
+        for _ in 0..trust_region.max_steps {
+            let (step, step_tangent_step) = dogleg(&residual, &newton, curvature, *radius);
+            let predicted = -residual.full_contraction(&step) - 0.5 * step_tangent_step;
+            let trial = step.copy() + solution;
+            let actual = match objective {
+                Some(objective) => objective(&trial).map(|trial_value| value - trial_value),
+                None => jacobian(&trial).map(|trial_residual| {
+                    -0.5 * (trial_residual + &residual).full_contraction(&step)
+                }),
+            }
+            .unwrap_or(TensorRank0::NAN);
+            let ratio = if actual.is_finite() && predicted <= TensorRank0::EPSILON * value.abs() {
+                1.0
+            } else {
+                actual / predicted
+            };
+            if ratio >= 0.75 && step.norm() >= 0.99 * *radius {
+                *radius = (2.0 * *radius).min(trust_region.max_radius)
+            } else if ratio < 0.25 || ratio.is_nan() {
+                *radius *= 0.25
+            }
+            if ratio > trust_region.eta {
+                return Ok(Some(step));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn dogleg<X: Tensor>(
+    residual: &X,
+    newton: &X,
+    curvature: TensorRank0,
+    radius: TensorRank0,
+) -> (X, TensorRank0) {
+    let newton_slope = residual.full_contraction(newton);
+    if newton_slope < 0.0 && newton.norm() <= radius {
+        return (newton.copy(), -newton_slope);
+    }
+    let residual_norm_squared = residual.norm_squared();
+    if curvature <= 0.0
+        || residual_norm_squared * residual_norm_squared.sqrt() >= radius * curvature
+    {
+        let scale = radius / residual_norm_squared.sqrt();
+        return (residual.copy() * -scale, scale.powi(2) * curvature);
+    }
+    let cauchy_scale = residual_norm_squared / curvature;
+    let cauchy = residual.copy() * -cauchy_scale;
+    if newton_slope >= 0.0 || !newton_slope.is_finite() {
+        return (cauchy, cauchy_scale * residual_norm_squared);
+    }
+    let difference = newton.copy() - &cauchy;
+    let a = difference.norm_squared();
+    let b = 2.0 * cauchy.full_contraction(&difference);
+    let c = cauchy.norm_squared() - radius.powi(2);
+    let tau = (-b + (b.powi(2) - 4.0 * a * c).sqrt()) / (2.0 * a);
+    (
+        difference * tau + cauchy,
+        cauchy_scale * residual_norm_squared * (1.0 - tau.powi(2)) - tau.powi(2) * newton_slope,
+    )
 }
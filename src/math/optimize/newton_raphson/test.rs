@@ -1,4 +1,11 @@
-use super::{NewtonRaphson, SecondOrder, TensorRank0};
+use super::{
+    super::super::{
+        IterativeMethod, IterativeSolver, Preconditioner, SparseMatrix, TensorArray, TensorRank1,
+        TensorRank1Vec, TensorRank2, TensorRank4, TensorVec,
+    },
+    Dirichlet, Globalization, LineSearch, LinearSolver, Neumann, NewtonRaphson, OptimizeError,
+    SecondOrder, Tensor, TensorRank0, TrustRegion,
+};
 
 const TOLERANCE: TensorRank0 = 1e-6;
 
@@ -74,3 +81,455 @@ fn sin_max() {
     )
     .unwrap();
 }
+
+fn soft_abs(x: &TensorRank0) -> Result<TensorRank0, OptimizeError> {
+    Ok((1.0 + x.powi(2)).sqrt())
+}
+
+fn soft_abs_jacobian(x: &TensorRank0) -> Result<TensorRank0, OptimizeError> {
+    Ok(x / (1.0 + x.powi(2)).sqrt())
+}
+
+fn soft_abs_hessian(x: &TensorRank0) -> Result<TensorRank0, OptimizeError> {
+    Ok((1.0 + x.powi(2)).powf(-1.5))
+}
+
+#[test]
+#[should_panic(expected = "The Hessian is singular.")]
+fn soft_abs_diverges() {
+    NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(soft_abs_jacobian, soft_abs_hessian, 1.5, None, None)
+    .unwrap();
+}
+
+#[test]
+fn soft_abs_line_search() {
+    assert!(
+        NewtonRaphson {
+            globalization: Globalization::LineSearch(LineSearch {
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .minimize(soft_abs_jacobian, soft_abs_hessian, 1.5, None, None)
+        .unwrap()
+        .abs()
+            < TOLERANCE
+    )
+}
+
+#[test]
+fn soft_abs_line_search_objective() {
+    assert!(
+        NewtonRaphson {
+            globalization: Globalization::LineSearch(LineSearch {
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .minimize_with_objective(
+            soft_abs,
+            soft_abs_jacobian,
+            soft_abs_hessian,
+            1.5,
+            None,
+            None
+        )
+        .unwrap()
+        .0
+        .abs()
+            < TOLERANCE
+    )
+}
+
+#[test]
+fn soft_abs_trust_region() {
+    assert!(
+        NewtonRaphson {
+            globalization: Globalization::TrustRegion(TrustRegion {
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .minimize(soft_abs_jacobian, soft_abs_hessian, 1.5, None, None)
+        .unwrap()
+        .abs()
+            < TOLERANCE
+    )
+}
+
+#[test]
+fn soft_abs_trust_region_objective() {
+    assert!(
+        NewtonRaphson {
+            globalization: Globalization::TrustRegion(TrustRegion {
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .minimize_with_objective(
+            soft_abs,
+            soft_abs_jacobian,
+            soft_abs_hessian,
+            1.5,
+            None,
+            None
+        )
+        .unwrap()
+        .0
+        .abs()
+            < TOLERANCE
+    )
+}
+
+#[test]
+fn cubic_not_minimum() {
+    assert!(matches!(
+        NewtonRaphson {
+            ..Default::default()
+        }
+        .minimize_with_objective(
+            |x: &TensorRank0| Ok(1e2 * x.powi(3)),
+            |x: &TensorRank0| Ok(3e2 * x.powi(2)),
+            |x: &TensorRank0| Ok(6e2 * x),
+            1.0,
+            None,
+            None
+        ),
+        Err(OptimizeError::NotMinimum(_, _))
+    ))
+}
+
+fn tridiagonal() -> SparseMatrix {
+    let mut sparse_matrix =
+        SparseMatrix::zero(&[vec![0, 1], vec![0, 1, 2], vec![1, 2, 3], vec![2, 3]]);
+    (0..4).for_each(|i| sparse_matrix[[i, i]] = 4.0);
+    (0..3).for_each(|i| {
+        sparse_matrix[[i, i + 1]] = -1.0;
+        sparse_matrix[[i + 1, i]] = -1.0
+    });
+    sparse_matrix
+}
+
+fn tridiagonal_product(x: &TensorRank1Vec<2, 1>) -> TensorRank1Vec<2, 1> {
+    let x: Vec<TensorRank0> = x.iter().flat_map(|x_a| x_a.iter().copied()).collect();
+    (0..4)
+        .map(|i| {
+            4.0 * x[i] - if i > 0 { x[i - 1] } else { 0.0 } - if i < 3 { x[i + 1] } else { 0.0 }
+        })
+        .collect::<Vec<TensorRank0>>()
+        .chunks(2)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect()
+}
+
+#[test]
+fn constrained() {
+    let solution = NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank1Vec<2, 1>| Ok(tridiagonal_product(x)),
+        |_: &TensorRank1Vec<2, 1>| Ok(tridiagonal()),
+        TensorRank1Vec::new(&[[0.0; 2]; 2]),
+        Some(Dirichlet {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        }),
+        Some(Neumann {
+            places: &[&[1, 1]],
+            values: &[3.0],
+        }),
+    )
+    .unwrap();
+    let product = tridiagonal_product(&solution);
+    assert!((solution[0][0] - 1.0).abs() < TOLERANCE);
+    assert!(product[0][1].abs() < TOLERANCE);
+    assert!(product[1][0].abs() < TOLERANCE);
+    assert!((product[1][1] - 3.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn constrained_tensor_rank_0() {
+    let solution = NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank0| Ok(x - 2.0),
+        |_: &TensorRank0| Ok(1.0),
+        0.0,
+        Some(Dirichlet {
+            places: &[&[]],
+            values: &[1.0],
+        }),
+        None,
+    )
+    .unwrap();
+    assert!((solution - 1.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn constrained_tensor_rank_1() {
+    let tridiagonal = TensorRank2::<4, 1, 1>::new([
+        [4.0, -1.0, 0.0, 0.0],
+        [-1.0, 4.0, -1.0, 0.0],
+        [0.0, -1.0, 4.0, -1.0],
+        [0.0, 0.0, -1.0, 4.0],
+    ]);
+    let solution = NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank1<4, 1>| Ok(&tridiagonal * x),
+        |_: &TensorRank1<4, 1>| Ok(tridiagonal.copy()),
+        TensorRank1::zero(),
+        Some(Dirichlet {
+            places: &[&[0]],
+            values: &[1.0],
+        }),
+        Some(Neumann {
+            places: &[&[3]],
+            values: &[3.0],
+        }),
+    )
+    .unwrap();
+    let product = &tridiagonal * &solution;
+    assert!((solution[0] - 1.0).abs() < TOLERANCE);
+    assert!(product[1].abs() < TOLERANCE);
+    assert!(product[2].abs() < TOLERANCE);
+    assert!((product[3] - 3.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn constrained_tensor_rank_2() {
+    let tridiagonal =
+        TensorRank2::<3, 1, 1>::new([[4.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 4.0]]);
+    let solution = NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank2<3, 1, 1>| Ok(&tridiagonal * x),
+        |_: &TensorRank2<3, 1, 1>| {
+            Ok(TensorRank4::<3, 1, 1, 1, 1>::dyad_ik_jl(
+                &tridiagonal,
+                &TensorRank2::identity(),
+            ))
+        },
+        TensorRank2::zero(),
+        Some(Dirichlet {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        }),
+        Some(Neumann {
+            places: &[&[2, 2]],
+            values: &[3.0],
+        }),
+    )
+    .unwrap();
+    let product = &tridiagonal * &solution;
+    assert!((solution[0][0] - 1.0).abs() < TOLERANCE);
+    product.iter().enumerate().for_each(|(i, product_i)| {
+        product_i
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| (i, *j) != (0, 0))
+            .for_each(|(j, product_ij)| {
+                if (i, j) == (2, 2) {
+                    assert!((product_ij - 3.0).abs() < TOLERANCE)
+                } else {
+                    assert!(product_ij.abs() < TOLERANCE)
+                }
+            })
+    })
+}
+
+#[test]
+fn constrained_iterative() {
+    let solution = NewtonRaphson {
+        linear_solver: LinearSolver::Iterative(IterativeSolver {
+            method: IterativeMethod::ConjugateGradient,
+            preconditioner: Preconditioner::IncompleteCholesky,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank1Vec<2, 1>| Ok(tridiagonal_product(x)),
+        |_: &TensorRank1Vec<2, 1>| Ok(tridiagonal()),
+        TensorRank1Vec::new(&[[0.0; 2]; 2]),
+        Some(Dirichlet {
+            places: &[&[0, 0]],
+            values: &[1.0],
+        }),
+        Some(Neumann {
+            places: &[&[1, 1]],
+            values: &[3.0],
+        }),
+    )
+    .unwrap();
+    let product = tridiagonal_product(&solution);
+    assert!((solution[0][0] - 1.0).abs() < TOLERANCE);
+    assert!(product[0][1].abs() < TOLERANCE);
+    assert!(product[1][0].abs() < TOLERANCE);
+    assert!((product[1][1] - 3.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn constrained_iterative_tensor_rank_1() {
+    let tridiagonal = TensorRank2::<4, 1, 1>::new([
+        [4.0, -1.0, 0.0, 0.0],
+        [-2.0, 4.0, -1.0, 0.0],
+        [0.0, -2.0, 4.0, -1.0],
+        [0.0, 0.0, -2.0, 4.0],
+    ]);
+    let solution = NewtonRaphson {
+        linear_solver: LinearSolver::Iterative(IterativeSolver::default()),
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank1<4, 1>| Ok(&tridiagonal * x),
+        |_: &TensorRank1<4, 1>| Ok(tridiagonal.copy()),
+        TensorRank1::zero(),
+        Some(Dirichlet {
+            places: &[&[0]],
+            values: &[1.0],
+        }),
+        Some(Neumann {
+            places: &[&[3]],
+            values: &[3.0],
+        }),
+    )
+    .unwrap();
+    let product = &tridiagonal * &solution;
+    assert!((solution[0] - 1.0).abs() < TOLERANCE);
+    assert!(product[1].abs() < TOLERANCE);
+    assert!(product[2].abs() < TOLERANCE);
+    assert!((product[3] - 3.0).abs() < TOLERANCE)
+}
+
+#[test]
+fn singular_hessian() {
+    let result = NewtonRaphson {
+        globalization: Globalization::LineSearch(LineSearch::default()),
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank1Vec<2, 1>| Ok(tridiagonal_product(x)),
+        |_: &TensorRank1Vec<2, 1>| {
+            let mut sparse_matrix = tridiagonal();
+            (0..4).for_each(|i| sparse_matrix[[i, i]] = 0.0);
+            (0..3).for_each(|i| {
+                sparse_matrix[[i, i + 1]] = 0.0;
+                sparse_matrix[[i + 1, i]] = 0.0
+            });
+            Ok(sparse_matrix)
+        },
+        TensorRank1Vec::new(&[[1.0; 2]; 2]),
+        None,
+        None,
+    );
+    assert!(matches!(result, Err(OptimizeError::SingularHessian(..))))
+}
+
+#[test]
+fn convergence() {
+    let result = NewtonRaphson {
+        max_steps: 3,
+        ..Default::default()
+    }
+    .minimize(soft_abs_jacobian, soft_abs_hessian, 0.5, None, None);
+    match result {
+        Err(OptimizeError::MaximumStepsReached(3, convergence, _)) => {
+            assert_eq!(convergence.residual_norms.len(), 3);
+            assert_eq!(convergence.step_sizes.len(), 3);
+            assert_eq!(convergence.solution.len(), 1);
+            assert!(convergence.residual_norms[2] < convergence.residual_norms[0])
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn evaluation_steps() {
+    let result = NewtonRaphson {
+        ..Default::default()
+    }
+    .minimize(
+        |x: &TensorRank0| {
+            if x.abs() < 0.5 {
+                Err(OptimizeError::Evaluation(Box::new(std::fmt::Error), 0))
+            } else {
+                Ok(*x)
+            }
+        },
+        |_: &TensorRank0| Ok(1.0),
+        1.0,
+        None,
+        None,
+    );
+    assert!(matches!(result, Err(OptimizeError::Evaluation(_, 1))))
+}
+
+#[test]
+fn non_finite_residual() {
+    assert!(matches!(
+        NewtonRaphson {
+            ..Default::default()
+        }
+        .minimize(
+            |x: &TensorRank0| Ok(x.ln()),
+            |x: &TensorRank0| Ok(1.0 / x),
+            -1.0,
+            None,
+            None
+        ),
+        Err(OptimizeError::NonFiniteResidual(_, _))
+    ))
+}
+
+#[test]
+fn linear_solver_failure() {
+    assert!(matches!(
+        NewtonRaphson {
+            linear_solver: LinearSolver::Iterative(IterativeSolver {
+                max_steps: 1,
+                method: IterativeMethod::ConjugateGradient,
+                preconditioner: Preconditioner::None,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .minimize(
+            |x: &TensorRank1Vec<2, 1>| Ok(tridiagonal_product(x)),
+            |_: &TensorRank1Vec<2, 1>| Ok(tridiagonal()),
+            TensorRank1Vec::new(&[[1.0, 2.0], [3.0, 4.0]]),
+            None,
+            None,
+        ),
+        Err(OptimizeError::LinearSolverFailure(_, _))
+    ))
+}
+
+#[test]
+fn trust_region_failure() {
+    assert!(matches!(
+        NewtonRaphson {
+            globalization: Globalization::TrustRegion(TrustRegion {
+                max_steps: 5,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .minimize_with_objective(
+            |_: &TensorRank0| Ok(TensorRank0::NAN),
+            soft_abs_jacobian,
+            soft_abs_hessian,
+            1.5,
+            None,
+            None
+        ),
+        Err(OptimizeError::TrustRegionFailure(_, _))
+    ))
+}
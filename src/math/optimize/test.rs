@@ -1,5 +1,8 @@
 use super::{super::test::TestError, OptimizeError};
 
+#[cfg(not(feature = "color"))]
+use super::Convergence;
+
 impl From<OptimizeError> for TestError {
     fn from(error: OptimizeError) -> Self {
         TestError {
@@ -7,3 +10,24 @@ impl From<OptimizeError> for TestError {
         }
     }
 }
+
+#[test]
+#[cfg(not(feature = "color"))]
+fn plain() {
+    let error = OptimizeError::MaximumStepsReached(
+        1,
+        Convergence {
+            residual_norms: vec![1.0],
+            ..Default::default()
+        },
+        "NewtonRaphson".to_string(),
+    );
+    assert_eq!(
+        format!("{}", error),
+        "Maximum number of steps (1) reached.\n\
+         After steps: 1.\n\
+         Last residual norm: 1.000000e0.\n\
+         In optimizer: NewtonRaphson."
+    );
+    assert_eq!(format!("{:?}", error), format!("{}", error))
+}
@@ -0,0 +1,207 @@
+#[cfg(test)]
+mod test;
+
+use std::ops::AddAssign;
+
+use super::{
+    rank_0::Scalar, rank_1::TensorRank1, rank_2::TensorRank2, rank_3::TensorRank3,
+    rank_4::TensorRank4, TensorArray,
+};
+
+/// An index into one slot of a tensor.
+///
+/// `D` is the dimension and `C` is the configuration of the slot,
+/// so that an index shared between slots of different configurations does not compile.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Slot<const D: usize, const C: usize>(usize);
+
+impl<const D: usize, const C: usize> Slot<D, C> {
+    /// Returns the value of the index.
+    pub fn value(&self) -> usize {
+        self.0
+    }
+}
+
+/// Iteration over every combination of a tuple of slot indices.
+pub trait Slots
+where
+    Self: Sized,
+{
+    /// Returns an iterator over every combination of the slot indices.
+    fn all() -> impl Iterator<Item = Self>;
+}
+
+impl Slots for () {
+    fn all() -> impl Iterator<Item = Self> {
+        std::iter::once(())
+    }
+}
+
+macro_rules! impl_slots {
+    ($count: literal; $(($d: ident, $c: ident, $n: tt)),+) => {
+        impl<$(const $d: usize, const $c: usize),+> Slots for ($(Slot<$d, $c>,)+) {
+            fn all() -> impl Iterator<Item = Self> {
+                let dimensions = [$($d),+];
+                (0..dimensions.iter().product()).map(move |mut index: usize| {
+                    let mut entries = [0; $count];
+                    entries
+                        .iter_mut()
+                        .zip(dimensions.iter())
+                        .rev()
+                        .for_each(|(entry, dimension)| {
+                            *entry = index % dimension;
+                            index /= dimension;
+                        });
+                    ($(Slot::<$d, $c>(entries[$n]),)+)
+                })
+            }
+        }
+    };
+}
+
+impl_slots!(1; (D0, C0, 0));
+impl_slots!(2; (D0, C0, 0), (D1, C1, 1));
+impl_slots!(3; (D0, C0, 0), (D1, C1, 1), (D2, C2, 2));
+impl_slots!(4; (D0, C0, 0), (D1, C1, 1), (D2, C2, 2), (D3, C3, 3));
+impl_slots!(5; (D0, C0, 0), (D1, C1, 1), (D2, C2, 2), (D3, C3, 3), (D4, C4, 4));
+impl_slots!(6; (D0, C0, 0), (D1, C1, 1), (D2, C2, 2), (D3, C3, 3), (D4, C4, 4), (D5, C5, 5));
+
+/// Access to the entries of tensors by slot indices, as used by [`einsum`](crate::einsum).
+pub trait Einsum {
+    /// The type of the entries.
+    type Item;
+    /// The slot indices of the tensor.
+    type Slots: Slots;
+    /// Returns the entry at the given slot indices.
+    fn entry(&self, slots: Self::Slots) -> Self::Item;
+    /// Returns a mutable reference to the entry at the given slot indices.
+    fn entry_mut(&mut self, slots: Self::Slots) -> &mut Self::Item;
+    /// Returns the tensor with all entries equal to zero.
+    fn zero() -> Self;
+}
+
+impl<T: Scalar> Einsum for T {
+    type Item = T;
+    type Slots = ();
+    fn entry(&self, _slots: Self::Slots) -> Self::Item {
+        *self
+    }
+    fn entry_mut(&mut self, _slots: Self::Slots) -> &mut Self::Item {
+        self
+    }
+    fn zero() -> Self {
+        T::zero()
+    }
+}
+
+impl<const D: usize, const I: usize, T: Scalar> Einsum for TensorRank1<D, I, T> {
+    type Item = T;
+    type Slots = (Slot<D, I>,);
+    fn entry(&self, (i,): Self::Slots) -> Self::Item {
+        self[i.0]
+    }
+    fn entry_mut(&mut self, (i,): Self::Slots) -> &mut Self::Item {
+        &mut self[i.0]
+    }
+    fn zero() -> Self {
+        TensorArray::zero()
+    }
+}
+
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Einsum for TensorRank2<D, I, J, T> {
+    type Item = T;
+    type Slots = (Slot<D, I>, Slot<D, J>);
+    fn entry(&self, (i, j): Self::Slots) -> Self::Item {
+        self[i.0][j.0]
+    }
+    fn entry_mut(&mut self, (i, j): Self::Slots) -> &mut Self::Item {
+        &mut self[i.0][j.0]
+    }
+    fn zero() -> Self {
+        TensorArray::zero()
+    }
+}
+
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Einsum
+    for TensorRank3<D, I, J, K, T>
+{
+    type Item = T;
+    type Slots = (Slot<D, I>, Slot<D, J>, Slot<D, K>);
+    fn entry(&self, (i, j, k): Self::Slots) -> Self::Item {
+        self[i.0][j.0][k.0]
+    }
+    fn entry_mut(&mut self, (i, j, k): Self::Slots) -> &mut Self::Item {
+        &mut self[i.0][j.0][k.0]
+    }
+    fn zero() -> Self {
+        TensorArray::zero()
+    }
+}
+
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Einsum for TensorRank4<D, I, J, K, L, T>
+{
+    type Item = T;
+    type Slots = (Slot<D, I>, Slot<D, J>, Slot<D, K>, Slot<D, L>);
+    fn entry(&self, (i, j, k, l): Self::Slots) -> Self::Item {
+        self[i.0][j.0][k.0][l.0]
+    }
+    fn entry_mut(&mut self, (i, j, k, l): Self::Slots) -> &mut Self::Item {
+        &mut self[i.0][j.0][k.0][l.0]
+    }
+    fn zero() -> Self {
+        TensorArray::zero()
+    }
+}
+
+#[doc(hidden)]
+pub fn add_assign<T: AddAssign>(entry: &mut T, value: T) {
+    *entry += value
+}
+
+#[doc(hidden)]
+pub fn for_each<S: Slots>(function: impl FnMut(S)) {
+    S::all().for_each(function)
+}
+
+/// Contracts tensors using Einstein notation.
+///
+/// The output indices are listed first, then the summed indices, then the product of tensors.
+/// Each index is checked at compile time to have the same dimension and configuration in every slot it appears.
+///
+/// ```
+/// use flavio::{einsum, math::{TensorArray, TensorRank2, TensorRank4}};
+/// let a = TensorRank2::<3, 1, 2>::identity();
+/// let b = TensorRank2::<3, 3, 4>::identity();
+/// let c: TensorRank4<3, 1, 3, 2, 4> = einsum!([i, j, k, l], []: a[i, k] * b[j, l]);
+/// let full_contraction: f64 = einsum!([], [i, j]: a[i, j] * a[i, j]);
+/// assert_eq!(c, TensorRank4::dyad_ik_jl(&a, &b));
+/// assert_eq!(full_contraction, 3.0);
+/// ```
+///
+/// Contracting slots of different configurations does not compile.
+///
+/// ```compile_fail
+/// use flavio::{einsum, math::{TensorArray, TensorRank2}};
+/// let a = TensorRank2::<3, 1, 2>::identity();
+/// let b: TensorRank2<3, 1, 1> = einsum!([i, j], [k]: a[i, k] * a[k, j]);
+/// ```
+#[macro_export]
+macro_rules! einsum {
+    (
+        [$($output: ident),*], [$($summed: ident),*]:
+        $first: ident[$($first_index: ident),+] $(* $tensor: ident[$($index: ident),+])*
+    ) => {{
+        let mut output = <_ as $crate::math::Einsum>::zero();
+        $crate::math::einsum_for_each(|($($output,)*)| {
+            $crate::math::einsum_for_each(|($($summed,)*)| {
+                $crate::math::einsum_add_assign(
+                    $crate::math::Einsum::entry_mut(&mut output, ($($output,)*)),
+                    $crate::math::Einsum::entry(&$first, ($($first_index,)+))
+                        $(* $crate::math::Einsum::entry(&$tensor, ($($index,)+)))*,
+                )
+            })
+        });
+        output
+    }};
+}
@@ -0,0 +1,162 @@
+use super::{
+    super::{
+        rank_4::{
+            ContractAllIndicesWithFirstIndicesOf, ContractFirstSecondIndicesWithSecondIndicesOf,
+            ContractSecondFourthIndicesWithFirstIndicesOf, ContractSecondIndexWithFirstIndexOf,
+        },
+        Rank2, Tensor,
+    },
+    Slot, Slots, TensorArray, TensorRank1, TensorRank2, TensorRank3, TensorRank4,
+};
+use crate::{einsum, math::TensorRank0};
+
+fn get_tensor_rank_1() -> TensorRank1<3, 1> {
+    TensorRank1::new([1.0, 2.0, 3.0])
+}
+
+fn get_other_tensor_rank_1() -> TensorRank1<3, 2> {
+    TensorRank1::new([4.0, 5.0, 6.0])
+}
+
+fn get_tensor_rank_2() -> TensorRank2<3, 1, 2> {
+    TensorRank2::new([[1.0, 4.0, 6.0], [7.0, 2.0, 5.0], [9.0, 8.0, 3.0]])
+}
+
+fn get_other_tensor_rank_2() -> TensorRank2<3, 3, 4> {
+    TensorRank2::new([[3.0, 2.0, 3.0], [6.0, 5.0, 2.0], [4.0, 5.0, 0.0]])
+}
+
+fn get_tensor_rank_4() -> TensorRank4<3, 1, 2, 3, 4> {
+    TensorRank4::new([
+        [
+            [[4.0, 2.0, 4.0], [1.0, 4.0, 3.0], [2.0, 4.0, 4.0]],
+            [[2.0, 2.0, 2.0], [3.0, 1.0, 1.0], [1.0, 4.0, 2.0]],
+            [[1.0, 2.0, 3.0], [2.0, 2.0, 3.0], [1.0, 1.0, 0.0]],
+        ],
+        [
+            [[2.0, 4.0, 2.0], [1.0, 2.0, 3.0], [3.0, 3.0, 2.0]],
+            [[1.0, 1.0, 1.0], [4.0, 2.0, 1.0], [1.0, 4.0, 1.0]],
+            [[2.0, 2.0, 4.0], [3.0, 3.0, 1.0], [0.0, 3.0, 3.0]],
+        ],
+        [
+            [[0.0, 1.0, 4.0], [3.0, 3.0, 3.0], [4.0, 4.0, 0.0]],
+            [[2.0, 3.0, 1.0], [1.0, 2.0, 0.0], [2.0, 2.0, 4.0]],
+            [[3.0, 4.0, 1.0], [2.0, 1.0, 2.0], [4.0, 4.0, 1.0]],
+        ],
+    ])
+}
+
+#[test]
+fn contract_all_indices_with_first_indices_of() {
+    let a = get_tensor_rank_2();
+    let b = TensorRank2::<3, 2, 6>::new(get_tensor_rank_2().as_array());
+    let c = TensorRank2::<3, 3, 7>::new(get_other_tensor_rank_2().as_array());
+    let d = TensorRank2::<3, 4, 8>::new(get_other_tensor_rank_2().transpose().as_array());
+    let s = get_tensor_rank_4();
+    let e: TensorRank4<3, 2, 6, 7, 8> = einsum!(
+        [i, j, k, l], [m, n, o, p]: s[m, n, o, p] * a[m, i] * b[n, j] * c[o, k] * d[p, l]
+    );
+    assert_eq!(
+        e,
+        s.contract_all_indices_with_first_indices_of(&a, &b, &c, &d)
+    )
+}
+
+#[test]
+fn contract_first_second_indices_with_second_indices_of() {
+    let a = TensorRank2::<3, 5, 1>::new(get_tensor_rank_2().as_array());
+    let b = TensorRank2::<3, 6, 2>::new(get_other_tensor_rank_2().as_array());
+    let s = get_tensor_rank_4();
+    let c: TensorRank4<3, 5, 6, 3, 4> =
+        einsum!([i, j, k, l], [m, n]: s[m, n, k, l] * a[i, m] * b[j, n]);
+    assert_eq!(
+        c,
+        s.contract_first_second_indices_with_second_indices_of(&a, &b)
+    )
+}
+
+#[test]
+fn contract_second_fourth_indices_with_first_indices_of() {
+    let a = get_other_tensor_rank_1();
+    let b = TensorRank1::<3, 4>::new(get_tensor_rank_1().as_array());
+    let s = get_tensor_rank_4();
+    let c: TensorRank2<3, 1, 3> = einsum!([i, k], [j, l]: s[i, j, k, l] * a[j] * b[l]);
+    assert_eq!(
+        c,
+        s.contract_second_fourth_indices_with_first_indices_of(&a, &b)
+    )
+}
+
+#[test]
+fn contract_second_index_with_first_index_of() {
+    let a = get_tensor_rank_2().transpose();
+    let s = get_tensor_rank_4();
+    let c: TensorRank4<3, 1, 1, 3, 4> = einsum!([i, j, k, l], [m]: s[i, m, k, l] * a[m, j]);
+    assert_eq!(c, s.contract_second_index_with_first_index_of(&a))
+}
+
+#[test]
+fn dyad() {
+    let a = get_tensor_rank_1();
+    let b = get_other_tensor_rank_1();
+    let c: TensorRank2<3, 1, 2> = einsum!([i, j], []: a[i] * b[j]);
+    assert_eq!(c, TensorRank2::dyad(&a, &b))
+}
+
+#[test]
+fn dyad_il_jk() {
+    let a = TensorRank2::<3, 1, 4>::new(get_tensor_rank_2().as_array());
+    let b = TensorRank2::<3, 2, 3>::new(get_other_tensor_rank_2().as_array());
+    let c: TensorRank4<3, 1, 2, 3, 4> = einsum!([i, j, k, l], []: a[i, l] * b[j, k]);
+    assert_eq!(c, TensorRank4::dyad_il_jk(&a, &b))
+}
+
+#[test]
+fn full_contraction() {
+    let a = get_tensor_rank_2();
+    let b = TensorRank2::<3, 1, 2>::new(get_other_tensor_rank_2().as_array());
+    let c: TensorRank0 = einsum!([], [i, j]: a[i, j] * b[i, j]);
+    assert_eq!(c, a.full_contraction(&b))
+}
+
+#[test]
+fn mul_tensor_rank_1() {
+    let a = get_tensor_rank_2();
+    let b = get_other_tensor_rank_1();
+    let c: TensorRank1<3, 1> = einsum!([i], [j]: a[i, j] * b[j]);
+    assert_eq!(c, &a * &b)
+}
+
+#[test]
+fn mul_tensor_rank_2() {
+    let a = get_tensor_rank_2();
+    let b = TensorRank2::<3, 2, 4>::new(get_other_tensor_rank_2().as_array());
+    let c: TensorRank2<3, 1, 4> = einsum!([i, k], [j]: a[i, j] * b[j, k]);
+    assert_eq!(c, &a * &b)
+}
+
+#[test]
+fn norm_squared() {
+    let a = get_tensor_rank_1();
+    let c: TensorRank0 = einsum!([], [i]: a[i] * a[i]);
+    assert_eq!(c, a.norm_squared())
+}
+
+#[test]
+fn rank_3() {
+    let a = get_tensor_rank_2();
+    let b = get_other_tensor_rank_1();
+    let c: TensorRank3<3, 1, 2, 2> = einsum!([i, j, k], []: a[i, j] * b[k]);
+    let d: TensorRank1<3, 1> = einsum!([i], [j, k]: c[i, j, k] * b[j] * b[k]);
+    assert_eq!(d, &a * &b * (&b * &b))
+}
+
+#[test]
+fn slots() {
+    let all: Vec<_> = <(Slot<2, 1>, Slot<3, 2>)>::all().collect();
+    assert_eq!(all.len(), 6);
+    all.iter().enumerate().for_each(|(index, (i, j))| {
+        assert_eq!(i.value(), index / 3);
+        assert_eq!(j.value(), index % 3);
+    });
+}
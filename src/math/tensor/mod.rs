@@ -1,13 +1,18 @@
 #[cfg(test)]
 pub mod test;
 
+pub mod einsum;
 pub mod rank_0;
 pub mod rank_1;
 pub mod rank_2;
 pub mod rank_3;
 pub mod rank_4;
 
-use rank_0::TensorRank0;
+#[cfg(feature = "simd")]
+mod simd;
+
+use super::IterativeSolver;
+use rank_0::{Scalar, TensorRank0};
 use std::{
     fmt::{Debug, Display},
     ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
@@ -24,27 +29,49 @@ pub trait Convert<T> {
 /// Possible errors for tensors.
 #[derive(Debug)]
 pub enum TensorError {
+    MaximumStepsReached(usize),
     NotPositiveDefinite,
+    Singular,
 }
 
 impl PartialEq for TensorError {
     fn eq(&self, other: &Self) -> bool {
         match self {
-            Self::NotPositiveDefinite => match other {
-                Self::NotPositiveDefinite => true,
-            },
+            Self::MaximumStepsReached(steps) => {
+                matches!(other, Self::MaximumStepsReached(other_steps) if steps == other_steps)
+            }
+            Self::NotPositiveDefinite => matches!(other, Self::NotPositiveDefinite),
+            Self::Singular => matches!(other, Self::Singular),
         }
     }
 }
 
 /// Common methods for Hessians.
-pub trait Hessian {
+pub trait Hessian
+where
+    Self: Debug,
+{
+    /// Eliminates the rows and columns at the specified flattened indices, leaving only unit diagonal entries.
+    fn eliminate(&mut self, indices: &[usize]);
     /// Checks whether the Hessian is positive-definite.
     fn is_positive_definite(&self) -> bool;
+    /// Solves the linear system with the flattened right-hand side using a direct solver.
+    fn solve_directly(&self, rhs: &[TensorRank0]) -> Result<Vec<TensorRank0>, TensorError>;
+    /// Solves the linear system with each of the flattened right-hand sides using a direct solver that factors only once.
+    fn solve_directly_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> Result<Vec<Vec<TensorRank0>>, TensorError>;
+    /// Solves the linear system with the flattened right-hand side using the iterative solver.
+    fn solve_iteratively(
+        &self,
+        rhs: &[TensorRank0],
+        iterative_solver: &IterativeSolver,
+    ) -> Result<Vec<TensorRank0>, TensorError>;
 }
 
 /// Common methods for rank-2 tensors.
-pub trait Rank2: Sized {
+pub trait Rank2<T: Scalar = TensorRank0>: Sized {
     /// The type that is the transpose of the tensor.
     type Transpose;
     /// Returns the Cholesky decomposition of the rank-2 tensor.
@@ -52,25 +79,27 @@ pub trait Rank2: Sized {
     /// Returns the deviatoric component of the rank-2 tensor.
     fn deviatoric(&self) -> Self;
     /// Returns the deviatoric component and trace of the rank-2 tensor.
-    fn deviatoric_and_trace(&self) -> (Self, TensorRank0);
+    fn deviatoric_and_trace(&self) -> (Self, T);
     /// Checks whether the tensor is a diagonal tensor.
     fn is_diagonal(&self) -> bool;
     /// Checks whether the tensor is the identity tensor.
     fn is_identity(&self) -> bool;
     /// Returns the second invariant of the rank-2 tensor.
-    fn second_invariant(&self) -> TensorRank0 {
-        0.5 * (self.trace().powi(2) - self.squared_trace())
+    fn second_invariant(&self) -> T {
+        T::from_f64(0.5) * (self.trace().powi(2) - self.squared_trace())
     }
     /// Returns the trace of the rank-2 tensor squared.
-    fn squared_trace(&self) -> TensorRank0;
+    fn squared_trace(&self) -> T;
     /// Returns the trace of the rank-2 tensor.
-    fn trace(&self) -> TensorRank0;
+    fn trace(&self) -> T;
     /// Returns the transpose of the rank-2 tensor.
     fn transpose(&self) -> Self::Transpose;
 }
 
 /// Common methods for tensors.
-pub trait Tensor
+///
+/// `T` is the scalar type of the entries.
+pub trait Tensor<T: Scalar = TensorRank0>
 where
     for<'a> Self: Sized
         + Debug
@@ -79,13 +108,13 @@ where
         + Add<&'a Self, Output = Self>
         + AddAssign
         + AddAssign<&'a Self>
-        + Div<TensorRank0, Output = Self>
-        + Mul<TensorRank0, Output = Self>
+        + Div<T, Output = Self>
+        + Mul<T, Output = Self>
         + Sub<Self, Output = Self>
         + Sub<&'a Self, Output = Self>
         + SubAssign
         + SubAssign<&'a Self>,
-    Self::Item: Tensor,
+    Self::Item: Tensor<T>,
 {
     /// The type of item encountered when iterating over the tensor.
     type Item;
@@ -94,20 +123,27 @@ where
     /// This method was implemented instead of the Copy trait to avoid unintended copy creations.
     fn copy(&self) -> Self;
     /// Returns the full contraction with another tensor.
-    fn full_contraction(&self, tensor: &Self) -> TensorRank0 {
+    fn full_contraction(&self, tensor: &Self) -> T {
         self.iter()
             .zip(tensor.iter())
             .map(|(self_entry, tensor_entry)| self_entry.full_contraction(tensor_entry))
             .sum()
     }
-    /// Returns a reference to the entry at the specified indices.
-    fn get_at(&self, _indices: &[usize]) -> &TensorRank0 {
-        panic!("Need to implement get_at() for {:?}.", self)
+    /// Returns the entries flattened into a vector.
+    fn flattened(&self) -> Vec<T> {
+        self.iter().flat_map(|entry| entry.flattened()).collect()
     }
-    /// Returns a mutable reference to the entry at the specified indices.
-    fn get_at_mut(&mut self, _indices: &[usize]) -> &mut TensorRank0 {
-        panic!("Need to implement get_at_mut() for {:?}.", self)
+    /// Sets the entries from an iterator over the flattened entries.
+    fn set_flattened(&mut self, entries: &mut impl Iterator<Item = T>) {
+        self.iter_mut()
+            .for_each(|entry| entry.set_flattened(entries))
     }
+    /// Returns the flattened index of the entry at the specified indices.
+    fn flat_index(&self, indices: &[usize]) -> usize;
+    /// Returns a reference to the entry at the specified indices.
+    fn get_at(&self, indices: &[usize]) -> &T;
+    /// Returns a mutable reference to the entry at the specified indices.
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T;
     /// Checks whether the tensor is the zero tensor.
     fn is_zero(&self) -> bool {
         self.iter().filter(|entry| !entry.is_zero()).count() == 0
@@ -121,11 +157,11 @@ where
     /// The iterator yields all items from start to end. [Read more](https://doc.rust-lang.org/std/iter/)
     fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item>;
     /// Returns the tensor norm.
-    fn norm(&self) -> TensorRank0 {
+    fn norm(&self) -> T {
         self.norm_squared().sqrt()
     }
     /// Returns the tensor norm squared.
-    fn norm_squared(&self) -> TensorRank0 {
+    fn norm_squared(&self) -> T {
         self.full_contraction(self)
     }
     /// Returns the tensor normalized.
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use super::{super::super::write_tensor_rank_0, Tensor, TensorArray, TensorRank0};
+use super::{super::super::write_tensor_rank_0, Scalar, Tensor, TensorArray, TensorRank0};
 use std::{
     fmt::{Display, Formatter, Result},
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
@@ -9,19 +9,19 @@ use std::{
 
 /// A list of tensors of rank 0 (a list of scalars).
 ///
-/// `W` is the list length.
+/// `W` is the list length, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank0List<const W: usize>(pub [TensorRank0; W]);
+pub struct TensorRank0List<const W: usize, T = TensorRank0>(pub [T; W]);
 
 /// Display implementation for rank-0 lists.
-impl<const W: usize> Display for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Display for TensorRank0List<W, T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         write!(f, "\x1B[s")?;
         write!(f, "[")?;
         self.0.chunks(5).enumerate().try_for_each(|(i, chunk)| {
             chunk
                 .iter()
-                .try_for_each(|entry| write_tensor_rank_0(f, entry))?;
+                .try_for_each(|entry| write_tensor_rank_0(f, &entry.to_f64()))?;
             if (i + 1) * 5 < W {
                 writeln!(f, "\x1B[2D,")?;
                 write!(f, "\x1B[u")?;
@@ -34,18 +34,27 @@ impl<const W: usize> Display for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> Tensor for TensorRank0List<W> {
-    type Item = TensorRank0;
+impl<const W: usize, T: Scalar> Tensor<T> for TensorRank0List<W, T> {
+    type Item = T;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
-    fn full_contraction(&self, tensor_rank_0_list: &Self) -> TensorRank0 {
+    fn full_contraction(&self, tensor_rank_0_list: &Self) -> T {
         self.iter()
             .zip(tensor_rank_0_list.iter())
-            .map(|(self_entry, tensor_rank_0)| self_entry * tensor_rank_0)
+            .map(|(self_entry, tensor_rank_0)| *self_entry * *tensor_rank_0)
             .sum()
     }
-    fn iter(&self) -> impl Iterator<Item = &TensorRank0> {
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        indices[0]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]]
+    }
+    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
     fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
@@ -53,25 +62,25 @@ impl<const W: usize> Tensor for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> TensorArray for TensorRank0List<W> {
-    type Array = [TensorRank0; W];
-    type Item = TensorRank0;
+impl<const W: usize, T: Scalar> TensorArray for TensorRank0List<W, T> {
+    type Array = [T; W];
+    type Item = T;
     fn as_array(&self) -> Self::Array {
         self.0
     }
     fn identity() -> Self {
-        Self([1.0; W])
+        Self([T::one(); W])
     }
     fn new(array: Self::Array) -> Self {
         Self(array)
     }
     fn zero() -> Self {
-        Self([0.0; W])
+        Self([T::zero(); W])
     }
 }
 
-impl<const W: usize> FromIterator<TensorRank0> for TensorRank0List<W> {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank0>>(into_iterator: Ii) -> Self {
+impl<const W: usize, T: Scalar> FromIterator<T> for TensorRank0List<W, T> {
+    fn from_iter<Ii: IntoIterator<Item = T>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_0_list = Self::zero();
         tensor_rank_0_list
             .iter_mut()
@@ -81,20 +90,20 @@ impl<const W: usize> FromIterator<TensorRank0> for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> Index<usize> for TensorRank0List<W> {
-    type Output = TensorRank0;
+impl<const W: usize, T: Scalar> Index<usize> for TensorRank0List<W, T> {
+    type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const W: usize> IndexMut<usize> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> IndexMut<usize> for TensorRank0List<W, T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const W: usize> Add for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Add for TensorRank0List<W, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_0_list: Self) -> Self::Output {
         self += tensor_rank_0_list;
@@ -102,7 +111,7 @@ impl<const W: usize> Add for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> Add<&Self> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Add<&Self> for TensorRank0List<W, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_0_list: &Self) -> Self::Output {
         self += tensor_rank_0_list;
@@ -110,133 +119,133 @@ impl<const W: usize> Add<&Self> for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> AddAssign for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> AddAssign for TensorRank0List<W, T> {
     fn add_assign(&mut self, tensor_rank_0_list: Self) {
         self.iter_mut()
             .zip(tensor_rank_0_list.iter())
-            .for_each(|(self_entry, tensor_rank_0)| *self_entry += tensor_rank_0);
+            .for_each(|(self_entry, tensor_rank_0)| *self_entry += *tensor_rank_0);
     }
 }
 
-impl<const W: usize> AddAssign<&Self> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> AddAssign<&Self> for TensorRank0List<W, T> {
     fn add_assign(&mut self, tensor_rank_0_list: &Self) {
         self.iter_mut()
             .zip(tensor_rank_0_list.iter())
-            .for_each(|(self_entry, tensor_rank_0)| *self_entry += tensor_rank_0);
+            .for_each(|(self_entry, tensor_rank_0)| *self_entry += *tensor_rank_0);
     }
 }
 
-impl<const W: usize> Div<TensorRank0> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Div<T> for TensorRank0List<W, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const W: usize> Div<&TensorRank0> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Div<&T> for TensorRank0List<W, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const W: usize> DivAssign<TensorRank0> for TensorRank0List<W> {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i /= &tensor_rank_0);
+impl<const W: usize, T: Scalar> DivAssign<T> for TensorRank0List<W, T> {
+    fn div_assign(&mut self, tensor_rank_0: T) {
+        self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
     }
 }
 
-impl<const W: usize> DivAssign<&TensorRank0> for TensorRank0List<W> {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
+impl<const W: usize, T: Scalar> DivAssign<&T> for TensorRank0List<W, T> {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
+        self.iter_mut().for_each(|self_i| *self_i /= *tensor_rank_0);
     }
 }
 
-impl<const W: usize> Mul<TensorRank0> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Mul<T> for TensorRank0List<W, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-// impl<const W: usize> Mul<TensorRank0> for &TensorRank0List<W> {
-//     type Output = TensorRank0List<W>;
-//     fn mul(self, tensor_rank_0: TensorRank0) -> Self::Output {
+// impl<const W: usize, T: Scalar> Mul<T> for &TensorRank0List<W, T> {
+//     type Output = TensorRank0List<W, T>;
+//     fn mul(self, tensor_rank_0: T) -> Self::Output {
 //         self.iter().map(|self_i| self_i * tensor_rank_0).collect()
 //     }
 // }
 
-impl<const W: usize> Mul<&TensorRank0> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Mul<&T> for TensorRank0List<W, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const W: usize> Mul<&TensorRank0> for &TensorRank0List<W> {
-    type Output = TensorRank0List<W>;
-    fn mul(self, tensor_rank_0: &TensorRank0) -> Self::Output {
-        self.iter().map(|self_i| self_i * tensor_rank_0).collect()
+impl<const W: usize, T: Scalar> Mul<&T> for &TensorRank0List<W, T> {
+    type Output = TensorRank0List<W, T>;
+    fn mul(self, tensor_rank_0: &T) -> Self::Output {
+        self.iter().map(|self_i| *self_i * *tensor_rank_0).collect()
     }
 }
 
-impl<const W: usize> MulAssign<TensorRank0> for TensorRank0List<W> {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i *= &tensor_rank_0);
+impl<const W: usize, T: Scalar> MulAssign<T> for TensorRank0List<W, T> {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
+        self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
     }
 }
 
-impl<const W: usize> MulAssign<&TensorRank0> for TensorRank0List<W> {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
+impl<const W: usize, T: Scalar> MulAssign<&T> for TensorRank0List<W, T> {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
+        self.iter_mut().for_each(|self_i| *self_i *= *tensor_rank_0);
     }
 }
 
-impl<const W: usize> Mul for TensorRank0List<W> {
-    type Output = TensorRank0;
+impl<const W: usize, T: Scalar> Mul for TensorRank0List<W, T> {
+    type Output = T;
     fn mul(self, tensor_rank_0_list: Self) -> Self::Output {
         self.iter()
             .zip(tensor_rank_0_list.iter())
-            .map(|(self_entry, tensor_rank_0_list_entry)| self_entry * tensor_rank_0_list_entry)
+            .map(|(self_entry, tensor_rank_0_list_entry)| *self_entry * *tensor_rank_0_list_entry)
             .sum()
     }
 }
 
-impl<const W: usize> Mul<&Self> for TensorRank0List<W> {
-    type Output = TensorRank0;
+impl<const W: usize, T: Scalar> Mul<&Self> for TensorRank0List<W, T> {
+    type Output = T;
     fn mul(self, tensor_rank_0_list: &Self) -> Self::Output {
         self.iter()
             .zip(tensor_rank_0_list.iter())
-            .map(|(self_entry, tensor_rank_0_list_entry)| self_entry * tensor_rank_0_list_entry)
+            .map(|(self_entry, tensor_rank_0_list_entry)| *self_entry * *tensor_rank_0_list_entry)
             .sum()
     }
 }
 
-impl<const W: usize> Mul<TensorRank0List<W>> for &TensorRank0List<W> {
-    type Output = TensorRank0;
-    fn mul(self, tensor_rank_0_list: TensorRank0List<W>) -> Self::Output {
+impl<const W: usize, T: Scalar> Mul<TensorRank0List<W, T>> for &TensorRank0List<W, T> {
+    type Output = T;
+    fn mul(self, tensor_rank_0_list: TensorRank0List<W, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_0_list.iter())
-            .map(|(self_entry, tensor_rank_0_list_entry)| self_entry * tensor_rank_0_list_entry)
+            .map(|(self_entry, tensor_rank_0_list_entry)| *self_entry * *tensor_rank_0_list_entry)
             .sum()
     }
 }
 
-impl<const W: usize> Mul for &TensorRank0List<W> {
-    type Output = TensorRank0;
+impl<const W: usize, T: Scalar> Mul for &TensorRank0List<W, T> {
+    type Output = T;
     fn mul(self, tensor_rank_0_list: Self) -> Self::Output {
         self.iter()
             .zip(tensor_rank_0_list.iter())
-            .map(|(self_entry, tensor_rank_0_list_entry)| self_entry * tensor_rank_0_list_entry)
+            .map(|(self_entry, tensor_rank_0_list_entry)| *self_entry * *tensor_rank_0_list_entry)
             .sum()
     }
 }
 
-impl<const W: usize> Sub for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Sub for TensorRank0List<W, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_0_list: Self) -> Self::Output {
         self -= tensor_rank_0_list;
@@ -244,7 +253,7 @@ impl<const W: usize> Sub for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> Sub<&Self> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> Sub<&Self> for TensorRank0List<W, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_0_list: &Self) -> Self::Output {
         self -= tensor_rank_0_list;
@@ -252,18 +261,18 @@ impl<const W: usize> Sub<&Self> for TensorRank0List<W> {
     }
 }
 
-impl<const W: usize> SubAssign for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> SubAssign for TensorRank0List<W, T> {
     fn sub_assign(&mut self, tensor_rank_0_list: Self) {
         self.iter_mut()
             .zip(tensor_rank_0_list.iter())
-            .for_each(|(self_entry, tensor_rank_0)| *self_entry -= tensor_rank_0);
+            .for_each(|(self_entry, tensor_rank_0)| *self_entry -= *tensor_rank_0);
     }
 }
 
-impl<const W: usize> SubAssign<&Self> for TensorRank0List<W> {
+impl<const W: usize, T: Scalar> SubAssign<&Self> for TensorRank0List<W, T> {
     fn sub_assign(&mut self, tensor_rank_0_list: &Self) {
         self.iter_mut()
             .zip(tensor_rank_0_list.iter())
-            .for_each(|(self_entry, tensor_rank_0)| *self_entry -= tensor_rank_0);
+            .for_each(|(self_entry, tensor_rank_0)| *self_entry -= *tensor_rank_0);
     }
 }
@@ -84,3 +84,12 @@ fn zero() {
         .iter()
         .for_each(|tensor_rank_0_list_i| assert_eq!(tensor_rank_0_list_i, &0.0));
 }
+
+#[test]
+fn mul_tensor_rank_1_list_f32() {
+    assert_eq!(
+        TensorRank0List::<4, f32>::new([1.0, 2.0, 3.0, 4.0])
+            * TensorRank0List::new([5.0, 6.0, 7.0, 8.0]),
+        70.0_f32
+    )
+}
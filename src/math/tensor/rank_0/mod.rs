@@ -6,11 +6,134 @@ use super::test::ErrorTensor;
 
 pub mod list;
 
-use super::{Hessian, Tensor, TensorArray};
+use super::{super::IterativeSolver, Hessian, Tensor, TensorArray, TensorError};
+use std::{
+    fmt::{Debug, Display},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 /// A tensor of rank 0 (a scalar).
 pub type TensorRank0 = f64;
 
+/// Common methods for the scalar types that tensors are generic over.
+pub trait Scalar
+where
+    Self: 'static
+        + Copy
+        + PartialOrd
+        + Product
+        + Debug
+        + Display
+        + PartialEq
+        + Sum
+        + Add<Output = Self>
+        + AddAssign
+        + Div<Output = Self>
+        + DivAssign
+        + Mul<Output = Self>
+        + MulAssign
+        + Neg<Output = Self>
+        + Sub<Output = Self>
+        + SubAssign
+        + Tensor<Self>,
+{
+    /// Returns the scalar closest to the given double-precision value.
+    fn from_f64(value: TensorRank0) -> Self;
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+    /// Raises the scalar to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+    /// Returns the double-precision value closest to the (real part of the) scalar.
+    fn to_f64(self) -> TensorRank0;
+    /// Returns the additive identity.
+    fn zero() -> Self;
+}
+
+macro_rules! real_scalar {
+    ($scalar:ty) => {
+        impl Scalar for $scalar {
+            fn from_f64(value: TensorRank0) -> Self {
+                value as $scalar
+            }
+            fn one() -> Self {
+                1.0
+            }
+            fn powi(self, n: i32) -> Self {
+                <$scalar>::powi(self, n)
+            }
+            fn sqrt(self) -> Self {
+                <$scalar>::sqrt(self)
+            }
+            fn to_f64(self) -> TensorRank0 {
+                self as TensorRank0
+            }
+            fn zero() -> Self {
+                0.0
+            }
+        }
+        impl Tensor<$scalar> for $scalar {
+            type Item = $scalar;
+            fn copy(&self) -> $scalar {
+                *self
+            }
+            fn full_contraction(&self, tensor_rank_0: &Self) -> $scalar {
+                self * tensor_rank_0
+            }
+            fn flattened(&self) -> Vec<$scalar> {
+                vec![*self]
+            }
+            fn set_flattened(&mut self, entries: &mut impl Iterator<Item = $scalar>) {
+                *self = entries
+                    .next()
+                    .expect("Not enough entries to set the flattened tensor.")
+            }
+            fn flat_index(&self, _indices: &[usize]) -> usize {
+                0
+            }
+            fn get_at(&self, _indices: &[usize]) -> &$scalar {
+                self
+            }
+            fn get_at_mut(&mut self, _indices: &[usize]) -> &mut $scalar {
+                self
+            }
+            fn is_zero(&self) -> bool {
+                self == &0.0
+            }
+            fn iter(&self) -> impl Iterator<Item = &Self::Item> {
+                std::iter::once(self)
+            }
+            fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
+                std::iter::once(self)
+            }
+            fn normalized(self) -> Self {
+                1.0
+            }
+        }
+        impl TensorArray for $scalar {
+            type Array = [Self; 1];
+            type Item = $scalar;
+            fn as_array(&self) -> Self::Array {
+                [self.copy()]
+            }
+            fn identity() -> Self {
+                1.0
+            }
+            fn new(array: Self::Array) -> Self {
+                array[0]
+            }
+            fn zero() -> Self {
+                0.0
+            }
+        }
+    };
+}
+
+real_scalar!(f32);
+real_scalar!(TensorRank0);
+
 #[cfg(test)]
 impl ErrorTensor for TensorRank0 {
     fn error(
@@ -35,46 +158,32 @@ impl ErrorTensor for TensorRank0 {
 }
 
 impl Hessian for TensorRank0 {
+    fn eliminate(&mut self, indices: &[usize]) {
+        if !indices.is_empty() {
+            *self = 1.0
+        }
+    }
     fn is_positive_definite(&self) -> bool {
         self > &0.0
     }
-}
-
-impl Tensor for TensorRank0 {
-    type Item = TensorRank0;
-    fn copy(&self) -> TensorRank0 {
-        *self
-    }
-    fn full_contraction(&self, tensor_rank_0: &Self) -> TensorRank0 {
-        self * tensor_rank_0
-    }
-    fn is_zero(&self) -> bool {
-        self == &0.0
-    }
-    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
-        [0.0].iter()
-    }
-    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
-        [self].into_iter()
-    }
-    fn normalized(self) -> Self {
-        1.0
-    }
-}
-
-impl TensorArray for TensorRank0 {
-    type Array = [Self; 1];
-    type Item = TensorRank0;
-    fn as_array(&self) -> Self::Array {
-        [self.copy()]
-    }
-    fn identity() -> Self {
-        1.0
+    fn solve_directly(&self, rhs: &[TensorRank0]) -> Result<Vec<TensorRank0>, TensorError> {
+        if self == &0.0 {
+            Err(TensorError::Singular)
+        } else {
+            Ok(vec![rhs[0] / self])
+        }
     }
-    fn new(array: Self::Array) -> Self {
-        array[0]
+    fn solve_directly_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> Result<Vec<Vec<TensorRank0>>, TensorError> {
+        rhs.iter().map(|rhs| self.solve_directly(rhs)).collect()
     }
-    fn zero() -> Self {
-        0.0
+    fn solve_iteratively(
+        &self,
+        rhs: &[TensorRank0],
+        iterative_solver: &IterativeSolver,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        iterative_solver.solve_dense(&self.flattened(), rhs)
     }
 }
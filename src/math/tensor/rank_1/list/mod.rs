@@ -14,25 +14,27 @@ use crate::math::{Tensor, TensorArray, TensorRank2};
 
 use super::{
     super::{super::write_tensor_rank_0, Convert},
-    TensorRank0, TensorRank1,
+    Scalar, TensorRank0, TensorRank1,
 };
 
 /// A list of *d*-dimensional tensors of rank 1.
 ///
-/// `D` is the dimension, `I` is the configuration, `W` is the list length.
+/// `D` is the dimension, `I` is the configuration, `W` is the list length, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank1List<const D: usize, const I: usize, const W: usize>(
-    pub [TensorRank1<D, I>; W],
+pub struct TensorRank1List<const D: usize, const I: usize, const W: usize, T = TensorRank0>(
+    pub [TensorRank1<D, I, T>; W],
 );
 
-impl<const D: usize, const I: usize, const W: usize> Display for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Display
+    for TensorRank1List<D, I, W, T>
+{
     fn fmt(&self, f: &mut Formatter) -> Result {
         write!(f, "\x1B[s")?;
         write!(f, "[[")?;
         self.iter().enumerate().try_for_each(|(i, tensor_rank_1)| {
             tensor_rank_1
                 .iter()
-                .try_for_each(|entry| write_tensor_rank_0(f, entry))?;
+                .try_for_each(|entry| write_tensor_rank_0(f, &entry.to_f64()))?;
             if i + 1 < W {
                 writeln!(f, "\x1B[2D],")?;
                 write!(f, "\x1B[u")?;
@@ -112,9 +114,9 @@ impl<const D: usize, const I: usize, const W: usize> ErrorTensor for TensorRank1
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> TensorRank1List<D, I, W, T> {
     /// Returns the sum of the full dot product of each tensor in each list.
-    pub fn dot(&self, tensors: &Self) -> TensorRank0 {
+    pub fn dot(&self, tensors: &Self) -> T {
         self.iter()
             .zip(tensors.iter())
             .map(|(entry, tensor)| entry * tensor)
@@ -122,15 +124,20 @@ impl<const D: usize, const I: usize, const W: usize> TensorRank1List<D, I, W> {
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Tensor for TensorRank1List<D, I, W> {
-    type Item = TensorRank1<D, I>;
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Tensor<T>
+    for TensorRank1List<D, I, W, T>
+{
+    type Item = TensorRank1<D, I, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
-    fn get_at(&self, indices: &[usize]) -> &TensorRank0 {
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        D * indices[0] + indices[1]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
         &self[indices[0]][indices[1]]
     }
-    fn get_at_mut(&mut self, indices: &[usize]) -> &mut TensorRank0 {
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
         &mut self[indices[0]][indices[1]]
     }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
@@ -141,11 +148,13 @@ impl<const D: usize, const I: usize, const W: usize> Tensor for TensorRank1List<
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> TensorArray for TensorRank1List<D, I, W> {
-    type Array = [[TensorRank0; D]; W];
-    type Item = TensorRank1<D, I>;
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> TensorArray
+    for TensorRank1List<D, I, W, T>
+{
+    type Array = [[T; D]; W];
+    type Item = TensorRank1<D, I, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[0.0; D]; W];
+        let mut array = [[T::zero(); D]; W];
         array
             .iter_mut()
             .zip(self.iter())
@@ -162,22 +171,24 @@ impl<const D: usize, const I: usize, const W: usize> TensorArray for TensorRank1
             .collect()
     }
     fn zero() -> Self {
-        Self(from_fn(|_| super::zero()))
+        Self(from_fn(|_| TensorRank1::zero()))
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize>
-    Convert<TensorRank1List<D, J, W>> for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar>
+    Convert<TensorRank1List<D, J, W, T>> for TensorRank1List<D, I, W, T>
 {
-    fn convert(&self) -> TensorRank1List<D, J, W> {
+    fn convert(&self) -> TensorRank1List<D, J, W, T> {
         self.iter()
             .map(|self_entry| self_entry.iter().copied().collect())
             .collect()
     }
 }
 
-impl<const D: usize, const W: usize> From<TensorRank1List<D, 0, W>> for TensorRank1List<D, 1, W> {
-    fn from(tensor_rank_1_list: TensorRank1List<D, 0, W>) -> Self {
+impl<const D: usize, const W: usize, T: Scalar> From<TensorRank1List<D, 0, W, T>>
+    for TensorRank1List<D, 1, W, T>
+{
+    fn from(tensor_rank_1_list: TensorRank1List<D, 0, W, T>) -> Self {
         tensor_rank_1_list
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -185,10 +196,10 @@ impl<const D: usize, const W: usize> From<TensorRank1List<D, 0, W>> for TensorRa
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> FromIterator<TensorRank1<D, I>>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> FromIterator<TensorRank1<D, I, T>>
+    for TensorRank1List<D, I, W, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank1<D, I>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank1<D, I, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_1_list = Self::zero();
         tensor_rank_1_list
             .iter_mut()
@@ -198,20 +209,26 @@ impl<const D: usize, const I: usize, const W: usize> FromIterator<TensorRank1<D,
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Index<usize> for TensorRank1List<D, I, W> {
-    type Output = TensorRank1<D, I>;
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Index<usize>
+    for TensorRank1List<D, I, W, T>
+{
+    type Output = TensorRank1<D, I, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> IndexMut<usize> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> IndexMut<usize>
+    for TensorRank1List<D, I, W, T>
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> std::iter::Sum for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> std::iter::Sum
+    for TensorRank1List<D, I, W, T>
+{
     fn sum<Ii>(iter: Ii) -> Self
     where
         Ii: Iterator<Item = Self>,
@@ -222,83 +239,89 @@ impl<const D: usize, const I: usize, const W: usize> std::iter::Sum for TensorRa
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Div<TensorRank0> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Div<T>
+    for TensorRank1List<D, I, W, T>
+{
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Div<&TensorRank0>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Div<&T>
+    for TensorRank1List<D, I, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> DivAssign<TensorRank0>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> DivAssign<T>
+    for TensorRank1List<D, I, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> DivAssign<&TensorRank0>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> DivAssign<&T>
+    for TensorRank1List<D, I, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Mul<TensorRank0> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Mul<T>
+    for TensorRank1List<D, I, W, T>
+{
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
-impl<const D: usize, const I: usize, const W: usize> Mul<&TensorRank0>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Mul<&T>
+    for TensorRank1List<D, I, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Mul<&TensorRank0>
-    for &TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Mul<&T>
+    for &TensorRank1List<D, I, W, T>
 {
-    type Output = TensorRank1List<D, I, W>;
-    fn mul(self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    type Output = TensorRank1List<D, I, W, T>;
+    fn mul(self, tensor_rank_0: &T) -> Self::Output {
         self.iter().map(|self_i| self_i * tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> MulAssign<TensorRank0>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> MulAssign<T>
+    for TensorRank1List<D, I, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> MulAssign<&TensorRank0>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> MulAssign<&T>
+    for TensorRank1List<D, I, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Add for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Add
+    for TensorRank1List<D, I, W, T>
+{
     type Output = Self;
     fn add(mut self, tensor_rank_1_list: Self) -> Self::Output {
         self += tensor_rank_1_list;
@@ -306,7 +329,9 @@ impl<const D: usize, const I: usize, const W: usize> Add for TensorRank1List<D,
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Add<&Self> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Add<&Self>
+    for TensorRank1List<D, I, W, T>
+{
     type Output = Self;
     fn add(mut self, tensor_rank_1_list: &Self) -> Self::Output {
         self += tensor_rank_1_list;
@@ -314,7 +339,9 @@ impl<const D: usize, const I: usize, const W: usize> Add<&Self> for TensorRank1L
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> AddAssign for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> AddAssign
+    for TensorRank1List<D, I, W, T>
+{
     fn add_assign(&mut self, tensor_rank_1_list: Self) {
         self.iter_mut()
             .zip(tensor_rank_1_list.iter())
@@ -322,7 +349,9 @@ impl<const D: usize, const I: usize, const W: usize> AddAssign for TensorRank1Li
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> AddAssign<&Self> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> AddAssign<&Self>
+    for TensorRank1List<D, I, W, T>
+{
     fn add_assign(&mut self, tensor_rank_1_list: &Self) {
         self.iter_mut()
             .zip(tensor_rank_1_list.iter())
@@ -330,11 +359,11 @@ impl<const D: usize, const I: usize, const W: usize> AddAssign<&Self> for Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<TensorRank1List<D, J, W>>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar>
+    Mul<TensorRank1List<D, J, W, T>> for TensorRank1List<D, I, W, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_list: TensorRank1List<D, J, W>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_list: TensorRank1List<D, J, W, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_list.iter())
             .map(|(self_entry, tensor_rank_1_list_entry)| {
@@ -344,11 +373,11 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<TensorR
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<&TensorRank1List<D, J, W>>
-    for TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar>
+    Mul<&TensorRank1List<D, J, W, T>> for TensorRank1List<D, I, W, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_list: &TensorRank1List<D, J, W>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_list: &TensorRank1List<D, J, W, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_list.iter())
             .map(|(self_entry, tensor_rank_1_list_entry)| {
@@ -358,11 +387,11 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<&Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<TensorRank1List<D, J, W>>
-    for &TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar>
+    Mul<TensorRank1List<D, J, W, T>> for &TensorRank1List<D, I, W, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_list: TensorRank1List<D, J, W>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_list: TensorRank1List<D, J, W, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_list.iter())
             .map(|(self_entry, tensor_rank_1_list_entry)| {
@@ -372,11 +401,11 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<TensorR
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<&TensorRank1List<D, J, W>>
-    for &TensorRank1List<D, I, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar>
+    Mul<&TensorRank1List<D, J, W, T>> for &TensorRank1List<D, I, W, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_list: &TensorRank1List<D, J, W>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_list: &TensorRank1List<D, J, W, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_list.iter())
             .map(|(self_entry, tensor_rank_1_list_entry)| {
@@ -386,7 +415,9 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<&Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Sub for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Sub
+    for TensorRank1List<D, I, W, T>
+{
     type Output = Self;
     fn sub(mut self, tensor_rank_1_list: Self) -> Self::Output {
         self -= tensor_rank_1_list;
@@ -394,7 +425,9 @@ impl<const D: usize, const I: usize, const W: usize> Sub for TensorRank1List<D,
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> Sub<&Self> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> Sub<&Self>
+    for TensorRank1List<D, I, W, T>
+{
     type Output = Self;
     fn sub(mut self, tensor_rank_1_list: &Self) -> Self::Output {
         self -= tensor_rank_1_list;
@@ -402,7 +435,9 @@ impl<const D: usize, const I: usize, const W: usize> Sub<&Self> for TensorRank1L
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> SubAssign for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> SubAssign
+    for TensorRank1List<D, I, W, T>
+{
     fn sub_assign(&mut self, tensor_rank_1_list: Self) {
         self.iter_mut()
             .zip(tensor_rank_1_list.iter())
@@ -410,7 +445,9 @@ impl<const D: usize, const I: usize, const W: usize> SubAssign for TensorRank1Li
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize> SubAssign<&Self> for TensorRank1List<D, I, W> {
+impl<const D: usize, const I: usize, const W: usize, T: Scalar> SubAssign<&Self>
+    for TensorRank1List<D, I, W, T>
+{
     fn sub_assign(&mut self, tensor_rank_1_list: &Self) {
         self.iter_mut()
             .zip(tensor_rank_1_list.iter())
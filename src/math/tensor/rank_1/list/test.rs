@@ -350,6 +350,30 @@ fn mul_tensor_rank_1_list_to_self() {
         });
 }
 
+#[test]
+fn mul_tensor_rank_1_list_f32() {
+    let tensor_rank_1_list = TensorRank1List::<3, 1, 8, f32>::new(
+        get_tensor_rank_1_list()
+            .as_array()
+            .map(|entry| entry.map(|entry_i| entry_i as f32)),
+    );
+    let other_tensor_rank_1_list = TensorRank1List::<3, 1, 8, f32>::new(
+        get_other_tensor_rank_1_list()
+            .as_array()
+            .map(|entry| entry.map(|entry_i| entry_i as f32)),
+    );
+    (tensor_rank_1_list * other_tensor_rank_1_list)
+        .iter()
+        .zip(get_tensor_rank_1_list_mul_other_tensor_rank_1_list().iter())
+        .for_each(|(tensor_rank_2_entry, mul_entry)| {
+            tensor_rank_2_entry.iter().zip(mul_entry.iter()).for_each(
+                |(tensor_rank_2_entry_i, mul_entry_i)| {
+                    assert_eq!(tensor_rank_2_entry_i, &(*mul_entry_i as f32))
+                },
+            )
+        });
+}
+
 #[test]
 fn mul_tensor_rank_1_list_ref_to_self() {
     (get_tensor_rank_1_list() * &get_other_tensor_rank_1_list())
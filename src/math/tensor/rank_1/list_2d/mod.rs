@@ -4,7 +4,7 @@ mod test;
 use super::{
     super::{Tensor, TensorArray},
     list::TensorRank1List,
-    TensorRank0,
+    Scalar, TensorRank0,
 };
 use std::array::from_fn;
 use std::{
@@ -14,28 +14,41 @@ use std::{
 
 /// A 2D list of *d*-dimensional tensors of rank 1.
 ///
-/// `D` is the dimension, `I` is the configuration, `W` and `X` are the list lengths.
+/// `D` is the dimension, `I` is the configuration, `W` and `X` are the list lengths, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank1List2D<const D: usize, const I: usize, const W: usize, const X: usize>(
-    pub [TensorRank1List<D, I, W>; X],
-);
+pub struct TensorRank1List2D<
+    const D: usize,
+    const I: usize,
+    const W: usize,
+    const X: usize,
+    T = TensorRank0,
+>(pub [TensorRank1List<D, I, W, T>; X]);
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Display
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Display
+    for TensorRank1List2D<D, I, W, X, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Tensor
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Tensor<T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    type Item = TensorRank1List<D, I, W>;
+    type Item = TensorRank1List<D, I, W, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
-    fn iter(&self) -> impl Iterator<Item = &TensorRank1List<D, I, W>> {
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        (indices[0] * W + indices[1]) * D + indices[2]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]]
+    }
+    fn iter(&self) -> impl Iterator<Item = &TensorRank1List<D, I, W, T>> {
         self.0.iter()
     }
     fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
@@ -43,13 +56,13 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> TensorArray
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> TensorArray
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    type Array = [[[TensorRank0; D]; W]; X];
-    type Item = TensorRank1List<D, I, W>;
+    type Array = [[[T; D]; W]; X];
+    type Item = TensorRank1List<D, I, W, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[0.0; D]; W]; X];
+        let mut array = [[[T::zero(); D]; W]; X];
         array
             .iter_mut()
             .zip(self.iter())
@@ -70,10 +83,10 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> TensorArray
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize>
-    FromIterator<TensorRank1List<D, I, W>> for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar>
+    FromIterator<TensorRank1List<D, I, W, T>> for TensorRank1List2D<D, I, W, X, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank1List<D, I, W>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank1List<D, I, W, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_1_list_2d = Self::zero();
         tensor_rank_1_list_2d
             .iter_mut()
@@ -83,25 +96,25 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize>
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Index<usize>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Index<usize>
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    type Output = TensorRank1List<D, I, W>;
+    type Output = TensorRank1List<D, I, W, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> IndexMut<usize>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> IndexMut<usize>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Add
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Add
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_1_list_2d: Self) -> Self::Output {
@@ -110,8 +123,8 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> Add
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Add<&Self>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Add<&Self>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_1_list_2d: &Self) -> Self::Output {
@@ -120,8 +133,8 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> Add<&Self>
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> AddAssign
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> AddAssign
+    for TensorRank1List2D<D, I, W, X, T>
 {
     fn add_assign(&mut self, tensor_rank_1_list_2d: Self) {
         self.iter_mut()
@@ -130,8 +143,8 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> AddAssign
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> AddAssign<&Self>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> AddAssign<&Self>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     fn add_assign(&mut self, tensor_rank_1_list_2d: &Self) {
         self.iter_mut()
@@ -140,80 +153,80 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> AddAssign<&
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Div<TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Div<T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Div<&TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Div<&T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> DivAssign<TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> DivAssign<T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> DivAssign<&TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> DivAssign<&T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Mul<TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Mul<T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Mul<&TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Mul<&T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> MulAssign<TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> MulAssign<T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> MulAssign<&TensorRank0>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> MulAssign<&T>
+    for TensorRank1List2D<D, I, W, X, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Sub
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Sub
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_1_list_2d: Self) -> Self::Output {
@@ -222,8 +235,8 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> Sub
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> Sub<&Self>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> Sub<&Self>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_1_list_2d: &Self) -> Self::Output {
@@ -232,8 +245,8 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> Sub<&Self>
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> SubAssign
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> SubAssign
+    for TensorRank1List2D<D, I, W, X, T>
 {
     fn sub_assign(&mut self, tensor_rank_1_list_2d: Self) {
         self.iter_mut()
@@ -242,8 +255,8 @@ impl<const D: usize, const I: usize, const W: usize, const X: usize> SubAssign
     }
 }
 
-impl<const D: usize, const I: usize, const W: usize, const X: usize> SubAssign<&Self>
-    for TensorRank1List2D<D, I, W, X>
+impl<const D: usize, const I: usize, const W: usize, const X: usize, T: Scalar> SubAssign<&Self>
+    for TensorRank1List2D<D, I, W, X, T>
 {
     fn sub_assign(&mut self, tensor_rank_1_list_2d: &Self) {
         self.iter_mut()
@@ -14,36 +14,39 @@ use std::{
 };
 
 use super::{
-    super::write_tensor_rank_0, rank_0::TensorRank0, rank_2::TensorRank2, Convert, Tensor,
-    TensorArray,
+    super::write_tensor_rank_0,
+    rank_0::{Scalar, TensorRank0},
+    rank_2::TensorRank2,
+    Convert, Tensor, TensorArray,
 };
 
 /// A *d*-dimensional tensor of rank 1.
 ///
-/// `D` is the dimension, `I` is the configuration.
+/// `D` is the dimension, `I` is the configuration, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank1<const D: usize, const I: usize>(pub [TensorRank0; D]);
+#[repr(transparent)]
+pub struct TensorRank1<const D: usize, const I: usize, T = TensorRank0>(pub [T; D]);
 
-impl<const D: usize, const I: usize> Display for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Display for TensorRank1<D, I, T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         write!(f, "[")?;
         self.iter()
-            .try_for_each(|entry| write_tensor_rank_0(f, entry))?;
+            .try_for_each(|entry| write_tensor_rank_0(f, &entry.to_f64()))?;
         write!(f, "\x1B[2D]")
     }
 }
 
-impl<const D: usize, const I: usize> PartialEq for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> PartialEq for TensorRank1<D, I, T> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
-impl<const D: usize, const I: usize> TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> TensorRank1<D, I, T> {
     /// Returns the cross product with another rank-1 tensor.
     pub fn cross(&self, tensor_rank_1: &Self) -> Self {
         if D == 3 {
-            let mut output = zero();
+            let mut output = Self::zero();
             output[0] = self[1] * tensor_rank_1[2] - self[2] * tensor_rank_1[1];
             output[1] = self[2] * tensor_rank_1[0] - self[0] * tensor_rank_1[2];
             output[2] = self[0] * tensor_rank_1[1] - self[1] * tensor_rank_1[0];
@@ -93,14 +96,23 @@ impl<const D: usize, const I: usize> ErrorTensor for TensorRank1<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Tensor for TensorRank1<D, I> {
-    type Item = TensorRank0;
+impl<const D: usize, const I: usize, T: Scalar> Tensor<T> for TensorRank1<D, I, T> {
+    type Item = T;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
-    fn full_contraction(&self, tensor_rank_1: &Self) -> TensorRank0 {
+    fn full_contraction(&self, tensor_rank_1: &Self) -> T {
         self * tensor_rank_1
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        indices[0]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -109,9 +121,9 @@ impl<const D: usize, const I: usize> Tensor for TensorRank1<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> TensorArray for TensorRank1<D, I> {
-    type Array = [TensorRank0; D];
-    type Item = TensorRank0;
+impl<const D: usize, const I: usize, T: Scalar> TensorArray for TensorRank1<D, I, T> {
+    type Array = [T; D];
+    type Item = T;
     fn as_array(&self) -> Self::Array {
         self.0
     }
@@ -122,7 +134,7 @@ impl<const D: usize, const I: usize> TensorArray for TensorRank1<D, I> {
         array.into_iter().collect()
     }
     fn zero() -> Self {
-        zero()
+        Self([T::zero(); D])
     }
 }
 
@@ -131,25 +143,25 @@ pub const fn zero<const D: usize, const I: usize>() -> TensorRank1<D, I> {
     TensorRank1([0.0; D])
 }
 
-impl<const D: usize, const I: usize, const J: usize> Convert<TensorRank1<D, J>>
-    for TensorRank1<D, I>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Convert<TensorRank1<D, J, T>>
+    for TensorRank1<D, I, T>
 {
-    fn convert(&self) -> TensorRank1<D, J> {
+    fn convert(&self) -> TensorRank1<D, J, T> {
         self.iter().copied().collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> From<&TensorRank1<D, I>>
-    for TensorRank1<D, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> From<&TensorRank1<D, I, T>>
+    for TensorRank1<D, J, T>
 {
-    fn from(tensor_rank_1: &TensorRank1<D, I>) -> Self {
+    fn from(tensor_rank_1: &TensorRank1<D, I, T>) -> Self {
         TensorRank1(tensor_rank_1.0)
     }
 }
 
-impl<const D: usize, const I: usize> FromIterator<TensorRank0> for TensorRank1<D, I> {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank0>>(into_iterator: Ii) -> Self {
-        let mut tensor_rank_1 = zero();
+impl<const D: usize, const I: usize, T: Scalar> FromIterator<T> for TensorRank1<D, I, T> {
+    fn from_iter<Ii: IntoIterator<Item = T>>(into_iterator: Ii) -> Self {
+        let mut tensor_rank_1 = Self::zero();
         tensor_rank_1
             .iter_mut()
             .zip(into_iterator)
@@ -158,115 +170,115 @@ impl<const D: usize, const I: usize> FromIterator<TensorRank0> for TensorRank1<D
     }
 }
 
-impl<const D: usize, const I: usize> Index<usize> for TensorRank1<D, I> {
-    type Output = TensorRank0;
+impl<const D: usize, const I: usize, T: Scalar> Index<usize> for TensorRank1<D, I, T> {
+    type Output = T;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize> IndexMut<usize> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> IndexMut<usize> for TensorRank1<D, I, T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize> std::iter::Sum for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> std::iter::Sum for TensorRank1<D, I, T> {
     fn sum<Ii>(iter: Ii) -> Self
     where
         Ii: Iterator<Item = Self>,
     {
-        let mut output = zero();
+        let mut output = Self::zero();
         iter.for_each(|item| output += item);
         output
     }
 }
 
-impl<const D: usize, const I: usize> Div<TensorRank0> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Div<T> for TensorRank1<D, I, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> Div<TensorRank0> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
-    fn div(self, tensor_rank_0: TensorRank0) -> Self::Output {
-        self.iter().map(|self_i| self_i / tensor_rank_0).collect()
+impl<const D: usize, const I: usize, T: Scalar> Div<T> for &TensorRank1<D, I, T> {
+    type Output = TensorRank1<D, I, T>;
+    fn div(self, tensor_rank_0: T) -> Self::Output {
+        self.iter().map(|self_i| *self_i / tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize> Div<&TensorRank0> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Div<&T> for TensorRank1<D, I, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> Div<&TensorRank0> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
-    fn div(self, tensor_rank_0: &TensorRank0) -> Self::Output {
-        self.iter().map(|self_i| self_i / tensor_rank_0).collect()
+impl<const D: usize, const I: usize, T: Scalar> Div<&T> for &TensorRank1<D, I, T> {
+    type Output = TensorRank1<D, I, T>;
+    fn div(self, tensor_rank_0: &T) -> Self::Output {
+        self.iter().map(|self_i| *self_i / *tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize> DivAssign<TensorRank0> for TensorRank1<D, I> {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i /= &tensor_rank_0);
+impl<const D: usize, const I: usize, T: Scalar> DivAssign<T> for TensorRank1<D, I, T> {
+    fn div_assign(&mut self, tensor_rank_0: T) {
+        self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> DivAssign<&TensorRank0> for TensorRank1<D, I> {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
+impl<const D: usize, const I: usize, T: Scalar> DivAssign<&T> for TensorRank1<D, I, T> {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
+        self.iter_mut().for_each(|self_i| *self_i /= *tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> Mul<TensorRank0> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Mul<T> for TensorRank1<D, I, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> Mul<TensorRank0> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
-    fn mul(self, tensor_rank_0: TensorRank0) -> Self::Output {
-        self.iter().map(|self_i| self_i * tensor_rank_0).collect()
+impl<const D: usize, const I: usize, T: Scalar> Mul<T> for &TensorRank1<D, I, T> {
+    type Output = TensorRank1<D, I, T>;
+    fn mul(self, tensor_rank_0: T) -> Self::Output {
+        self.iter().map(|self_i| *self_i * tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize> Mul<&TensorRank0> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Mul<&T> for TensorRank1<D, I, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> Mul<&TensorRank0> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
-    fn mul(self, tensor_rank_0: &TensorRank0) -> Self::Output {
-        self.iter().map(|self_i| self_i * tensor_rank_0).collect()
+impl<const D: usize, const I: usize, T: Scalar> Mul<&T> for &TensorRank1<D, I, T> {
+    type Output = TensorRank1<D, I, T>;
+    fn mul(self, tensor_rank_0: &T) -> Self::Output {
+        self.iter().map(|self_i| *self_i * *tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize> MulAssign<TensorRank0> for TensorRank1<D, I> {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i *= &tensor_rank_0);
+impl<const D: usize, const I: usize, T: Scalar> MulAssign<T> for TensorRank1<D, I, T> {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
+        self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> MulAssign<&TensorRank0> for TensorRank1<D, I> {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
-        self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
+impl<const D: usize, const I: usize, T: Scalar> MulAssign<&T> for TensorRank1<D, I, T> {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
+        self.iter_mut().for_each(|self_i| *self_i *= *tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> Add for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Add for TensorRank1<D, I, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_1: Self) -> Self::Output {
         self += tensor_rank_1;
@@ -274,7 +286,7 @@ impl<const D: usize, const I: usize> Add for TensorRank1<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Add<&Self> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Add<&Self> for TensorRank1<D, I, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_1: &Self) -> Self::Output {
         self += tensor_rank_1;
@@ -282,31 +294,33 @@ impl<const D: usize, const I: usize> Add<&Self> for TensorRank1<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Add<TensorRank1<D, I>> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
-    fn add(self, mut tensor_rank_1: TensorRank1<D, I>) -> Self::Output {
+impl<const D: usize, const I: usize, T: Scalar> Add<TensorRank1<D, I, T>>
+    for &TensorRank1<D, I, T>
+{
+    type Output = TensorRank1<D, I, T>;
+    fn add(self, mut tensor_rank_1: TensorRank1<D, I, T>) -> Self::Output {
         tensor_rank_1 += self;
         tensor_rank_1
     }
 }
 
-impl<const D: usize, const I: usize> AddAssign for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> AddAssign for TensorRank1<D, I, T> {
     fn add_assign(&mut self, tensor_rank_1: Self) {
         self.iter_mut()
             .zip(tensor_rank_1.iter())
-            .for_each(|(self_i, tensor_rank_1_i)| *self_i += tensor_rank_1_i);
+            .for_each(|(self_i, tensor_rank_1_i)| *self_i += *tensor_rank_1_i);
     }
 }
 
-impl<const D: usize, const I: usize> AddAssign<&Self> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> AddAssign<&Self> for TensorRank1<D, I, T> {
     fn add_assign(&mut self, tensor_rank_1: &Self) {
         self.iter_mut()
             .zip(tensor_rank_1.iter())
-            .for_each(|(self_i, tensor_rank_1_i)| *self_i += tensor_rank_1_i);
+            .for_each(|(self_i, tensor_rank_1_i)| *self_i += *tensor_rank_1_i);
     }
 }
 
-impl<const D: usize, const I: usize> Sub for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Sub for TensorRank1<D, I, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_1: Self) -> Self::Output {
         self -= tensor_rank_1;
@@ -314,7 +328,7 @@ impl<const D: usize, const I: usize> Sub for TensorRank1<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Sub<&Self> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Sub<&Self> for TensorRank1<D, I, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_1: &Self) -> Self::Output {
         self -= tensor_rank_1;
@@ -322,80 +336,84 @@ impl<const D: usize, const I: usize> Sub<&Self> for TensorRank1<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Sub<TensorRank1<D, I>> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
-    fn sub(self, mut tensor_rank_1: TensorRank1<D, I>) -> Self::Output {
+impl<const D: usize, const I: usize, T: Scalar> Sub<TensorRank1<D, I, T>>
+    for &TensorRank1<D, I, T>
+{
+    type Output = TensorRank1<D, I, T>;
+    fn sub(self, mut tensor_rank_1: TensorRank1<D, I, T>) -> Self::Output {
         tensor_rank_1
             .iter_mut()
             .zip(self.iter())
-            .for_each(|(tensor_rank_1_i, self_i)| *tensor_rank_1_i = self_i - *tensor_rank_1_i);
+            .for_each(|(tensor_rank_1_i, self_i)| *tensor_rank_1_i = *self_i - *tensor_rank_1_i);
         tensor_rank_1
     }
 }
 
-impl<const D: usize, const I: usize> Sub<Self> for &TensorRank1<D, I> {
-    type Output = TensorRank1<D, I>;
+impl<const D: usize, const I: usize, T: Scalar> Sub<Self> for &TensorRank1<D, I, T> {
+    type Output = TensorRank1<D, I, T>;
     fn sub(self, tensor_rank_1: Self) -> Self::Output {
         tensor_rank_1
             .iter()
             .zip(self.iter())
-            .map(|(tensor_rank_1_i, self_i)| self_i - *tensor_rank_1_i)
+            .map(|(tensor_rank_1_i, self_i)| *self_i - *tensor_rank_1_i)
             .collect()
     }
 }
 
-impl<const D: usize, const I: usize> SubAssign for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> SubAssign for TensorRank1<D, I, T> {
     fn sub_assign(&mut self, tensor_rank_1: Self) {
         self.iter_mut()
             .zip(tensor_rank_1.iter())
-            .for_each(|(self_i, tensor_rank_1_i)| *self_i -= tensor_rank_1_i);
+            .for_each(|(self_i, tensor_rank_1_i)| *self_i -= *tensor_rank_1_i);
     }
 }
 
-impl<const D: usize, const I: usize> SubAssign<&Self> for TensorRank1<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> SubAssign<&Self> for TensorRank1<D, I, T> {
     fn sub_assign(&mut self, tensor_rank_1: &Self) {
         self.iter_mut()
             .zip(tensor_rank_1.iter())
-            .for_each(|(self_i, tensor_rank_1_i)| *self_i -= tensor_rank_1_i);
+            .for_each(|(self_i, tensor_rank_1_i)| *self_i -= *tensor_rank_1_i);
     }
 }
 
-impl<const D: usize, const I: usize> Mul for TensorRank1<D, I> {
-    type Output = TensorRank0;
+impl<const D: usize, const I: usize, T: Scalar> Mul for TensorRank1<D, I, T> {
+    type Output = T;
     fn mul(self, tensor_rank_1: Self) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1.iter())
-            .map(|(self_i, tensor_rank_1_i)| self_i * tensor_rank_1_i)
+            .map(|(self_i, tensor_rank_1_i)| *self_i * *tensor_rank_1_i)
             .sum()
     }
 }
 
-impl<const D: usize, const I: usize> Mul<&Self> for TensorRank1<D, I> {
-    type Output = TensorRank0;
+impl<const D: usize, const I: usize, T: Scalar> Mul<&Self> for TensorRank1<D, I, T> {
+    type Output = T;
     fn mul(self, tensor_rank_1: &Self) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1.iter())
-            .map(|(self_i, tensor_rank_1_i)| self_i * tensor_rank_1_i)
+            .map(|(self_i, tensor_rank_1_i)| *self_i * *tensor_rank_1_i)
             .sum()
     }
 }
 
-impl<const D: usize, const I: usize> Mul<TensorRank1<D, I>> for &TensorRank1<D, I> {
-    type Output = TensorRank0;
-    fn mul(self, tensor_rank_1: TensorRank1<D, I>) -> Self::Output {
+impl<const D: usize, const I: usize, T: Scalar> Mul<TensorRank1<D, I, T>>
+    for &TensorRank1<D, I, T>
+{
+    type Output = T;
+    fn mul(self, tensor_rank_1: TensorRank1<D, I, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1.iter())
-            .map(|(self_i, tensor_rank_1_i)| self_i * tensor_rank_1_i)
+            .map(|(self_i, tensor_rank_1_i)| *self_i * *tensor_rank_1_i)
             .sum()
     }
 }
 
-impl<const D: usize, const I: usize> Mul for &TensorRank1<D, I> {
-    type Output = TensorRank0;
+impl<const D: usize, const I: usize, T: Scalar> Mul for &TensorRank1<D, I, T> {
+    type Output = T;
     fn mul(self, tensor_rank_1: Self) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1.iter())
-            .map(|(self_i, tensor_rank_1_i)| self_i * tensor_rank_1_i)
+            .map(|(self_i, tensor_rank_1_i)| *self_i * *tensor_rank_1_i)
             .sum()
     }
 }
@@ -286,6 +286,14 @@ fn norm() {
     assert_eq!(get_tensor_rank_1().norm(), 5.477_225_575_051_661);
 }
 
+#[test]
+fn norm_f32() {
+    assert_eq!(
+        TensorRank1::<4, 1, f32>::new([1.0, 2.0, 3.0, 4.0]).norm(),
+        30.0_f32.sqrt()
+    );
+}
+
 #[test]
 fn normalized() {
     assert_eq!(get_tensor_rank_1().normalized().norm(), 0.9999999999999999);
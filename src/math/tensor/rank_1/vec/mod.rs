@@ -2,7 +2,8 @@
 use super::super::test::ErrorTensor;
 
 use crate::math::{
-    write_tensor_rank_0, Tensor, TensorArray, TensorRank0, TensorRank1, TensorRank2, TensorVec,
+    write_tensor_rank_0, Scalar, Tensor, TensorArray, TensorRank0, TensorRank1, TensorRank2,
+    TensorVec,
 };
 use std::{
     fmt::{Display, Formatter, Result},
@@ -11,18 +12,20 @@ use std::{
 
 /// A vector of *d*-dimensional tensors of rank 1.
 ///
-/// `D` is the dimension, `I` is the configuration.
+/// `D` is the dimension, `I` is the configuration, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank1Vec<const D: usize, const I: usize>(pub Vec<TensorRank1<D, I>>);
+pub struct TensorRank1Vec<const D: usize, const I: usize, T = TensorRank0>(
+    pub Vec<TensorRank1<D, I, T>>,
+);
 
-impl<const D: usize, const I: usize> Display for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Display for TensorRank1Vec<D, I, T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         write!(f, "\x1B[s")?;
         write!(f, "[[")?;
         self.iter().enumerate().try_for_each(|(i, tensor_rank_1)| {
             tensor_rank_1
                 .iter()
-                .try_for_each(|entry| write_tensor_rank_0(f, entry))?;
+                .try_for_each(|entry| write_tensor_rank_0(f, &entry.to_f64()))?;
             if i + 1 < self.len() {
                 writeln!(f, "\x1B[2D],")?;
                 write!(f, "\x1B[u")?;
@@ -102,8 +105,8 @@ impl<const D: usize, const I: usize> ErrorTensor for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize> From<TensorRank1Vec<D, 0>> for TensorRank1Vec<D, 1> {
-    fn from(tensor_rank_1_vec: TensorRank1Vec<D, 0>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank1Vec<D, 0, T>> for TensorRank1Vec<D, 1, T> {
+    fn from(tensor_rank_1_vec: TensorRank1Vec<D, 0, T>) -> Self {
         tensor_rank_1_vec
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -111,28 +114,30 @@ impl<const D: usize> From<TensorRank1Vec<D, 0>> for TensorRank1Vec<D, 1> {
     }
 }
 
-impl<const D: usize, const I: usize> FromIterator<TensorRank1<D, I>> for TensorRank1Vec<D, I> {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank1<D, I>>>(into_iterator: Ii) -> Self {
+impl<const D: usize, const I: usize, T: Scalar> FromIterator<TensorRank1<D, I, T>>
+    for TensorRank1Vec<D, I, T>
+{
+    fn from_iter<Ii: IntoIterator<Item = TensorRank1<D, I, T>>>(into_iterator: Ii) -> Self {
         Self(Vec::from_iter(into_iterator))
     }
 }
 
-impl<const D: usize, const I: usize> Index<usize> for TensorRank1Vec<D, I> {
-    type Output = TensorRank1<D, I>;
+impl<const D: usize, const I: usize, T: Scalar> Index<usize> for TensorRank1Vec<D, I, T> {
+    type Output = TensorRank1<D, I, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize> IndexMut<usize> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> IndexMut<usize> for TensorRank1Vec<D, I, T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize> TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> TensorRank1Vec<D, I, T> {
     /// Returns the sum of the full dot product of each tensor in each vector.
-    pub fn dot(&self, tensors: &Self) -> TensorRank0 {
+    pub fn dot(&self, tensors: &Self) -> T {
         self.iter()
             .zip(tensors.iter())
             .map(|(entry, tensor)| entry * tensor)
@@ -140,9 +145,9 @@ impl<const D: usize, const I: usize> TensorRank1Vec<D, I> {
     }
 }
 
-impl<'a, const D: usize, const I: usize> TensorVec<'a> for TensorRank1Vec<D, I> {
-    type Item = TensorRank1<D, I>;
-    type Slice = &'a [[TensorRank0; D]];
+impl<'a, const D: usize, const I: usize, T: Scalar> TensorVec<'a> for TensorRank1Vec<D, I, T> {
+    type Item = TensorRank1<D, I, T>;
+    type Slice = &'a [[T; D]];
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -156,19 +161,22 @@ impl<'a, const D: usize, const I: usize> TensorVec<'a> for TensorRank1Vec<D, I>
             .collect()
     }
     fn zero(len: usize) -> Self {
-        (0..len).map(|_| super::zero()).collect()
+        (0..len).map(|_| TensorRank1::zero()).collect()
     }
 }
 
-impl<const D: usize, const I: usize> Tensor for TensorRank1Vec<D, I> {
-    type Item = TensorRank1<D, I>;
+impl<const D: usize, const I: usize, T: Scalar> Tensor<T> for TensorRank1Vec<D, I, T> {
+    type Item = TensorRank1<D, I, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
-    fn get_at(&self, indices: &[usize]) -> &TensorRank0 {
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        D * indices[0] + indices[1]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
         &self[indices[0]][indices[1]]
     }
-    fn get_at_mut(&mut self, indices: &[usize]) -> &mut TensorRank0 {
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
         &mut self[indices[0]][indices[1]]
     }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
@@ -179,69 +187,69 @@ impl<const D: usize, const I: usize> Tensor for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Div<TensorRank0> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Div<T> for TensorRank1Vec<D, I, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> Div<&TensorRank0> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Div<&T> for TensorRank1Vec<D, I, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> DivAssign<TensorRank0> for TensorRank1Vec<D, I> {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+impl<const D: usize, const I: usize, T: Scalar> DivAssign<T> for TensorRank1Vec<D, I, T> {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> DivAssign<&TensorRank0> for TensorRank1Vec<D, I> {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+impl<const D: usize, const I: usize, T: Scalar> DivAssign<&T> for TensorRank1Vec<D, I, T> {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> Mul<TensorRank0> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Mul<T> for TensorRank1Vec<D, I, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
-impl<const D: usize, const I: usize> Mul<&TensorRank0> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Mul<&T> for TensorRank1Vec<D, I, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize> Mul<&TensorRank0> for &TensorRank1Vec<D, I> {
-    type Output = TensorRank1Vec<D, I>;
-    fn mul(self, tensor_rank_0: &TensorRank0) -> Self::Output {
+impl<const D: usize, const I: usize, T: Scalar> Mul<&T> for &TensorRank1Vec<D, I, T> {
+    type Output = TensorRank1Vec<D, I, T>;
+    fn mul(self, tensor_rank_0: &T) -> Self::Output {
         self.iter().map(|self_i| self_i * tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize> MulAssign<TensorRank0> for TensorRank1Vec<D, I> {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+impl<const D: usize, const I: usize, T: Scalar> MulAssign<T> for TensorRank1Vec<D, I, T> {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> MulAssign<&TensorRank0> for TensorRank1Vec<D, I> {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+impl<const D: usize, const I: usize, T: Scalar> MulAssign<&T> for TensorRank1Vec<D, I, T> {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize> Add for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Add for TensorRank1Vec<D, I, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_1_vec: Self) -> Self::Output {
         self += tensor_rank_1_vec;
@@ -249,7 +257,7 @@ impl<const D: usize, const I: usize> Add for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Add<&Self> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Add<&Self> for TensorRank1Vec<D, I, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_1_vec: &Self) -> Self::Output {
         self += tensor_rank_1_vec;
@@ -257,7 +265,7 @@ impl<const D: usize, const I: usize> Add<&Self> for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> AddAssign for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> AddAssign for TensorRank1Vec<D, I, T> {
     fn add_assign(&mut self, tensor_rank_1_vec: Self) {
         self.iter_mut()
             .zip(tensor_rank_1_vec.iter())
@@ -265,7 +273,7 @@ impl<const D: usize, const I: usize> AddAssign for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> AddAssign<&Self> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> AddAssign<&Self> for TensorRank1Vec<D, I, T> {
     fn add_assign(&mut self, tensor_rank_1_vec: &Self) {
         self.iter_mut()
             .zip(tensor_rank_1_vec.iter())
@@ -273,11 +281,11 @@ impl<const D: usize, const I: usize> AddAssign<&Self> for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank1Vec<D, J>>
-    for TensorRank1Vec<D, I>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<TensorRank1Vec<D, J, T>>
+    for TensorRank1Vec<D, I, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_vec: TensorRank1Vec<D, J>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_vec: TensorRank1Vec<D, J, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_vec.iter())
             .map(|(self_entry, tensor_rank_1_vec_entry)| {
@@ -287,11 +295,11 @@ impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank1Vec<D, J>>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank1Vec<D, J>>
-    for TensorRank1Vec<D, I>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&TensorRank1Vec<D, J, T>>
+    for TensorRank1Vec<D, I, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_vec: &TensorRank1Vec<D, J>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_vec: &TensorRank1Vec<D, J, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_vec.iter())
             .map(|(self_entry, tensor_rank_1_vec_entry)| {
@@ -301,11 +309,11 @@ impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank1Vec<D, J>>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank1Vec<D, J>>
-    for &TensorRank1Vec<D, I>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<TensorRank1Vec<D, J, T>>
+    for &TensorRank1Vec<D, I, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_vec: TensorRank1Vec<D, J>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_vec: TensorRank1Vec<D, J, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_vec.iter())
             .map(|(self_entry, tensor_rank_1_vec_entry)| {
@@ -315,11 +323,11 @@ impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank1Vec<D, J>>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank1Vec<D, J>>
-    for &TensorRank1Vec<D, I>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&TensorRank1Vec<D, J, T>>
+    for &TensorRank1Vec<D, I, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_1_vec: &TensorRank1Vec<D, J>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_1_vec: &TensorRank1Vec<D, J, T>) -> Self::Output {
         self.iter()
             .zip(tensor_rank_1_vec.iter())
             .map(|(self_entry, tensor_rank_1_vec_entry)| {
@@ -329,7 +337,7 @@ impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank1Vec<D, J>>
     }
 }
 
-impl<const D: usize, const I: usize> Sub for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Sub for TensorRank1Vec<D, I, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_1_vec: Self) -> Self::Output {
         self -= tensor_rank_1_vec;
@@ -337,7 +345,7 @@ impl<const D: usize, const I: usize> Sub for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> Sub<&Self> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> Sub<&Self> for TensorRank1Vec<D, I, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_1_vec: &Self) -> Self::Output {
         self -= tensor_rank_1_vec;
@@ -345,7 +353,7 @@ impl<const D: usize, const I: usize> Sub<&Self> for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> SubAssign for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> SubAssign for TensorRank1Vec<D, I, T> {
     fn sub_assign(&mut self, tensor_rank_1_vec: Self) {
         self.iter_mut()
             .zip(tensor_rank_1_vec.iter())
@@ -353,7 +361,7 @@ impl<const D: usize, const I: usize> SubAssign for TensorRank1Vec<D, I> {
     }
 }
 
-impl<const D: usize, const I: usize> SubAssign<&Self> for TensorRank1Vec<D, I> {
+impl<const D: usize, const I: usize, T: Scalar> SubAssign<&Self> for TensorRank1Vec<D, I, T> {
     fn sub_assign(&mut self, tensor_rank_1_vec: &Self) {
         self.iter_mut()
             .zip(tensor_rank_1_vec.iter())
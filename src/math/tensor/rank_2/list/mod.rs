@@ -10,18 +10,22 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use super::{Tensor, TensorArray, TensorRank0, TensorRank2};
+use super::{Scalar, Tensor, TensorArray, TensorRank0, TensorRank2};
 
 /// A list of *d*-dimensional tensors of rank 2.
 ///
-/// `D` is the dimension, `I`, `J` are the configurations `W` is the list length.
+/// `D` is the dimension, `I`, `J` are the configurations `W` is the list length, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank2List<const D: usize, const I: usize, const J: usize, const W: usize>(
-    pub [TensorRank2<D, I, J>; W],
-);
+pub struct TensorRank2List<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const W: usize,
+    T = TensorRank0,
+>(pub [TensorRank2<D, I, J, T>; W]);
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Display
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Display
+    for TensorRank2List<D, I, J, W, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
@@ -94,13 +98,22 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> ErrorTensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Tensor
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Tensor<T>
+    for TensorRank2List<D, I, J, W, T>
 {
-    type Item = TensorRank2<D, I, J>;
+    type Item = TensorRank2<D, I, J, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        (indices[0] * D + indices[1]) * D + indices[2]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -109,13 +122,13 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> TensorArray
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> TensorArray
+    for TensorRank2List<D, I, J, W, T>
 {
-    type Array = [[[TensorRank0; D]; D]; W];
-    type Item = TensorRank2<D, I, J>;
+    type Array = [[[T; D]; D]; W];
+    type Item = TensorRank2<D, I, J, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[0.0; D]; D]; W];
+        let mut array = [[[T::zero(); D]; D]; W];
         array
             .iter_mut()
             .zip(self.iter())
@@ -136,10 +149,10 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> TensorArray
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize>
-    FromIterator<TensorRank2<D, I, J>> for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar>
+    FromIterator<TensorRank2<D, I, J, T>> for TensorRank2List<D, I, J, W, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank2<D, I, J>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank2<D, I, J, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_2_list = Self::zero();
         tensor_rank_2_list
             .iter_mut()
@@ -149,25 +162,25 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Index<usize>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Index<usize>
+    for TensorRank2List<D, I, J, W, T>
 {
-    type Output = TensorRank2<D, I, J>;
+    type Output = TensorRank2<D, I, J, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> IndexMut<usize>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> IndexMut<usize>
+    for TensorRank2List<D, I, J, W, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Add
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Add
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_2_list: Self) -> Self::Output {
@@ -176,8 +189,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Add
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Add<&Self>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Add<&Self>
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_2_list: &Self) -> Self::Output {
@@ -186,8 +199,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Add<&Self>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> AddAssign
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> AddAssign
+    for TensorRank2List<D, I, J, W, T>
 {
     fn add_assign(&mut self, tensor_rank_2_list: Self) {
         self.iter_mut()
@@ -196,8 +209,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> AddAssign
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> AddAssign<&Self>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> AddAssign<&Self>
+    for TensorRank2List<D, I, J, W, T>
 {
     fn add_assign(&mut self, tensor_rank_2_list: &Self) {
         self.iter_mut()
@@ -206,80 +219,80 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> AddAssign<&
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Div<TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Div<T>
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Div<&TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Div<&T>
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> DivAssign<TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> DivAssign<T>
+    for TensorRank2List<D, I, J, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> DivAssign<&TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> DivAssign<&T>
+    for TensorRank2List<D, I, J, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Mul<T>
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Mul<&TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Mul<&T>
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> MulAssign<TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> MulAssign<T>
+    for TensorRank2List<D, I, J, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> MulAssign<&TensorRank0>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> MulAssign<&T>
+    for TensorRank2List<D, I, J, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Sub
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Sub
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_2_list: Self) -> Self::Output {
@@ -288,8 +301,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Sub
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> Sub<&Self>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> Sub<&Self>
+    for TensorRank2List<D, I, J, W, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_2_list: &Self) -> Self::Output {
@@ -298,8 +311,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> Sub<&Self>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> SubAssign
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> SubAssign
+    for TensorRank2List<D, I, J, W, T>
 {
     fn sub_assign(&mut self, tensor_rank_2_list: Self) {
         self.iter_mut()
@@ -308,8 +321,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize> SubAssign
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize> SubAssign<&Self>
-    for TensorRank2List<D, I, J, W>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, T: Scalar> SubAssign<&Self>
+    for TensorRank2List<D, I, J, W, T>
 {
     fn sub_assign(&mut self, tensor_rank_2_list: &Self) {
         self.iter_mut()
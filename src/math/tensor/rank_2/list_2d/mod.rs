@@ -13,12 +13,12 @@ use std::{
 use super::{
     super::{Tensor, TensorArray},
     list::TensorRank2List,
-    TensorRank0, TensorRank2,
+    Scalar, TensorRank0, TensorRank2,
 };
 
 /// A 2D list of *d*-dimensional tensors of rank 2.
 ///
-/// `D` is the dimension, `I`, `J` are the configurations, `W` and `X` are the list lengths.
+/// `D` is the dimension, `I`, `J` are the configurations, `W` and `X` are the list lengths, `T` is the scalar type.
 #[derive(Debug)]
 pub struct TensorRank2List2D<
     const D: usize,
@@ -26,10 +26,11 @@ pub struct TensorRank2List2D<
     const J: usize,
     const W: usize,
     const X: usize,
->([TensorRank2List<D, I, J, W>; X]);
+    T = TensorRank0,
+>([TensorRank2List<D, I, J, W, T>; X]);
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Display
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Display for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
@@ -144,13 +145,22 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Tensor
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Tensor<T> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    type Item = TensorRank2List<D, I, J, W>;
+    type Item = TensorRank2List<D, I, J, W, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        ((indices[0] * W + indices[1]) * D + indices[2]) * D + indices[3]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]][indices[3]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]][indices[3]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -159,13 +169,13 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> TensorArray
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    TensorArray for TensorRank2List2D<D, I, J, W, X, T>
 {
-    type Array = [[[[TensorRank0; D]; D]; W]; X];
-    type Item = TensorRank2List<D, I, J, W>;
+    type Array = [[[[T; D]; D]; W]; X];
+    type Item = TensorRank2List<D, I, J, W, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[[0.0; D]; D]; W]; X];
+        let mut array = [[[[T::zero(); D]; D]; W]; X];
         array
             .iter_mut()
             .zip(self.iter())
@@ -188,10 +198,12 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    FromIterator<TensorRank2List<D, I, J, W>> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    FromIterator<TensorRank2List<D, I, J, W, T>> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank2List<D, I, J, W>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank2List<D, I, J, W, T>>>(
+        into_iterator: Ii,
+    ) -> Self {
         let mut tensor_rank_2_list_2d = Self::zero();
         tensor_rank_2_list_2d
             .iter_mut()
@@ -201,25 +213,25 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Index<usize>
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Index<usize> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    type Output = TensorRank2List<D, I, J, W>;
+    type Output = TensorRank2List<D, I, J, W, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> IndexMut<usize>
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    IndexMut<usize> for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> std::iter::Sum
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    std::iter::Sum for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn sum<Ii>(iter: Ii) -> Self
     where
@@ -238,10 +250,11 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Mul<TensorRank2<D, J, K>> for TensorRank2List2D<D, I, J, W, X>
+        T: Scalar,
+    > Mul<TensorRank2<D, J, K, T>> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    type Output = TensorRank2List2D<D, I, K, W, X>;
-    fn mul(self, tensor_rank_2: TensorRank2<D, J, K>) -> Self::Output {
+    type Output = TensorRank2List2D<D, I, K, W, X, T>;
+    fn mul(self, tensor_rank_2: TensorRank2<D, J, K, T>) -> Self::Output {
         self.iter()
             .map(|self_entry| {
                 self_entry
@@ -260,10 +273,11 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Mul<&TensorRank2<D, J, K>> for TensorRank2List2D<D, I, J, W, X>
+        T: Scalar,
+    > Mul<&TensorRank2<D, J, K, T>> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    type Output = TensorRank2List2D<D, I, K, W, X>;
-    fn mul(self, tensor_rank_2: &TensorRank2<D, J, K>) -> Self::Output {
+    type Output = TensorRank2List2D<D, I, K, W, X, T>;
+    fn mul(self, tensor_rank_2: &TensorRank2<D, J, K, T>) -> Self::Output {
         self.iter()
             .map(|self_entry| {
                 self_entry
@@ -275,8 +289,8 @@ impl<
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Add
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar> Add
+    for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_2_list_2d: Self) -> Self::Output {
@@ -285,8 +299,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Add<&Self>
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Add<&Self> for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_2_list_2d: &Self) -> Self::Output {
@@ -295,8 +309,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> AddAssign
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    AddAssign for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn add_assign(&mut self, tensor_rank_2_list_2d: Self) {
         self.iter_mut()
@@ -305,8 +319,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    AddAssign<&Self> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    AddAssign<&Self> for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn add_assign(&mut self, tensor_rank_2_list_2d: &Self) {
         self.iter_mut()
@@ -315,80 +329,80 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    Div<TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Div<T> for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    Div<&TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Div<&T> for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    DivAssign<TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    DivAssign<T> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    DivAssign<&TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    DivAssign<&T> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    Mul<TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Mul<T> for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    Mul<&TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Mul<&T> for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    MulAssign<TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    MulAssign<T> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    MulAssign<&TensorRank0> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    MulAssign<&T> for TensorRank2List2D<D, I, J, W, X, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Sub
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar> Sub
+    for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_2_list_2d: Self) -> Self::Output {
@@ -397,8 +411,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> Sub<&Self>
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    Sub<&Self> for TensorRank2List2D<D, I, J, W, X, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_2_list_2d: &Self) -> Self::Output {
@@ -407,8 +421,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize> SubAssign
-    for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    SubAssign for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn sub_assign(&mut self, tensor_rank_2_list_2d: Self) {
         self.iter_mut()
@@ -417,8 +431,8 @@ impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize>
-    SubAssign<&Self> for TensorRank2List2D<D, I, J, W, X>
+impl<const D: usize, const I: usize, const J: usize, const W: usize, const X: usize, T: Scalar>
+    SubAssign<&Self> for TensorRank2List2D<D, I, J, W, X, T>
 {
     fn sub_assign(&mut self, tensor_rank_2_list_2d: &Self) {
         self.iter_mut()
@@ -6,6 +6,7 @@ use super::test::ErrorTensor;
 
 pub mod list;
 pub mod list_2d;
+pub mod symmetric;
 pub mod vec;
 pub mod vec_2d;
 
@@ -16,9 +17,11 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
+#[cfg(feature = "simd")]
+use super::simd::tensor_rank_2_mul_tensor_rank_2;
 use super::{
-    super::write_tensor_rank_0,
-    rank_0::TensorRank0,
+    super::{write_tensor_rank_0, IterativeSolver, SquareMatrix},
+    rank_0::{list::TensorRank0List, Scalar, TensorRank0},
     rank_1::{list::TensorRank1List, vec::TensorRank1Vec, TensorRank1},
     rank_4::TensorRank4,
     Convert, Hessian, Rank2, Tensor, TensorArray, TensorError,
@@ -28,17 +31,22 @@ use vec_2d::TensorRank2Vec2D;
 
 /// A *d*-dimensional tensor of rank 2.
 ///
-/// `D` is the dimension, `I`, `J` are the configurations.
+/// `D` is the dimension, `I`, `J` are the configurations, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank2<const D: usize, const I: usize, const J: usize>(pub [TensorRank1<D, J>; D]);
+#[repr(transparent)]
+pub struct TensorRank2<const D: usize, const I: usize, const J: usize, T = TensorRank0>(
+    pub [TensorRank1<D, J, T>; D],
+);
 
-impl<const D: usize, const I: usize, const J: usize> fmt::Display for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> fmt::Display
+    for TensorRank2<D, I, J, T>
+{
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "\x1B[s")?;
         write!(f, "[[")?;
         self.iter().enumerate().try_for_each(|(i, row)| {
             row.iter()
-                .try_for_each(|entry| write_tensor_rank_0(f, entry))?;
+                .try_for_each(|entry| write_tensor_rank_0(f, &entry.to_f64()))?;
             if i + 1 < D {
                 writeln!(f, "\x1B[2D],")?;
                 write!(f, "\x1B[u")?;
@@ -50,7 +58,9 @@ impl<const D: usize, const I: usize, const J: usize> fmt::Display for TensorRank
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> PartialEq for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> PartialEq
+    for TensorRank2<D, I, J, T>
+{
     fn eq(&self, other: &Self) -> bool {
         let mut result = true;
         self.iter().zip(other.iter()).for_each(|(self_i, other_i)| {
@@ -113,11 +123,11 @@ impl<const D: usize, const I: usize, const J: usize> ErrorTensor for TensorRank2
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> TensorRank2<D, I, J, T> {
     /// Returns the rank-2 tensor reshaped as a rank-1 tensor.
-    pub fn as_tensor_rank_1(&self) -> TensorRank1<9, 88> {
+    pub fn as_tensor_rank_1(&self) -> TensorRank1<9, 88, T> {
         assert_eq!(D, 3);
-        let mut tensor_rank_1 = TensorRank1::<9, 88>::zero();
+        let mut tensor_rank_1 = TensorRank1::<9, 88, T>::zero();
         self.iter().enumerate().for_each(|(i, self_i)| {
             self_i
                 .iter()
@@ -127,7 +137,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         tensor_rank_1
     }
     /// Returns the determinant of the rank-2 tensor.
-    pub fn determinant(&self) -> TensorRank0 {
+    pub fn determinant(&self) -> T {
         if D == 2 {
             self[0][0] * self[1][1] - self[0][1] * self[1][0]
         } else if D == 3 {
@@ -160,28 +170,28 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         }
     }
     /// Returns a rank-2 tensor constructed from a dyad of the given vectors.
-    pub fn dyad(vector_a: &TensorRank1<D, I>, vector_b: &TensorRank1<D, J>) -> Self {
+    pub fn dyad(vector_a: &TensorRank1<D, I, T>, vector_b: &TensorRank1<D, J, T>) -> Self {
         vector_a
             .iter()
             .map(|vector_a_i| {
                 vector_b
                     .iter()
-                    .map(|vector_b_j| vector_a_i * vector_b_j)
+                    .map(|vector_b_j| *vector_a_i * *vector_b_j)
                     .collect()
             })
             .collect()
     }
     /// Returns the inverse of the rank-2 tensor.
-    pub fn inverse(&self) -> TensorRank2<D, J, I> {
+    pub fn inverse(&self) -> TensorRank2<D, J, I, T> {
         if D == 2 {
-            let mut adjugate = TensorRank2::<D, J, I>::zero();
+            let mut adjugate = TensorRank2::<D, J, I, T>::zero();
             adjugate[0][0] = self[1][1];
             adjugate[0][1] = -self[0][1];
             adjugate[1][0] = -self[1][0];
             adjugate[1][1] = self[0][0];
             adjugate / self.determinant()
         } else if D == 3 {
-            let mut adjugate = TensorRank2::<D, J, I>::zero();
+            let mut adjugate = TensorRank2::<D, J, I, T>::zero();
             let c_00 = self[1][1] * self[2][2] - self[1][2] * self[2][1];
             let c_10 = self[1][2] * self[2][0] - self[1][0] * self[2][2];
             let c_20 = self[1][0] * self[2][1] - self[1][1] * self[2][0];
@@ -196,7 +206,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             adjugate[2][2] = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             adjugate / (self[0][0] * c_00 + self[0][1] * c_10 + self[0][2] * c_20)
         } else if D == 4 {
-            let mut adjugate = TensorRank2::<D, J, I>::zero();
+            let mut adjugate = TensorRank2::<D, J, I, T>::zero();
             let s0 = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             let s1 = self[0][0] * self[1][2] - self[0][2] * self[1][0];
             let s2 = self[0][0] * self[1][3] - self[0][3] * self[1][0];
@@ -232,9 +242,9 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         }
     }
     /// Returns the inverse and determinant of the rank-2 tensor.
-    pub fn inverse_and_determinant(&self) -> (TensorRank2<D, J, I>, TensorRank0) {
+    pub fn inverse_and_determinant(&self) -> (TensorRank2<D, J, I, T>, T) {
         if D == 2 {
-            let mut adjugate = TensorRank2::<D, J, I>::zero();
+            let mut adjugate = TensorRank2::<D, J, I, T>::zero();
             adjugate[0][0] = self[1][1];
             adjugate[0][1] = -self[0][1];
             adjugate[1][0] = -self[1][0];
@@ -242,7 +252,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             let determinant = self.determinant();
             (adjugate / determinant, determinant)
         } else if D == 3 {
-            let mut adjugate = TensorRank2::<D, J, I>::zero();
+            let mut adjugate = TensorRank2::<D, J, I, T>::zero();
             let c_00 = self[1][1] * self[2][2] - self[1][2] * self[2][1];
             let c_10 = self[1][2] * self[2][0] - self[1][0] * self[2][2];
             let c_20 = self[1][0] * self[2][1] - self[1][1] * self[2][0];
@@ -258,7 +268,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             adjugate[2][2] = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             (adjugate / determinant, determinant)
         } else if D == 4 {
-            let mut adjugate = TensorRank2::<D, J, I>::zero();
+            let mut adjugate = TensorRank2::<D, J, I, T>::zero();
             let s0 = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             let s1 = self[0][0] * self[1][2] - self[0][2] * self[1][0];
             let s2 = self[0][0] * self[1][3] - self[0][3] * self[1][0];
@@ -296,14 +306,14 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
     /// Returns the inverse transpose of the rank-2 tensor.
     pub fn inverse_transpose(&self) -> Self {
         if D == 2 {
-            let mut adjugate_transpose = TensorRank2::<D, I, J>::zero();
+            let mut adjugate_transpose = TensorRank2::<D, I, J, T>::zero();
             adjugate_transpose[0][0] = self[1][1];
             adjugate_transpose[0][1] = -self[1][0];
             adjugate_transpose[1][0] = -self[0][1];
             adjugate_transpose[1][1] = self[0][0];
             adjugate_transpose / self.determinant()
         } else if D == 3 {
-            let mut adjugate_transpose = TensorRank2::<D, I, J>::zero();
+            let mut adjugate_transpose = TensorRank2::<D, I, J, T>::zero();
             let c_00 = self[1][1] * self[2][2] - self[1][2] * self[2][1];
             let c_10 = self[1][2] * self[2][0] - self[1][0] * self[2][2];
             let c_20 = self[1][0] * self[2][1] - self[1][1] * self[2][0];
@@ -318,7 +328,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             adjugate_transpose[2][2] = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             adjugate_transpose / (self[0][0] * c_00 + self[0][1] * c_10 + self[0][2] * c_20)
         } else if D == 4 {
-            let mut adjugate_transpose = TensorRank2::<D, I, J>::zero();
+            let mut adjugate_transpose = TensorRank2::<D, I, J, T>::zero();
             let s0 = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             let s1 = self[0][0] * self[1][2] - self[0][2] * self[1][0];
             let s2 = self[0][0] * self[1][3] - self[0][3] * self[1][0];
@@ -353,9 +363,9 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         }
     }
     /// Returns the inverse transpose and determinant of the rank-2 tensor.
-    pub fn inverse_transpose_and_determinant(&self) -> (Self, TensorRank0) {
+    pub fn inverse_transpose_and_determinant(&self) -> (Self, T) {
         if D == 2 {
-            let mut adjugate_transpose = TensorRank2::<D, I, J>::zero();
+            let mut adjugate_transpose = TensorRank2::<D, I, J, T>::zero();
             adjugate_transpose[0][0] = self[1][1];
             adjugate_transpose[0][1] = -self[1][0];
             adjugate_transpose[1][0] = -self[0][1];
@@ -363,7 +373,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             let determinant = self.determinant();
             (adjugate_transpose / determinant, determinant)
         } else if D == 3 {
-            let mut adjugate_transpose = TensorRank2::<D, I, J>::zero();
+            let mut adjugate_transpose = TensorRank2::<D, I, J, T>::zero();
             let c_00 = self[1][1] * self[2][2] - self[1][2] * self[2][1];
             let c_10 = self[1][2] * self[2][0] - self[1][0] * self[2][2];
             let c_20 = self[1][0] * self[2][1] - self[1][1] * self[2][0];
@@ -379,7 +389,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             adjugate_transpose[2][2] = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             (adjugate_transpose / determinant, determinant)
         } else if D == 4 {
-            let mut adjugate_transpose = TensorRank2::<D, I, J>::zero();
+            let mut adjugate_transpose = TensorRank2::<D, I, J, T>::zero();
             let s0 = self[0][0] * self[1][1] - self[0][1] * self[1][0];
             let s1 = self[0][0] * self[1][2] - self[0][2] * self[1][0];
             let s2 = self[0][0] * self[1][3] - self[0][3] * self[1][0];
@@ -415,7 +425,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         }
     }
     /// Returns the LU decomposition of the rank-2 tensor.
-    pub fn lu_decomposition(&self) -> (TensorRank2<D, I, 88>, TensorRank2<D, 88, J>) {
+    pub fn lu_decomposition(&self) -> (TensorRank2<D, I, 88, T>, TensorRank2<D, 88, J, T>) {
         let mut tensor_l = TensorRank2::zero();
         let mut tensor_u = TensorRank2::zero();
         for i in 0..D {
@@ -423,19 +433,21 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
                 if j >= i {
                     tensor_l[j][i] = self[j][i];
                     for k in 0..i {
-                        tensor_l[j][i] -= tensor_l[j][k] * tensor_u[k][i];
+                        let product = tensor_l[j][k] * tensor_u[k][i];
+                        tensor_l[j][i] -= product;
                     }
                 }
             }
             for j in 0..D {
                 match j.cmp(&i) {
                     Ordering::Equal => {
-                        tensor_u[i][j] = 1.0;
+                        tensor_u[i][j] = T::one();
                     }
                     Ordering::Greater => {
                         tensor_u[i][j] = self[i][j] / tensor_l[i][i];
                         for k in 0..i {
-                            tensor_u[i][j] -= (tensor_l[i][k] * tensor_u[k][j]) / tensor_l[i][i];
+                            let product = tensor_l[i][k] * tensor_u[k][j];
+                            tensor_u[i][j] -= product / tensor_l[i][i];
                         }
                     }
                     Ordering::Less => (),
@@ -445,7 +457,7 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         (tensor_l, tensor_u)
     }
     /// Returns the inverse of the LU decomposition of the rank-2 tensor.
-    pub fn lu_decomposition_inverse(&self) -> (TensorRank2<D, 88, I>, TensorRank2<D, J, 88>) {
+    pub fn lu_decomposition_inverse(&self) -> (TensorRank2<D, 88, I, T>, TensorRank2<D, J, 88, T>) {
         let mut tensor_l = TensorRank2::zero();
         let mut tensor_u = TensorRank2::zero();
         for i in 0..D {
@@ -453,19 +465,21 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
                 if j >= i {
                     tensor_l[j][i] = self[j][i];
                     for k in 0..i {
-                        tensor_l[j][i] -= tensor_l[j][k] * tensor_u[k][i];
+                        let product = tensor_l[j][k] * tensor_u[k][i];
+                        tensor_l[j][i] -= product;
                     }
                 }
             }
             for j in 0..D {
                 match j.cmp(&i) {
                     Ordering::Equal => {
-                        tensor_u[i][j] = 1.0;
+                        tensor_u[i][j] = T::one();
                     }
                     Ordering::Greater => {
                         tensor_u[i][j] = self[i][j] / tensor_l[i][i];
                         for k in 0..i {
-                            tensor_u[i][j] -= (tensor_l[i][k] * tensor_u[k][j]) / tensor_l[i][i];
+                            let product = tensor_l[i][k] * tensor_u[k][j];
+                            tensor_u[i][j] -= product / tensor_l[i][i];
                         }
                     }
                     Ordering::Less => (),
@@ -474,9 +488,9 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
         }
         let mut sum;
         for i in 0..D {
-            tensor_l[i][i] = 1.0 / tensor_l[i][i];
+            tensor_l[i][i] = T::one() / tensor_l[i][i];
             for j in 0..i {
-                sum = 0.0;
+                sum = T::zero();
                 for k in j..i {
                     sum += tensor_l[i][k] * tensor_l[k][j];
                 }
@@ -484,9 +498,9 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
             }
         }
         for i in 0..D {
-            tensor_u[i][i] = 1.0 / tensor_u[i][i];
+            tensor_u[i][i] = T::one() / tensor_u[i][i];
             for j in 0..i {
-                sum = 0.0;
+                sum = T::zero();
                 for k in j..i {
                     sum += tensor_u[j][k] * tensor_u[k][i];
                 }
@@ -497,16 +511,177 @@ impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
     }
 }
 
+impl<const D: usize, const I: usize, const J: usize> TensorRank2<D, I, J> {
+    /// Returns the eigenvalues and eigenvectors of the symmetric rank-2 tensor.
+    ///
+    /// The eigenvalues are in ascending order and the eigenvectors are normalized.
+    /// Only the symmetric part of the rank-2 tensor is considered.
+    pub fn eigen_decomposition(&self) -> (TensorRank0List<D>, TensorRank1List<D, I, D>) {
+        //
+        // The cyclic Jacobi method applies rotations that each zero an off-diagonal entry,
+        // converging quadratically and remaining accurate for repeated or clustered eigenvalues.
+        //
+        let mut tensor_a: [[TensorRank0; D]; D] =
+            from_fn(|i| from_fn(|j| 0.5 * (self[i][j] + self[j][i])));
+        let mut tensor_v: [[TensorRank0; D]; D] =
+            from_fn(|i| from_fn(|j| ((i == j) as u8) as TensorRank0));
+        let scale = tensor_a
+            .iter()
+            .flat_map(|tensor_a_i| tensor_a_i.iter())
+            .map(|tensor_a_ij| tensor_a_ij.powi(2))
+            .sum::<TensorRank0>();
+        for _ in 0..50 {
+            let off_diagonal: TensorRank0 = (0..D)
+                .flat_map(|p| (p + 1..D).map(move |q| (p, q)))
+                .map(|(p, q)| tensor_a[p][q].powi(2))
+                .sum();
+            if off_diagonal <= TensorRank0::EPSILON.powi(2) * scale {
+                break;
+            }
+            for p in 0..D {
+                for q in p + 1..D {
+                    if tensor_a[p][q] == 0.0 {
+                        continue;
+                    }
+                    let theta = (tensor_a[q][q] - tensor_a[p][p]) / (2.0 * tensor_a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta.powi(2) + 1.0).sqrt());
+                    let c = 1.0 / (t.powi(2) + 1.0).sqrt();
+                    let s = t * c;
+                    for tensor_a_k in tensor_a.iter_mut() {
+                        let (a_kp, a_kq) = (tensor_a_k[p], tensor_a_k[q]);
+                        tensor_a_k[p] = c * a_kp - s * a_kq;
+                        tensor_a_k[q] = s * a_kp + c * a_kq;
+                    }
+                    let (tensor_a_p, tensor_a_q) = (tensor_a[p], tensor_a[q]);
+                    tensor_a[p] = from_fn(|k| c * tensor_a_p[k] - s * tensor_a_q[k]);
+                    tensor_a[q] = from_fn(|k| s * tensor_a_p[k] + c * tensor_a_q[k]);
+                    for tensor_v_k in tensor_v.iter_mut() {
+                        let (v_kp, v_kq) = (tensor_v_k[p], tensor_v_k[q]);
+                        tensor_v_k[p] = c * v_kp - s * v_kq;
+                        tensor_v_k[q] = s * v_kp + c * v_kq;
+                    }
+                    tensor_a[p][q] = 0.0;
+                    tensor_a[q][p] = 0.0;
+                }
+            }
+        }
+        let mut order: [usize; D] = from_fn(|k| k);
+        order.sort_by(|&a, &b| tensor_a[a][a].total_cmp(&tensor_a[b][b]));
+        (
+            TensorRank0List::new(from_fn(|k| tensor_a[order[k]][order[k]])),
+            TensorRank1List::new(from_fn(|k| from_fn(|i| tensor_v[i][order[k]]))),
+        )
+    }
+    /// Returns the exponential of the symmetric rank-2 tensor.
+    pub fn exponential(&self) -> Self {
+        self.spectral(TensorRank0::exp)
+    }
+    /// Returns the logarithm of the symmetric positive-definite rank-2 tensor.
+    pub fn logarithm(&self) -> Result<Self, TensorError> {
+        if self
+            .eigen_decomposition()
+            .0
+            .iter()
+            .all(|&eigenvalue| eigenvalue > 0.0)
+        {
+            Ok(self.spectral(TensorRank0::ln))
+        } else {
+            Err(TensorError::NotPositiveDefinite)
+        }
+    }
+    /// Returns the principal stretches of the rank-2 tensor.
+    ///
+    /// These are the square roots of the eigenvalues of the transpose of the rank-2 tensor multiplied by itself,
+    /// in ascending order.
+    pub fn principal_stretches(&self) -> TensorRank0List<D> {
+        let tensor_c = TensorRank2::<D, J, J>::new(from_fn(|i| {
+            from_fn(|j| {
+                self.iter()
+                    .map(|self_k| self_k[i] * self_k[j])
+                    .sum::<TensorRank0>()
+            })
+        }));
+        tensor_c
+            .eigen_decomposition()
+            .0
+            .iter()
+            .map(|eigenvalue| eigenvalue.max(0.0).sqrt())
+            .collect()
+    }
+    /// Returns the square root of the symmetric positive-semidefinite rank-2 tensor.
+    pub fn square_root(&self) -> Result<Self, TensorError> {
+        if self
+            .eigen_decomposition()
+            .0
+            .iter()
+            .all(|&eigenvalue| eigenvalue >= 0.0)
+        {
+            Ok(self.spectral(TensorRank0::sqrt))
+        } else {
+            Err(TensorError::NotPositiveDefinite)
+        }
+    }
+    fn spectral(&self, function: impl Fn(TensorRank0) -> TensorRank0) -> Self {
+        let (eigenvalues, eigenvectors) = self.eigen_decomposition();
+        let values: Vec<TensorRank0> = eigenvalues
+            .iter()
+            .map(|&eigenvalue| function(eigenvalue))
+            .collect();
+        Self::new(from_fn(|i| {
+            from_fn(|j| {
+                values
+                    .iter()
+                    .zip(eigenvectors.iter())
+                    .map(|(value, eigenvector)| value * eigenvector[i] * eigenvector[j])
+                    .sum()
+            })
+        }))
+    }
+}
+
 impl<const D: usize, const I: usize, const J: usize> Hessian for TensorRank2<D, I, J> {
+    fn eliminate(&mut self, indices: &[usize]) {
+        indices.iter().for_each(|&index| {
+            self.iter_mut().for_each(|self_i| self_i[index] = 0.0);
+            self[index].iter_mut().for_each(|self_ij| *self_ij = 0.0);
+            self[index][index] = 1.0;
+        })
+    }
     fn is_positive_definite(&self) -> bool {
         self.cholesky_decomposition().is_ok()
     }
+    fn solve_directly(&self, rhs: &[TensorRank0]) -> Result<Vec<TensorRank0>, TensorError> {
+        self.flattened()
+            .chunks(rhs.len())
+            .map(|row| row.iter().copied().collect())
+            .collect::<SquareMatrix>()
+            .solve_directly(rhs)
+    }
+    fn solve_directly_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> Result<Vec<Vec<TensorRank0>>, TensorError> {
+        self.flattened()
+            .chunks(D)
+            .map(|row| row.iter().copied().collect())
+            .collect::<SquareMatrix>()
+            .solve_directly_multiple(rhs)
+    }
+    fn solve_iteratively(
+        &self,
+        rhs: &[TensorRank0],
+        iterative_solver: &IterativeSolver,
+    ) -> Result<Vec<TensorRank0>, TensorError> {
+        iterative_solver.solve_dense(&self.flattened(), rhs)
+    }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I, J> {
-    type Transpose = TensorRank2<D, J, I>;
-    fn cholesky_decomposition(&self) -> Result<TensorRank2<D, I, J>, TensorError> {
-        let mut check = 0.0;
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Rank2<T>
+    for TensorRank2<D, I, J, T>
+{
+    type Transpose = TensorRank2<D, J, I, T>;
+    fn cholesky_decomposition(&self) -> Result<TensorRank2<D, I, J, T>, TensorError> {
+        let mut check = T::zero();
         let mut tensor_l = Self::zero();
         self.iter().enumerate().try_for_each(|(j, self_j)| {
             check = self_j[j]
@@ -514,8 +689,8 @@ impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I,
                     .iter()
                     .take(j)
                     .map(|tensor_l_jk| tensor_l_jk.powi(2))
-                    .sum::<TensorRank0>();
-            if check < 0.0 {
+                    .sum::<T>();
+            if check < T::zero() {
                 Err(TensorError::NotPositiveDefinite)
             } else {
                 tensor_l[j][j] = check.sqrt();
@@ -524,7 +699,7 @@ impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I,
                         .iter()
                         .zip(tensor_l[j].iter())
                         .take(j)
-                        .map(|(tensor_l_ik, tensor_l_jk)| tensor_l_ik * tensor_l_jk)
+                        .map(|(tensor_l_ik, tensor_l_jk)| *tensor_l_ik * *tensor_l_jk)
                         .sum();
                     tensor_l[i][j] = (self_i[j] - check) / tensor_l[j][j];
                 });
@@ -534,12 +709,12 @@ impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I,
         Ok(tensor_l)
     }
     fn deviatoric(&self) -> Self {
-        Self::identity() * (self.trace() / -(D as TensorRank0)) + self
+        Self::identity() * (self.trace() / -T::from_f64(D as TensorRank0)) + self
     }
-    fn deviatoric_and_trace(&self) -> (Self, TensorRank0) {
+    fn deviatoric_and_trace(&self) -> (Self, T) {
         let trace = self.trace();
         (
-            Self::identity() * (trace / -(D as TensorRank0)) + self,
+            Self::identity() * (trace / -T::from_f64(D as TensorRank0)) + self,
             trace,
         )
     }
@@ -550,7 +725,7 @@ impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I,
                 self_i
                     .iter()
                     .enumerate()
-                    .map(|(j, self_ij)| (self_ij == &0.0) as u8 * (i != j) as u8)
+                    .map(|(j, self_ij)| (self_ij == &T::zero()) as u8 * (i != j) as u8)
                     .sum::<u8>()
             })
             .sum::<u8>()
@@ -563,25 +738,27 @@ impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I,
                 self_i
                     .iter()
                     .enumerate()
-                    .map(|(j, self_ij)| (self_ij == &((i == j) as u8 as f64)) as u8)
+                    .map(|(j, self_ij)| {
+                        (self_ij == &T::from_f64((i == j) as u8 as TensorRank0)) as u8
+                    })
                     .sum::<u8>()
             })
             .sum::<u8>()
             == D.pow(2) as u8
     }
-    fn squared_trace(&self) -> TensorRank0 {
+    fn squared_trace(&self) -> T {
         self.iter()
             .enumerate()
             .map(|(i, self_i)| {
                 self_i
                     .iter()
                     .zip(self.iter())
-                    .map(|(self_ij, self_j)| self_ij * self_j[i])
-                    .sum::<TensorRank0>()
+                    .map(|(self_ij, self_j)| *self_ij * self_j[i])
+                    .sum::<T>()
             })
             .sum()
     }
-    fn trace(&self) -> TensorRank0 {
+    fn trace(&self) -> T {
         self.iter().enumerate().map(|(i, self_i)| self_i[i]).sum()
     }
     fn transpose(&self) -> Self::Transpose {
@@ -591,11 +768,22 @@ impl<const D: usize, const I: usize, const J: usize> Rank2 for TensorRank2<D, I,
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Tensor for TensorRank2<D, I, J> {
-    type Item = TensorRank1<D, J>;
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Tensor<T>
+    for TensorRank2<D, I, J, T>
+{
+    type Item = TensorRank1<D, J, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        D * indices[0] + indices[1]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -604,11 +792,13 @@ impl<const D: usize, const I: usize, const J: usize> Tensor for TensorRank2<D, I
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> TensorArray for TensorRank2<D, I, J> {
-    type Array = [[TensorRank0; D]; D];
-    type Item = TensorRank1<D, J>;
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> TensorArray
+    for TensorRank2<D, I, J, T>
+{
+    type Array = [[T; D]; D];
+    type Item = TensorRank1<D, J, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[0.0; D]; D];
+        let mut array = [[T::zero(); D]; D];
         array
             .iter_mut()
             .zip(self.iter())
@@ -617,7 +807,11 @@ impl<const D: usize, const I: usize, const J: usize> TensorArray for TensorRank2
     }
     fn identity() -> Self {
         (0..D)
-            .map(|i| (0..D).map(|j| ((i == j) as u8) as TensorRank0).collect())
+            .map(|i| {
+                (0..D)
+                    .map(|j| T::from_f64((i == j) as u8 as TensorRank0))
+                    .collect()
+            })
             .collect()
     }
     fn new(array: Self::Array) -> Self {
@@ -631,18 +825,18 @@ impl<const D: usize, const I: usize, const J: usize> TensorArray for TensorRank2
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Convert<TensorRank2<D, K, L>> for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Convert<TensorRank2<D, K, L, T>> for TensorRank2<D, I, J, T>
 {
-    fn convert(&self) -> TensorRank2<D, K, L> {
+    fn convert(&self) -> TensorRank2<D, K, L, T> {
         self.iter()
             .map(|self_i| self_i.iter().copied().collect())
             .collect()
     }
 }
 
-impl<const D: usize> From<TensorRank2<D, 0, 0>> for TensorRank2<D, 1, 0> {
-    fn from(tensor_rank_2: TensorRank2<D, 0, 0>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank2<D, 0, 0, T>> for TensorRank2<D, 1, 0, T> {
+    fn from(tensor_rank_2: TensorRank2<D, 0, 0, T>) -> Self {
         tensor_rank_2
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -650,8 +844,8 @@ impl<const D: usize> From<TensorRank2<D, 0, 0>> for TensorRank2<D, 1, 0> {
     }
 }
 
-impl<const D: usize> From<TensorRank2<D, 0, 0>> for TensorRank2<D, 1, 1> {
-    fn from(tensor_rank_2: TensorRank2<D, 0, 0>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank2<D, 0, 0, T>> for TensorRank2<D, 1, 1, T> {
+    fn from(tensor_rank_2: TensorRank2<D, 0, 0, T>) -> Self {
         tensor_rank_2
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -659,8 +853,8 @@ impl<const D: usize> From<TensorRank2<D, 0, 0>> for TensorRank2<D, 1, 1> {
     }
 }
 
-impl<const D: usize> From<TensorRank2<D, 0, 1>> for TensorRank2<D, 0, 0> {
-    fn from(tensor_rank_2: TensorRank2<D, 0, 1>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank2<D, 0, 1, T>> for TensorRank2<D, 0, 0, T> {
+    fn from(tensor_rank_2: TensorRank2<D, 0, 1, T>) -> Self {
         tensor_rank_2
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -668,8 +862,8 @@ impl<const D: usize> From<TensorRank2<D, 0, 1>> for TensorRank2<D, 0, 0> {
     }
 }
 
-impl<const D: usize> From<TensorRank2<D, 1, 0>> for TensorRank2<D, 0, 0> {
-    fn from(tensor_rank_2: TensorRank2<D, 1, 0>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank2<D, 1, 0, T>> for TensorRank2<D, 0, 0, T> {
+    fn from(tensor_rank_2: TensorRank2<D, 1, 0, T>) -> Self {
         tensor_rank_2
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -677,8 +871,8 @@ impl<const D: usize> From<TensorRank2<D, 1, 0>> for TensorRank2<D, 0, 0> {
     }
 }
 
-impl<const D: usize> From<TensorRank2<D, 1, 1>> for TensorRank2<D, 1, 0> {
-    fn from(tensor_rank_2: TensorRank2<D, 1, 1>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank2<D, 1, 1, T>> for TensorRank2<D, 1, 0, T> {
+    fn from(tensor_rank_2: TensorRank2<D, 1, 1, T>) -> Self {
         tensor_rank_2
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -686,8 +880,8 @@ impl<const D: usize> From<TensorRank2<D, 1, 1>> for TensorRank2<D, 1, 0> {
     }
 }
 
-impl<const D: usize> From<TensorRank2<D, 1, 2>> for TensorRank2<D, 1, 0> {
-    fn from(tensor_rank_2: TensorRank2<D, 1, 2>) -> Self {
+impl<const D: usize, T: Scalar> From<TensorRank2<D, 1, 2, T>> for TensorRank2<D, 1, 0, T> {
+    fn from(tensor_rank_2: TensorRank2<D, 1, 2, T>) -> Self {
         tensor_rank_2
             .iter()
             .map(|tensor_rank_1| tensor_rank_1.into())
@@ -695,10 +889,10 @@ impl<const D: usize> From<TensorRank2<D, 1, 2>> for TensorRank2<D, 1, 0> {
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> FromIterator<TensorRank1<D, J>>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> FromIterator<TensorRank1<D, J, T>>
+    for TensorRank2<D, I, J, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank1<D, J>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank1<D, J, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_2 = Self::zero();
         tensor_rank_2
             .iter_mut()
@@ -708,20 +902,26 @@ impl<const D: usize, const I: usize, const J: usize> FromIterator<TensorRank1<D,
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Index<usize> for TensorRank2<D, I, J> {
-    type Output = TensorRank1<D, J>;
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Index<usize>
+    for TensorRank2<D, I, J, T>
+{
+    type Output = TensorRank1<D, J, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> IndexMut<usize> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> IndexMut<usize>
+    for TensorRank2<D, I, J, T>
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> std::iter::Sum for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> std::iter::Sum
+    for TensorRank2<D, I, J, T>
+{
     fn sum<Ii>(iter: Ii) -> Self
     where
         Ii: Iterator<Item = Self>,
@@ -732,135 +932,147 @@ impl<const D: usize, const I: usize, const J: usize> std::iter::Sum for TensorRa
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Div<TensorRank0> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Div<T> for TensorRank2<D, I, J, T> {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Div<TensorRank0> for &TensorRank2<D, I, J> {
-    type Output = TensorRank2<D, I, J>;
-    fn div(self, tensor_rank_0: TensorRank0) -> Self::Output {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Div<T>
+    for &TensorRank2<D, I, J, T>
+{
+    type Output = TensorRank2<D, I, J, T>;
+    fn div(self, tensor_rank_0: T) -> Self::Output {
         self.iter().map(|self_i| self_i / tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Div<&TensorRank0> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Div<&T>
+    for TensorRank2<D, I, J, T>
+{
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Div<&TensorRank0> for &TensorRank2<D, I, J> {
-    type Output = TensorRank2<D, I, J>;
-    fn div(self, tensor_rank_0: &TensorRank0) -> Self::Output {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Div<&T>
+    for &TensorRank2<D, I, J, T>
+{
+    type Output = TensorRank2<D, I, J, T>;
+    fn div(self, tensor_rank_0: &T) -> Self::Output {
         self.iter().map(|self_i| self_i / tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> DivAssign<TensorRank0>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> DivAssign<T>
+    for TensorRank2<D, I, J, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|self_i| *self_i /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> DivAssign<&TensorRank0>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> DivAssign<&T>
+    for TensorRank2<D, I, J, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank0> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<T> for TensorRank2<D, I, J, T> {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank0> for &TensorRank2<D, I, J> {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_0: TensorRank0) -> Self::Output {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<T>
+    for &TensorRank2<D, I, J, T>
+{
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_0: T) -> Self::Output {
         self.iter().map(|self_i| self_i * tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank0> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&T>
+    for TensorRank2<D, I, J, T>
+{
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank0> for &TensorRank2<D, I, J> {
-    type Output = TensorRank2<D, I, J>;
-    fn mul(self, tensor_rank_0: &TensorRank0) -> Self::Output {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&T>
+    for &TensorRank2<D, I, J, T>
+{
+    type Output = TensorRank2<D, I, J, T>;
+    fn mul(self, tensor_rank_0: &T) -> Self::Output {
         self.iter().map(|self_i| self_i * tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> MulAssign<TensorRank0>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> MulAssign<T>
+    for TensorRank2<D, I, J, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|self_i| *self_i *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> MulAssign<&TensorRank0>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> MulAssign<&T>
+    for TensorRank2<D, I, J, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank1<D, J>>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<TensorRank1<D, J, T>>
+    for TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank1<D, I>;
-    fn mul(self, tensor_rank_1: TensorRank1<D, J>) -> Self::Output {
+    type Output = TensorRank1<D, I, T>;
+    fn mul(self, tensor_rank_1: TensorRank1<D, J, T>) -> Self::Output {
         self.iter().map(|self_i| self_i * &tensor_rank_1).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank1<D, J>>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&TensorRank1<D, J, T>>
+    for TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank1<D, I>;
-    fn mul(self, tensor_rank_1: &TensorRank1<D, J>) -> Self::Output {
+    type Output = TensorRank1<D, I, T>;
+    fn mul(self, tensor_rank_1: &TensorRank1<D, J, T>) -> Self::Output {
         self.iter().map(|self_i| self_i * tensor_rank_1).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank1<D, J>>
-    for &TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<TensorRank1<D, J, T>>
+    for &TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank1<D, I>;
-    fn mul(self, tensor_rank_1: TensorRank1<D, J>) -> Self::Output {
+    type Output = TensorRank1<D, I, T>;
+    fn mul(self, tensor_rank_1: TensorRank1<D, J, T>) -> Self::Output {
         self.iter().map(|self_i| self_i * &tensor_rank_1).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank1<D, J>>
-    for &TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&TensorRank1<D, J, T>>
+    for &TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank1<D, I>;
-    fn mul(self, tensor_rank_1: &TensorRank1<D, J>) -> Self::Output {
+    type Output = TensorRank1<D, I, T>;
+    fn mul(self, tensor_rank_1: &TensorRank1<D, J, T>) -> Self::Output {
         self.iter().map(|self_i| self_i * tensor_rank_1).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Add for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Add for TensorRank2<D, I, J, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_2: Self) -> Self::Output {
         self += tensor_rank_2;
@@ -868,7 +1080,9 @@ impl<const D: usize, const I: usize, const J: usize> Add for TensorRank2<D, I, J
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Add<&Self> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Add<&Self>
+    for TensorRank2<D, I, J, T>
+{
     type Output = Self;
     fn add(mut self, tensor_rank_2: &Self) -> Self::Output {
         self += tensor_rank_2;
@@ -876,17 +1090,19 @@ impl<const D: usize, const I: usize, const J: usize> Add<&Self> for TensorRank2<
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Add<TensorRank2<D, I, J>>
-    for &TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Add<TensorRank2<D, I, J, T>>
+    for &TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank2<D, I, J>;
-    fn add(self, mut tensor_rank_2: TensorRank2<D, I, J>) -> Self::Output {
+    type Output = TensorRank2<D, I, J, T>;
+    fn add(self, mut tensor_rank_2: TensorRank2<D, I, J, T>) -> Self::Output {
         tensor_rank_2 += self;
         tensor_rank_2
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> AddAssign for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> AddAssign
+    for TensorRank2<D, I, J, T>
+{
     fn add_assign(&mut self, tensor_rank_2: Self) {
         self.iter_mut()
             .zip(tensor_rank_2.iter())
@@ -894,7 +1110,9 @@ impl<const D: usize, const I: usize, const J: usize> AddAssign for TensorRank2<D
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> AddAssign<&Self> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> AddAssign<&Self>
+    for TensorRank2<D, I, J, T>
+{
     fn add_assign(&mut self, tensor_rank_2: &Self) {
         self.iter_mut()
             .zip(tensor_rank_2.iter())
@@ -902,11 +1120,15 @@ impl<const D: usize, const I: usize, const J: usize> AddAssign<&Self> for Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<TensorRank2<D, J, K>>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar>
+    Mul<TensorRank2<D, J, K, T>> for TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank2<D, I, K>;
-    fn mul(self, tensor_rank_2: TensorRank2<D, J, K>) -> Self::Output {
+    type Output = TensorRank2<D, I, K, T>;
+    fn mul(self, tensor_rank_2: TensorRank2<D, J, K, T>) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(output) = tensor_rank_2_mul_tensor_rank_2(&self, &tensor_rank_2) {
+            return output;
+        }
         self.iter()
             .map(|self_i| {
                 self_i
@@ -919,11 +1141,15 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<TensorR
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<&TensorRank2<D, J, K>>
-    for TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar>
+    Mul<&TensorRank2<D, J, K, T>> for TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank2<D, I, K>;
-    fn mul(self, tensor_rank_2: &TensorRank2<D, J, K>) -> Self::Output {
+    type Output = TensorRank2<D, I, K, T>;
+    fn mul(self, tensor_rank_2: &TensorRank2<D, J, K, T>) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(output) = tensor_rank_2_mul_tensor_rank_2(&self, tensor_rank_2) {
+            return output;
+        }
         self.iter()
             .map(|self_i| {
                 self_i
@@ -936,11 +1162,15 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<&Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<TensorRank2<D, J, K>>
-    for &TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar>
+    Mul<TensorRank2<D, J, K, T>> for &TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank2<D, I, K>;
-    fn mul(self, tensor_rank_2: TensorRank2<D, J, K>) -> Self::Output {
+    type Output = TensorRank2<D, I, K, T>;
+    fn mul(self, tensor_rank_2: TensorRank2<D, J, K, T>) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(output) = tensor_rank_2_mul_tensor_rank_2(self, &tensor_rank_2) {
+            return output;
+        }
         self.iter()
             .map(|self_i| {
                 self_i
@@ -953,11 +1183,15 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<TensorR
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<&TensorRank2<D, J, K>>
-    for &TensorRank2<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar>
+    Mul<&TensorRank2<D, J, K, T>> for &TensorRank2<D, I, J, T>
 {
-    type Output = TensorRank2<D, I, K>;
-    fn mul(self, tensor_rank_2: &TensorRank2<D, J, K>) -> Self::Output {
+    type Output = TensorRank2<D, I, K, T>;
+    fn mul(self, tensor_rank_2: &TensorRank2<D, J, K, T>) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(output) = tensor_rank_2_mul_tensor_rank_2(self, tensor_rank_2) {
+            return output;
+        }
         self.iter()
             .map(|self_i| {
                 self_i
@@ -970,7 +1204,7 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<&Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Sub for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Sub for TensorRank2<D, I, J, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_2: Self) -> Self::Output {
         self -= tensor_rank_2;
@@ -978,7 +1212,9 @@ impl<const D: usize, const I: usize, const J: usize> Sub for TensorRank2<D, I, J
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Sub<&Self> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Sub<&Self>
+    for TensorRank2<D, I, J, T>
+{
     type Output = Self;
     fn sub(mut self, tensor_rank_2: &Self) -> Self::Output {
         self -= tensor_rank_2;
@@ -986,7 +1222,9 @@ impl<const D: usize, const I: usize, const J: usize> Sub<&Self> for TensorRank2<
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> SubAssign for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> SubAssign
+    for TensorRank2<D, I, J, T>
+{
     fn sub_assign(&mut self, tensor_rank_2: Self) {
         self.iter_mut()
             .zip(tensor_rank_2.iter())
@@ -994,7 +1232,9 @@ impl<const D: usize, const I: usize, const J: usize> SubAssign for TensorRank2<D
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> SubAssign<&Self> for TensorRank2<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> SubAssign<&Self>
+    for TensorRank2<D, I, J, T>
+{
     fn sub_assign(&mut self, tensor_rank_2: &Self) {
         self.iter_mut()
             .zip(tensor_rank_2.iter())
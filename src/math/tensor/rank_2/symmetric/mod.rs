@@ -0,0 +1,317 @@
+#[cfg(test)]
+mod test;
+
+#[cfg(test)]
+use super::super::test::ErrorTensor;
+
+use std::{
+    f64::consts::SQRT_2,
+    fmt::{Display, Formatter, Result},
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::{super::super::write_tensor_rank_0, Tensor, TensorArray, TensorRank0, TensorRank2};
+
+/// The pairs of indices corresponding to each Mandel index.
+pub const MANDEL_INDICES: [[usize; 2]; 6] = [[0, 0], [1, 1], [2, 2], [1, 2], [0, 2], [0, 1]];
+
+/// Returns the Mandel weight of the entry at the given Mandel index.
+pub fn mandel_weight(index: usize) -> TensorRank0 {
+    if index < 3 {
+        1.0
+    } else {
+        SQRT_2
+    }
+}
+
+/// A symmetric 3-dimensional tensor of rank 2 stored in Mandel notation.
+///
+/// `I` is the configuration.
+/// The off-diagonal entries are scaled by √2 so that full contractions are preserved.
+#[derive(Debug)]
+pub struct TensorRank2Symmetric<const I: usize>(pub [TensorRank0; 6]);
+
+impl<const I: usize> Display for TensorRank2Symmetric<I> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "[")?;
+        self.iter()
+            .try_for_each(|entry| write_tensor_rank_0(f, entry))?;
+        write!(f, "\x1B[2D]")
+    }
+}
+
+impl<const I: usize> PartialEq for TensorRank2Symmetric<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(test)]
+impl<const I: usize> ErrorTensor for TensorRank2Symmetric<I> {
+    fn error(
+        &self,
+        comparator: &Self,
+        tol_abs: &TensorRank0,
+        tol_rel: &TensorRank0,
+    ) -> Option<usize> {
+        let error_count = self
+            .iter()
+            .zip(comparator.iter())
+            .filter(|(&self_a, &comparator_a)| {
+                &(self_a - comparator_a).abs() >= tol_abs
+                    && &(self_a / comparator_a - 1.0).abs() >= tol_rel
+            })
+            .count();
+        if error_count > 0 {
+            Some(error_count)
+        } else {
+            None
+        }
+    }
+    fn error_fd(&self, comparator: &Self, epsilon: &TensorRank0) -> Option<(bool, usize)> {
+        let error_count = self
+            .iter()
+            .zip(comparator.iter())
+            .filter(|(&self_a, &comparator_a)| {
+                &(self_a / comparator_a - 1.0).abs() >= epsilon
+                    && (&self_a.abs() >= epsilon || &comparator_a.abs() >= epsilon)
+            })
+            .count();
+        if error_count > 0 {
+            Some((true, error_count))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const I: usize> TensorRank2Symmetric<I> {
+    /// Returns the entries in Voigt notation, without any scaling of the off-diagonal entries.
+    pub fn as_voigt(&self) -> [TensorRank0; 6] {
+        let mut voigt = self.0;
+        voigt
+            .iter_mut()
+            .enumerate()
+            .for_each(|(a, voigt_a)| *voigt_a /= mandel_weight(a));
+        voigt
+    }
+    /// Returns the symmetric rank-2 tensor given entries in Voigt notation, without any scaling of the off-diagonal entries.
+    pub fn from_voigt(mut voigt: [TensorRank0; 6]) -> Self {
+        voigt
+            .iter_mut()
+            .enumerate()
+            .for_each(|(a, voigt_a)| *voigt_a *= mandel_weight(a));
+        Self(voigt)
+    }
+    /// Returns the trace of the symmetric rank-2 tensor.
+    pub fn trace(&self) -> TensorRank0 {
+        self.iter().take(3).sum()
+    }
+}
+
+impl<const I: usize> Tensor for TensorRank2Symmetric<I> {
+    type Item = TensorRank0;
+    fn copy(&self) -> Self {
+        Self(self.0)
+    }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        indices[0]
+    }
+    fn get_at(&self, indices: &[usize]) -> &TensorRank0 {
+        &self[indices[0]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut TensorRank0 {
+        &mut self[indices[0]]
+    }
+    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
+        self.0.iter()
+    }
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
+        self.0.iter_mut()
+    }
+}
+
+impl<const I: usize> TensorArray for TensorRank2Symmetric<I> {
+    type Array = [TensorRank0; 6];
+    type Item = TensorRank0;
+    fn as_array(&self) -> Self::Array {
+        self.0
+    }
+    fn identity() -> Self {
+        Self([1.0, 1.0, 1.0, 0.0, 0.0, 0.0])
+    }
+    fn new(array: Self::Array) -> Self {
+        Self(array)
+    }
+    fn zero() -> Self {
+        Self([0.0; 6])
+    }
+}
+
+impl<const I: usize> From<&TensorRank2<3, I, I>> for TensorRank2Symmetric<I> {
+    fn from(tensor_rank_2: &TensorRank2<3, I, I>) -> Self {
+        MANDEL_INDICES
+            .iter()
+            .enumerate()
+            .map(|(a, [i, j])| {
+                0.5 * mandel_weight(a) * (tensor_rank_2[*i][*j] + tensor_rank_2[*j][*i])
+            })
+            .collect()
+    }
+}
+
+impl<const I: usize> From<&TensorRank2Symmetric<I>> for TensorRank2<3, I, I> {
+    fn from(tensor_rank_2_symmetric: &TensorRank2Symmetric<I>) -> Self {
+        let mut tensor_rank_2 = Self::zero();
+        MANDEL_INDICES
+            .iter()
+            .zip(tensor_rank_2_symmetric.iter())
+            .enumerate()
+            .for_each(|(a, ([i, j], entry))| {
+                tensor_rank_2[*i][*j] = entry / mandel_weight(a);
+                tensor_rank_2[*j][*i] = tensor_rank_2[*i][*j];
+            });
+        tensor_rank_2
+    }
+}
+
+impl<const I: usize> FromIterator<TensorRank0> for TensorRank2Symmetric<I> {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank0>>(into_iterator: Ii) -> Self {
+        let mut tensor_rank_2_symmetric = Self::zero();
+        tensor_rank_2_symmetric
+            .iter_mut()
+            .zip(into_iterator)
+            .for_each(|(tensor_rank_2_symmetric_a, value_a)| *tensor_rank_2_symmetric_a = value_a);
+        tensor_rank_2_symmetric
+    }
+}
+
+impl<const I: usize> Index<usize> for TensorRank2Symmetric<I> {
+    type Output = TensorRank0;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const I: usize> IndexMut<usize> for TensorRank2Symmetric<I> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const I: usize> std::iter::Sum for TensorRank2Symmetric<I> {
+    fn sum<Ii>(iter: Ii) -> Self
+    where
+        Ii: Iterator<Item = Self>,
+    {
+        let mut output = Self::zero();
+        iter.for_each(|item| output += item);
+        output
+    }
+}
+
+impl<const I: usize> Div<TensorRank0> for TensorRank2Symmetric<I> {
+    type Output = Self;
+    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+        self /= tensor_rank_0;
+        self
+    }
+}
+
+impl<const I: usize> Div<TensorRank0> for &TensorRank2Symmetric<I> {
+    type Output = TensorRank2Symmetric<I>;
+    fn div(self, tensor_rank_0: TensorRank0) -> Self::Output {
+        self.iter().map(|self_a| self_a / tensor_rank_0).collect()
+    }
+}
+
+impl<const I: usize> DivAssign<TensorRank0> for TensorRank2Symmetric<I> {
+    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+        self.iter_mut().for_each(|self_a| *self_a /= tensor_rank_0);
+    }
+}
+
+impl<const I: usize> Mul<TensorRank0> for TensorRank2Symmetric<I> {
+    type Output = Self;
+    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+        self *= tensor_rank_0;
+        self
+    }
+}
+
+impl<const I: usize> Mul<TensorRank0> for &TensorRank2Symmetric<I> {
+    type Output = TensorRank2Symmetric<I>;
+    fn mul(self, tensor_rank_0: TensorRank0) -> Self::Output {
+        self.iter().map(|self_a| self_a * tensor_rank_0).collect()
+    }
+}
+
+impl<const I: usize> MulAssign<TensorRank0> for TensorRank2Symmetric<I> {
+    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+        self.iter_mut().for_each(|self_a| *self_a *= tensor_rank_0);
+    }
+}
+
+impl<const I: usize> Add for TensorRank2Symmetric<I> {
+    type Output = Self;
+    fn add(mut self, tensor_rank_2_symmetric: Self) -> Self::Output {
+        self += tensor_rank_2_symmetric;
+        self
+    }
+}
+
+impl<const I: usize> Add<&Self> for TensorRank2Symmetric<I> {
+    type Output = Self;
+    fn add(mut self, tensor_rank_2_symmetric: &Self) -> Self::Output {
+        self += tensor_rank_2_symmetric;
+        self
+    }
+}
+
+impl<const I: usize> AddAssign for TensorRank2Symmetric<I> {
+    fn add_assign(&mut self, tensor_rank_2_symmetric: Self) {
+        self.iter_mut()
+            .zip(tensor_rank_2_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_2_symmetric_a)| *self_a += tensor_rank_2_symmetric_a);
+    }
+}
+
+impl<const I: usize> AddAssign<&Self> for TensorRank2Symmetric<I> {
+    fn add_assign(&mut self, tensor_rank_2_symmetric: &Self) {
+        self.iter_mut()
+            .zip(tensor_rank_2_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_2_symmetric_a)| *self_a += tensor_rank_2_symmetric_a);
+    }
+}
+
+impl<const I: usize> Sub for TensorRank2Symmetric<I> {
+    type Output = Self;
+    fn sub(mut self, tensor_rank_2_symmetric: Self) -> Self::Output {
+        self -= tensor_rank_2_symmetric;
+        self
+    }
+}
+
+impl<const I: usize> Sub<&Self> for TensorRank2Symmetric<I> {
+    type Output = Self;
+    fn sub(mut self, tensor_rank_2_symmetric: &Self) -> Self::Output {
+        self -= tensor_rank_2_symmetric;
+        self
+    }
+}
+
+impl<const I: usize> SubAssign for TensorRank2Symmetric<I> {
+    fn sub_assign(&mut self, tensor_rank_2_symmetric: Self) {
+        self.iter_mut()
+            .zip(tensor_rank_2_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_2_symmetric_a)| *self_a -= tensor_rank_2_symmetric_a);
+    }
+}
+
+impl<const I: usize> SubAssign<&Self> for TensorRank2Symmetric<I> {
+    fn sub_assign(&mut self, tensor_rank_2_symmetric: &Self) {
+        self.iter_mut()
+            .zip(tensor_rank_2_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_2_symmetric_a)| *self_a -= tensor_rank_2_symmetric_a);
+    }
+}
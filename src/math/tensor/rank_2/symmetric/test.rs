@@ -0,0 +1,95 @@
+use super::{
+    super::{
+        super::test::{assert_eq, assert_eq_within_tols, TestError},
+        Rank2,
+    },
+    Tensor, TensorArray, TensorRank0, TensorRank2, TensorRank2Symmetric,
+};
+
+fn get_tensor_rank_2() -> TensorRank2<3, 1, 1> {
+    TensorRank2::new([[1.0, 4.0, 6.0], [4.0, 2.0, 5.0], [6.0, 5.0, 3.0]])
+}
+
+fn get_other_tensor_rank_2() -> TensorRank2<3, 1, 1> {
+    TensorRank2::new([[3.0, 2.0, 1.0], [2.0, 5.0, 7.0], [1.0, 7.0, 4.0]])
+}
+
+#[test]
+fn as_voigt() {
+    assert_eq!(
+        TensorRank2Symmetric::from(&get_tensor_rank_2()).as_voigt(),
+        [1.0, 2.0, 3.0, 5.0, 6.0, 4.0]
+    )
+}
+
+#[test]
+fn from_tensor_rank_2() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    assert_eq_within_tols(
+        &TensorRank2::from(&TensorRank2Symmetric::from(&tensor_rank_2)),
+        &tensor_rank_2,
+    )
+}
+
+#[test]
+fn from_tensor_rank_2_unsymmetric() -> Result<(), TestError> {
+    let tensor_rank_2 =
+        TensorRank2::<3, 1, 1>::new([[1.0, 2.0, 3.0], [6.0, 5.0, 8.0], [9.0, 2.0, 4.0]]);
+    assert_eq_within_tols(
+        &TensorRank2::from(&TensorRank2Symmetric::from(&tensor_rank_2)),
+        &((tensor_rank_2.transpose() + &tensor_rank_2) * 0.5),
+    )
+}
+
+#[test]
+fn from_voigt() -> Result<(), TestError> {
+    let tensor_rank_2_symmetric = TensorRank2Symmetric::<1>::from(&get_tensor_rank_2());
+    assert_eq_within_tols(
+        &TensorRank2Symmetric::from_voigt(tensor_rank_2_symmetric.as_voigt()),
+        &tensor_rank_2_symmetric,
+    )
+}
+
+#[test]
+fn full_contraction() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &TensorRank2Symmetric::from(&get_tensor_rank_2())
+            .full_contraction(&TensorRank2Symmetric::from(&get_other_tensor_rank_2())),
+        &get_tensor_rank_2().full_contraction(&get_other_tensor_rank_2()),
+    )
+}
+
+#[test]
+fn identity() -> Result<(), TestError> {
+    assert_eq(
+        &TensorRank2::from(&TensorRank2Symmetric::<1>::identity()),
+        &TensorRank2::identity(),
+    )
+}
+
+#[test]
+fn norm() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &TensorRank2Symmetric::from(&get_tensor_rank_2()).norm(),
+        &get_tensor_rank_2().norm(),
+    )
+}
+
+#[test]
+fn sub_tensor_rank_2_symmetric_to_self() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &TensorRank2::from(
+            &(TensorRank2Symmetric::from(&get_tensor_rank_2())
+                - TensorRank2Symmetric::from(&get_other_tensor_rank_2())),
+        ),
+        &(get_tensor_rank_2() - get_other_tensor_rank_2()),
+    )
+}
+
+#[test]
+fn trace() {
+    assert_eq!(
+        TensorRank2Symmetric::from(&get_tensor_rank_2()).trace(),
+        6.0 as TensorRank0
+    )
+}
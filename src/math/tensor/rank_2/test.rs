@@ -1,7 +1,7 @@
 use super::{
     super::{
         test::{assert_eq, assert_eq_within_tols, ErrorTensor, TestError},
-        TensorError,
+        Hessian, TensorError,
     },
     Rank2, Tensor, TensorArray, TensorRank0, TensorRank1, TensorRank1List, TensorRank2,
     TensorRank2List2D, TensorRank4,
@@ -108,6 +108,10 @@ fn get_tensor_rank_2_dim_9() -> TensorRank2<9, 1, 1> {
     TensorRank2::new(get_array_dim_9())
 }
 
+fn get_tensor_rank_2_dim_9_f32() -> TensorRank2<9, 1, 1, f32> {
+    TensorRank2::new(get_array_dim_9().map(|row| row.map(|entry| entry as f32)))
+}
+
 fn get_other_tensor_rank_2_dim_2() -> TensorRank2<2, 1, 1> {
     TensorRank2::new([[5.0, 6.0], [7.0, 8.0]])
 }
@@ -480,6 +484,11 @@ fn determinant_dim_9() -> Result<(), TestError> {
     assert_eq_within_tols(&get_tensor_rank_2_dim_9().determinant(), &2398.0)
 }
 
+#[test]
+fn determinant_f32() {
+    assert!((get_tensor_rank_2_dim_9_f32().determinant() / 2398.0 - 1.0).abs() < 1e-5)
+}
+
 #[test]
 fn deviatoric_dim_2() -> Result<(), TestError> {
     let tensor_rank_2 = get_tensor_rank_2_dim_2();
@@ -576,6 +585,63 @@ fn dyad() {
     );
 }
 
+#[test]
+fn eigen_decomposition() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2_pos_def_dim_3();
+    let (eigenvalues, eigenvectors) = tensor_rank_2.eigen_decomposition();
+    assert!(eigenvalues[0] <= eigenvalues[1] && eigenvalues[1] <= eigenvalues[2]);
+    eigenvalues
+        .iter()
+        .zip(eigenvectors.iter())
+        .try_for_each(|(eigenvalue, eigenvector)| {
+            assert_eq_within_tols(&eigenvector.norm(), &1.0)?;
+            assert_eq_within_tols(&(&tensor_rank_2 * eigenvector), &(eigenvector * eigenvalue))
+        })?;
+    Ok(())
+}
+
+#[test]
+fn eigen_decomposition_identity() -> Result<(), TestError> {
+    TensorRank2::<3, 1, 1>::identity()
+        .eigen_decomposition()
+        .0
+        .iter()
+        .try_for_each(|eigenvalue| assert_eq_within_tols(eigenvalue, &1.0))
+}
+
+#[test]
+fn exponential() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &TensorRank2::<3, 1, 1>::new([[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]])
+            .exponential(),
+        &TensorRank2::new([
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0_f64.exp(), 0.0],
+            [0.0, 0.0, 2.0_f64.exp()],
+        ]),
+    )
+}
+
+#[test]
+fn exponential_logarithm() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2_pos_def_dim_3();
+    assert_eq_within_tols(&tensor_rank_2.logarithm()?.exponential(), &tensor_rank_2)
+}
+
+#[test]
+fn eliminate() {
+    let mut tensor_rank_2 = get_tensor_rank_2_pos_def_dim_3();
+    tensor_rank_2.eliminate(&[1]);
+    assert_eq!(
+        tensor_rank_2,
+        TensorRank2::new([
+            [1.25408849, 0.0, 1.02098481],
+            [0.0, 1.0, 0.0],
+            [1.02098481, 0.0, 1.22438759],
+        ])
+    )
+}
+
 #[test]
 fn error() {
     let a = get_tensor_rank_1_a();
@@ -682,6 +748,12 @@ fn inverse_dim_9() -> Result<(), TestError> {
     )
 }
 
+#[test]
+fn inverse_f32() {
+    let tensor_rank_2 = get_tensor_rank_2_dim_9_f32();
+    assert!((&tensor_rank_2 * tensor_rank_2.inverse() - TensorRank2::identity()).norm() < 1e-4)
+}
+
 #[test]
 fn inverse_and_determinant_dim_2() -> Result<(), TestError> {
     let tensor_rank_2 = get_tensor_rank_2_dim_2();
@@ -848,6 +920,15 @@ fn iter_mut() {
         });
 }
 
+#[test]
+fn logarithm_fail() {
+    assert_eq!(
+        TensorRank2::<3, 1, 1>::new([[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0]])
+            .logarithm(),
+        Err(TensorError::NotPositiveDefinite)
+    )
+}
+
 #[test]
 fn lu_decomposition() {
     let (tensor_l, tensor_u) = get_tensor_rank_2_dim_9().lu_decomposition();
@@ -1201,6 +1282,21 @@ fn norm_dim_9() -> Result<(), TestError> {
     assert_eq(&get_tensor_rank_2_dim_9().norm(), &20.976_176_963_403_03)
 }
 
+#[test]
+fn principal_stretches() -> Result<(), TestError> {
+    let (cosine, sine) = (0.3_f64.cos(), 0.3_f64.sin());
+    let rotation =
+        TensorRank2::<3, 1, 1>::new([[cosine, -sine, 0.0], [sine, cosine, 0.0], [0.0, 0.0, 1.0]]);
+    let stretch = TensorRank2::<3, 1, 1>::new([[3.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+    (rotation * stretch)
+        .principal_stretches()
+        .iter()
+        .zip([1.0, 2.0, 3.0].iter())
+        .try_for_each(|(principal_stretch, expected)| {
+            assert_eq_within_tols(principal_stretch, expected)
+        })
+}
+
 #[test]
 fn size() {
     assert_eq!(
@@ -1214,6 +1310,30 @@ fn second_invariant() {
     assert_eq!(get_tensor_rank_2_dim_4().second_invariant(), 16.0);
 }
 
+#[test]
+fn second_invariant_f32() {
+    let tensor_rank_2 = TensorRank2::<4, 1, 1, f32>::new(
+        get_array_dim_4().map(|row| row.map(|entry| entry as f32)),
+    );
+    assert_eq!(tensor_rank_2.second_invariant(), 16.0);
+}
+
+#[test]
+fn square_root() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2_pos_def_dim_3();
+    let square_root = tensor_rank_2.square_root()?;
+    assert_eq_within_tols(&(&square_root * &square_root), &tensor_rank_2)
+}
+
+#[test]
+fn square_root_fail() {
+    assert_eq!(
+        TensorRank2::<3, 1, 1>::new([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+            .square_root(),
+        Err(TensorError::NotPositiveDefinite)
+    )
+}
+
 #[test]
 fn squared_trace_dim_2() -> Result<(), TestError> {
     assert_eq_within_tols(&get_tensor_rank_2_dim_2().squared_trace(), &29.0)
@@ -1,7 +1,7 @@
 #[cfg(test)]
 use super::super::test::ErrorTensor;
 
-use crate::math::{Tensor, TensorArray, TensorRank0, TensorRank2, TensorVec};
+use crate::math::{Scalar, Tensor, TensorArray, TensorRank0, TensorRank2, TensorVec};
 use std::{
     fmt::{Display, Formatter, Result},
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
@@ -9,13 +9,15 @@ use std::{
 
 /// A vector of *d*-dimensional tensors of rank 2.
 ///
-/// `D` is the dimension, `I`, `J` are the configurations.
+/// `D` is the dimension, `I`, `J` are the configurations, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank2Vec<const D: usize, const I: usize, const J: usize>(
-    pub Vec<TensorRank2<D, I, J>>,
+pub struct TensorRank2Vec<const D: usize, const I: usize, const J: usize, T = TensorRank0>(
+    pub Vec<TensorRank2<D, I, J, T>>,
 );
 
-impl<const D: usize, const I: usize, const J: usize> Display for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Display
+    for TensorRank2Vec<D, I, J, T>
+{
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
     }
@@ -85,30 +87,36 @@ impl<const D: usize, const I: usize, const J: usize> ErrorTensor for TensorRank2
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> FromIterator<TensorRank2<D, I, J>>
-    for TensorRank2Vec<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar>
+    FromIterator<TensorRank2<D, I, J, T>> for TensorRank2Vec<D, I, J, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank2<D, I, J>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank2<D, I, J, T>>>(into_iterator: Ii) -> Self {
         Self(Vec::from_iter(into_iterator))
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Index<usize> for TensorRank2Vec<D, I, J> {
-    type Output = TensorRank2<D, I, J>;
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Index<usize>
+    for TensorRank2Vec<D, I, J, T>
+{
+    type Output = TensorRank2<D, I, J, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> IndexMut<usize> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> IndexMut<usize>
+    for TensorRank2Vec<D, I, J, T>
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<'a, const D: usize, const I: usize, const J: usize> TensorVec<'a> for TensorRank2Vec<D, I, J> {
-    type Item = TensorRank2<D, I, J>;
-    type Slice = &'a [[[TensorRank0; D]; D]];
+impl<'a, const D: usize, const I: usize, const J: usize, T: Scalar> TensorVec<'a>
+    for TensorRank2Vec<D, I, J, T>
+{
+    type Item = TensorRank2<D, I, J, T>;
+    type Slice = &'a [[[T; D]; D]];
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
@@ -126,11 +134,22 @@ impl<'a, const D: usize, const I: usize, const J: usize> TensorVec<'a> for Tenso
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Tensor for TensorRank2Vec<D, I, J> {
-    type Item = TensorRank2<D, I, J>;
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Tensor<T>
+    for TensorRank2Vec<D, I, J, T>
+{
+    type Item = TensorRank2<D, I, J, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        (D * indices[0] + indices[1]) * D + indices[2]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -139,7 +158,7 @@ impl<const D: usize, const I: usize, const J: usize> Tensor for TensorRank2Vec<D
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Add for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Add for TensorRank2Vec<D, I, J, T> {
     type Output = Self;
     fn add(mut self, tensor_rank_2_vec: Self) -> Self::Output {
         self += tensor_rank_2_vec;
@@ -147,7 +166,9 @@ impl<const D: usize, const I: usize, const J: usize> Add for TensorRank2Vec<D, I
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Add<&Self> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Add<&Self>
+    for TensorRank2Vec<D, I, J, T>
+{
     type Output = Self;
     fn add(mut self, tensor_rank_2_vec: &Self) -> Self::Output {
         self += tensor_rank_2_vec;
@@ -155,7 +176,9 @@ impl<const D: usize, const I: usize, const J: usize> Add<&Self> for TensorRank2V
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> AddAssign for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> AddAssign
+    for TensorRank2Vec<D, I, J, T>
+{
     fn add_assign(&mut self, tensor_rank_2_vec: Self) {
         self.iter_mut()
             .zip(tensor_rank_2_vec.iter())
@@ -163,7 +186,9 @@ impl<const D: usize, const I: usize, const J: usize> AddAssign for TensorRank2Ve
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> AddAssign<&Self> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> AddAssign<&Self>
+    for TensorRank2Vec<D, I, J, T>
+{
     fn add_assign(&mut self, tensor_rank_2_vec: &Self) {
         self.iter_mut()
             .zip(tensor_rank_2_vec.iter())
@@ -171,71 +196,79 @@ impl<const D: usize, const I: usize, const J: usize> AddAssign<&Self> for Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Div<TensorRank0> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Div<T>
+    for TensorRank2Vec<D, I, J, T>
+{
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Div<&TensorRank0> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Div<&T>
+    for TensorRank2Vec<D, I, J, T>
+{
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> DivAssign<TensorRank0>
-    for TensorRank2Vec<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> DivAssign<T>
+    for TensorRank2Vec<D, I, J, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> DivAssign<&TensorRank0>
-    for TensorRank2Vec<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> DivAssign<&T>
+    for TensorRank2Vec<D, I, J, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<TensorRank0> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<T>
+    for TensorRank2Vec<D, I, J, T>
+{
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Mul<&TensorRank0> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Mul<&T>
+    for TensorRank2Vec<D, I, J, T>
+{
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> MulAssign<TensorRank0>
-    for TensorRank2Vec<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> MulAssign<T>
+    for TensorRank2Vec<D, I, J, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> MulAssign<&TensorRank0>
-    for TensorRank2Vec<D, I, J>
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> MulAssign<&T>
+    for TensorRank2Vec<D, I, J, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Sub for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Sub for TensorRank2Vec<D, I, J, T> {
     type Output = Self;
     fn sub(mut self, tensor_rank_2_vec: Self) -> Self::Output {
         self -= tensor_rank_2_vec;
@@ -243,7 +276,9 @@ impl<const D: usize, const I: usize, const J: usize> Sub for TensorRank2Vec<D, I
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> Sub<&Self> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> Sub<&Self>
+    for TensorRank2Vec<D, I, J, T>
+{
     type Output = Self;
     fn sub(mut self, tensor_rank_2_vec: &Self) -> Self::Output {
         self -= tensor_rank_2_vec;
@@ -251,7 +286,9 @@ impl<const D: usize, const I: usize, const J: usize> Sub<&Self> for TensorRank2V
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> SubAssign for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> SubAssign
+    for TensorRank2Vec<D, I, J, T>
+{
     fn sub_assign(&mut self, tensor_rank_2_vec: Self) {
         self.iter_mut()
             .zip(tensor_rank_2_vec.iter())
@@ -259,7 +296,9 @@ impl<const D: usize, const I: usize, const J: usize> SubAssign for TensorRank2Ve
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize> SubAssign<&Self> for TensorRank2Vec<D, I, J> {
+impl<const D: usize, const I: usize, const J: usize, T: Scalar> SubAssign<&Self>
+    for TensorRank2Vec<D, I, J, T>
+{
     fn sub_assign(&mut self, tensor_rank_2_vec: &Self) {
         self.iter_mut()
             .zip(tensor_rank_2_vec.iter())
@@ -7,11 +7,11 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use super::{Tensor, TensorArray, TensorRank0, TensorRank3};
+use super::{Scalar, Tensor, TensorArray, TensorRank0, TensorRank3};
 
 /// A list of *d*-dimensional tensors of rank 3.
 ///
-/// `D` is the dimension, `I`, `J`, `K` are the configurations `W` is the list length.
+/// `D` is the dimension, `I`, `J`, `K` are the configurations `W` is the list length, `T` is the scalar type.
 #[derive(Debug)]
 pub struct TensorRank3List<
     const D: usize,
@@ -19,23 +19,33 @@ pub struct TensorRank3List<
     const J: usize,
     const K: usize,
     const W: usize,
->([TensorRank3<D, I, J, K>; W]);
+    T = TensorRank0,
+>([TensorRank3<D, I, J, K, T>; W]);
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Display
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Display for TensorRank3List<D, I, J, K, W, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Tensor
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Tensor<T> for TensorRank3List<D, I, J, K, W, T>
 {
-    type Item = TensorRank3<D, I, J, K>;
+    type Item = TensorRank3<D, I, J, K, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        ((indices[0] * D + indices[1]) * D + indices[2]) * D + indices[3]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]][indices[3]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]][indices[3]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -44,13 +54,13 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> TensorArray
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    TensorArray for TensorRank3List<D, I, J, K, W, T>
 {
-    type Array = [[[[TensorRank0; D]; D]; D]; W];
-    type Item = TensorRank3<D, I, J, K>;
+    type Array = [[[[T; D]; D]; D]; W];
+    type Item = TensorRank3<D, I, J, K, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[[0.0; D]; D]; D]; W];
+        let mut array = [[[[T::zero(); D]; D]; D]; W];
         array
             .iter_mut()
             .zip(self.iter())
@@ -71,10 +81,10 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    FromIterator<TensorRank3<D, I, J, K>> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    FromIterator<TensorRank3<D, I, J, K, T>> for TensorRank3List<D, I, J, K, W, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank3<D, I, J, K>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank3<D, I, J, K, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_3_list = Self::zero();
         tensor_rank_3_list
             .iter_mut()
@@ -84,25 +94,25 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Index<usize>
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Index<usize> for TensorRank3List<D, I, J, K, W, T>
 {
-    type Output = TensorRank3<D, I, J, K>;
+    type Output = TensorRank3<D, I, J, K, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> IndexMut<usize>
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    IndexMut<usize> for TensorRank3List<D, I, J, K, W, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> std::iter::Sum
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    std::iter::Sum for TensorRank3List<D, I, J, K, W, T>
 {
     fn sum<Ii>(iter: Ii) -> Self
     where
@@ -114,8 +124,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Add
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar> Add
+    for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3_list: Self) -> Self::Output {
@@ -124,8 +134,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Add<&Self>
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Add<&Self> for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3_list: &Self) -> Self::Output {
@@ -134,8 +144,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> AddAssign
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    AddAssign for TensorRank3List<D, I, J, K, W, T>
 {
     fn add_assign(&mut self, tensor_rank_3_list: Self) {
         self.iter_mut()
@@ -144,8 +154,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    AddAssign<&Self> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    AddAssign<&Self> for TensorRank3List<D, I, J, K, W, T>
 {
     fn add_assign(&mut self, tensor_rank_3_list: &Self) {
         self.iter_mut()
@@ -154,80 +164,80 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    Div<TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Div<T> for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    Div<&TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Div<&T> for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    DivAssign<TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    DivAssign<T> for TensorRank3List<D, I, J, K, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    DivAssign<&TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    DivAssign<&T> for TensorRank3List<D, I, J, K, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    Mul<TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Mul<T> for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    Mul<&TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Mul<&T> for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    MulAssign<TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    MulAssign<T> for TensorRank3List<D, I, J, K, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    MulAssign<&TensorRank0> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    MulAssign<&T> for TensorRank3List<D, I, J, K, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Sub
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar> Sub
+    for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3_list: Self) -> Self::Output {
@@ -236,8 +246,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> Sub<&Self>
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    Sub<&Self> for TensorRank3List<D, I, J, K, W, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3_list: &Self) -> Self::Output {
@@ -246,8 +256,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize> SubAssign
-    for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    SubAssign for TensorRank3List<D, I, J, K, W, T>
 {
     fn sub_assign(&mut self, tensor_rank_3_list: Self) {
         self.iter_mut()
@@ -256,8 +266,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize>
-    SubAssign<&Self> for TensorRank3List<D, I, J, K, W>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const W: usize, T: Scalar>
+    SubAssign<&Self> for TensorRank3List<D, I, J, K, W, T>
 {
     fn sub_assign(&mut self, tensor_rank_3_list: &Self) {
         self.iter_mut()
@@ -7,7 +7,7 @@ use super::super::test::ErrorTensor;
 use super::{
     super::{Tensor, TensorArray},
     list::TensorRank3List,
-    TensorRank0,
+    Scalar, TensorRank0,
 };
 use std::{
     array::from_fn,
@@ -17,7 +17,7 @@ use std::{
 
 /// A 2D list of *d*-dimensional tensors of rank 3.
 ///
-/// `D` is the dimension, `I`, `J`, `K` are the configurations `W` and `X` are the list lengths.
+/// `D` is the dimension, `I`, `J`, `K` are the configurations `W` and `X` are the list lengths, `T` is the scalar type.
 #[derive(Debug)]
 pub struct TensorRank3List2D<
     const D: usize,
@@ -26,7 +26,8 @@ pub struct TensorRank3List2D<
     const K: usize,
     const W: usize,
     const X: usize,
->([TensorRank3List<D, I, J, K, W>; X]);
+    T = TensorRank0,
+>([TensorRank3List<D, I, J, K, W, T>; X]);
 
 impl<
         const D: usize,
@@ -35,7 +36,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Display for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Display for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
@@ -148,12 +150,22 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Tensor for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Tensor<T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    type Item = TensorRank3List<D, I, J, K, W>;
+    type Item = TensorRank3List<D, I, J, K, W, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        (((indices[0] * W + indices[1]) * D + indices[2]) * D + indices[3]) * D + indices[4]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]][indices[3]][indices[4]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]][indices[3]][indices[4]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -169,12 +181,13 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > TensorArray for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > TensorArray for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    type Array = [[[[[TensorRank0; D]; D]; D]; W]; X];
-    type Item = TensorRank3List<D, I, J, K, W>;
+    type Array = [[[[[T; D]; D]; D]; W]; X];
+    type Item = TensorRank3List<D, I, J, K, W, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[[[0.0; D]; D]; D]; W]; X];
+        let mut array = [[[[[T::zero(); D]; D]; D]; W]; X];
         array
             .iter_mut()
             .zip(self.iter())
@@ -204,9 +217,10 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > FromIterator<TensorRank3List<D, I, J, K, W>> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > FromIterator<TensorRank3List<D, I, J, K, W, T>> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank3List<D, I, J, K, W>>>(
+    fn from_iter<Ii: IntoIterator<Item = TensorRank3List<D, I, J, K, W, T>>>(
         into_iterator: Ii,
     ) -> Self {
         let mut tensor_rank_3_list_2d = Self::zero();
@@ -225,9 +239,10 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Index<usize> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Index<usize> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    type Output = TensorRank3List<D, I, J, K, W>;
+    type Output = TensorRank3List<D, I, J, K, W, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
@@ -240,7 +255,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > IndexMut<usize> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > IndexMut<usize> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
@@ -254,7 +270,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Add for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Add for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3_list_2d: Self) -> Self::Output {
@@ -270,7 +287,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Add<&Self> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Add<&Self> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3_list_2d: &Self) -> Self::Output {
@@ -286,7 +304,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > AddAssign for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > AddAssign for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     fn add_assign(&mut self, tensor_rank_3_list_2d: Self) {
         self.iter_mut()
@@ -302,7 +321,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > AddAssign<&Self> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > AddAssign<&Self> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     fn add_assign(&mut self, tensor_rank_3_list_2d: &Self) {
         self.iter_mut()
@@ -318,10 +338,11 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Div<TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Div<T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
@@ -334,10 +355,11 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Div<&TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Div<&T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
@@ -350,9 +372,10 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > DivAssign<TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > DivAssign<T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
@@ -364,9 +387,10 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > DivAssign<&TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > DivAssign<&T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
@@ -378,10 +402,11 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Mul<TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Mul<T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
@@ -394,10 +419,11 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Mul<&TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Mul<&T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
@@ -410,9 +436,10 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > MulAssign<TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > MulAssign<T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
@@ -424,9 +451,10 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > MulAssign<&TensorRank0> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > MulAssign<&T> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
@@ -438,7 +466,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Sub for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Sub for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3_list_2d: Self) -> Self::Output {
@@ -454,7 +483,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > Sub<&Self> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > Sub<&Self> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3_list_2d: &Self) -> Self::Output {
@@ -470,7 +500,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > SubAssign for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > SubAssign for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     fn sub_assign(&mut self, tensor_rank_3_list_2d: Self) {
         self.iter_mut()
@@ -486,7 +517,8 @@ impl<
         const K: usize,
         const W: usize,
         const X: usize,
-    > SubAssign<&Self> for TensorRank3List2D<D, I, J, K, W, X>
+        T: Scalar,
+    > SubAssign<&Self> for TensorRank3List2D<D, I, J, K, W, X, T>
 {
     fn sub_assign(&mut self, tensor_rank_3_list_2d: &Self) {
         self.iter_mut()
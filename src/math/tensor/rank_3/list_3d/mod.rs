@@ -7,7 +7,7 @@ use super::super::test::ErrorTensor;
 use super::{
     super::{Tensor, TensorArray},
     list_2d::TensorRank3List2D,
-    TensorRank0,
+    Scalar, TensorRank0,
 };
 use std::{
     array::from_fn,
@@ -17,7 +17,7 @@ use std::{
 
 /// A 3D list of *d*-dimensional tensors of rank 3.
 ///
-/// `D` is the dimension, `I`, `J`, `K` are the configurations `W`, `X`, and `Y` are the list lengths.
+/// `D` is the dimension, `I`, `J`, `K` are the configurations `W`, `X`, and `Y` are the list lengths, `T` is the scalar type.
 #[derive(Debug)]
 pub struct TensorRank3List3D<
     const D: usize,
@@ -27,7 +27,8 @@ pub struct TensorRank3List3D<
     const W: usize,
     const X: usize,
     const Y: usize,
->([TensorRank3List2D<D, I, J, K, W, X>; Y]);
+    T = TensorRank0,
+>([TensorRank3List2D<D, I, J, K, W, X, T>; Y]);
 
 impl<
         const D: usize,
@@ -37,7 +38,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Display for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Display for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
@@ -175,12 +177,23 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Tensor for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Tensor<T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    type Item = TensorRank3List2D<D, I, J, K, W, X>;
+    type Item = TensorRank3List2D<D, I, J, K, W, X, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        ((((indices[0] * X + indices[1]) * W + indices[2]) * D + indices[3]) * D + indices[4]) * D
+            + indices[5]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]][indices[3]][indices[4]][indices[5]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]][indices[3]][indices[4]][indices[5]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -197,12 +210,13 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > TensorArray for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > TensorArray for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    type Array = [[[[[[TensorRank0; D]; D]; D]; W]; X]; Y];
-    type Item = TensorRank3List2D<D, I, J, K, W, X>;
+    type Array = [[[[[[T; D]; D]; D]; W]; X]; Y];
+    type Item = TensorRank3List2D<D, I, J, K, W, X, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[[[[0.0; D]; D]; D]; W]; X]; Y];
+        let mut array = [[[[[[T::zero(); D]; D]; D]; W]; X]; Y];
         array.iter_mut().zip(self.iter()).for_each(
             |(entry_rank_3_list_2d, tensor_rank_3_list_2d)| {
                 *entry_rank_3_list_2d = tensor_rank_3_list_2d.as_array()
@@ -232,9 +246,11 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > FromIterator<TensorRank3List2D<D, I, J, K, W, X>> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > FromIterator<TensorRank3List2D<D, I, J, K, W, X, T>>
+    for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank3List2D<D, I, J, K, W, X>>>(
+    fn from_iter<Ii: IntoIterator<Item = TensorRank3List2D<D, I, J, K, W, X, T>>>(
         into_iterator: Ii,
     ) -> Self {
         let mut tensor_rank_3_list_3d = Self::zero();
@@ -254,9 +270,10 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Index<usize> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Index<usize> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    type Output = TensorRank3List2D<D, I, J, K, W, X>;
+    type Output = TensorRank3List2D<D, I, J, K, W, X, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
@@ -270,7 +287,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > IndexMut<usize> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > IndexMut<usize> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
@@ -285,7 +303,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Add for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Add for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3_list_3d: Self) -> Self::Output {
@@ -302,7 +321,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Add<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Add<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3_list_3d: &Self) -> Self::Output {
@@ -319,7 +339,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > AddAssign for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > AddAssign for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     fn add_assign(&mut self, tensor_rank_3_list_3d: Self) {
         self.iter_mut()
@@ -336,7 +357,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > AddAssign<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > AddAssign<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     fn add_assign(&mut self, tensor_rank_3_list_3d: &Self) {
         self.iter_mut()
@@ -353,10 +375,11 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Div<TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Div<T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
@@ -370,10 +393,11 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Div<&TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Div<&T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
@@ -387,9 +411,10 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > DivAssign<TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > DivAssign<T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
@@ -402,9 +427,10 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > DivAssign<&TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > DivAssign<&T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
@@ -417,10 +443,11 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Mul<TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Mul<T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
@@ -434,10 +461,11 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Mul<&TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Mul<&T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
@@ -451,9 +479,10 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > MulAssign<TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > MulAssign<T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
@@ -466,9 +495,10 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > MulAssign<&TensorRank0> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > MulAssign<&T> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
@@ -481,7 +511,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Sub for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Sub for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3_list_3d: Self) -> Self::Output {
@@ -498,7 +529,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > Sub<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > Sub<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3_list_3d: &Self) -> Self::Output {
@@ -515,7 +547,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > SubAssign for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > SubAssign for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     fn sub_assign(&mut self, tensor_rank_3_list_3d: Self) {
         self.iter_mut()
@@ -532,7 +565,8 @@ impl<
         const W: usize,
         const X: usize,
         const Y: usize,
-    > SubAssign<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y>
+        T: Scalar,
+    > SubAssign<&Self> for TensorRank3List3D<D, I, J, K, W, X, Y, T>
 {
     fn sub_assign(&mut self, tensor_rank_3_list_3d: &Self) {
         self.iter_mut()
@@ -14,7 +14,11 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use super::{rank_0::TensorRank0, rank_2::TensorRank2, Tensor, TensorArray};
+use super::{
+    rank_0::{Scalar, TensorRank0},
+    rank_2::TensorRank2,
+    Tensor, TensorArray,
+};
 
 /// Returns the rank-3 Levi-Civita symbol.
 pub fn levi_civita<const I: usize, const J: usize, const K: usize>() -> TensorRank3<3, I, J, K> {
@@ -27,22 +31,26 @@ pub fn levi_civita<const I: usize, const J: usize, const K: usize>() -> TensorRa
 
 /// A *d*-dimensional tensor of rank 3.
 ///
-/// `D` is the dimension, `I`, `J`, `K` are the configurations.
+/// `D` is the dimension, `I`, `J`, `K` are the configurations, `T` is the scalar type.
 #[derive(Debug)]
-pub struct TensorRank3<const D: usize, const I: usize, const J: usize, const K: usize>(
-    pub [TensorRank2<D, J, K>; D],
-);
-
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Display
-    for TensorRank3<D, I, J, K>
+pub struct TensorRank3<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    T = TensorRank0,
+>(pub [TensorRank2<D, J, K, T>; D]);
+
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Display
+    for TensorRank3<D, I, J, K, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> PartialEq
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> PartialEq
+    for TensorRank3<D, I, J, K, T>
 {
     fn eq(&self, other: &Self) -> bool {
         let mut result = true;
@@ -121,15 +129,24 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> ErrorTensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Tensor
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Tensor<T>
+    for TensorRank3<D, I, J, K, T>
 {
-    type Item = TensorRank2<D, J, K>;
+    type Item = TensorRank2<D, J, K, T>;
     fn copy(&self) -> Self {
         self.iter()
             .map(|entry_rank_2| entry_rank_2.copy())
             .collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        (D * indices[0] + indices[1]) * D + indices[2]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -138,13 +155,13 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Tensor
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> TensorArray
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> TensorArray
+    for TensorRank3<D, I, J, K, T>
 {
-    type Array = [[[TensorRank0; D]; D]; D];
-    type Item = TensorRank2<D, J, K>;
+    type Array = [[[T; D]; D]; D];
+    type Item = TensorRank2<D, J, K, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[0.0; D]; D]; D];
+        let mut array = [[[T::zero(); D]; D]; D];
         array
             .iter_mut()
             .zip(self.iter())
@@ -162,10 +179,10 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> TensorArray
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize>
-    FromIterator<TensorRank2<D, J, K>> for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar>
+    FromIterator<TensorRank2<D, J, K, T>> for TensorRank3<D, I, J, K, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank2<D, J, K>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank2<D, J, K, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_3 = Self::zero();
         tensor_rank_3
             .iter_mut()
@@ -175,106 +192,106 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Index<usize>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Index<usize>
+    for TensorRank3<D, I, J, K, T>
 {
-    type Output = TensorRank2<D, J, K>;
+    type Output = TensorRank2<D, J, K, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> IndexMut<usize>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> IndexMut<usize>
+    for TensorRank3<D, I, J, K, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Div<TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Div<T>
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Div<TensorRank0>
-    for &TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Div<T>
+    for &TensorRank3<D, I, J, K, T>
 {
-    type Output = TensorRank3<D, I, J, K>;
-    fn div(self, tensor_rank_0: TensorRank0) -> Self::Output {
+    type Output = TensorRank3<D, I, J, K, T>;
+    fn div(self, tensor_rank_0: T) -> Self::Output {
         self.iter().map(|self_i| self_i / tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Div<&TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Div<&T>
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> DivAssign<TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> DivAssign<T>
+    for TensorRank3<D, I, J, K, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|self_i| *self_i /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> DivAssign<&TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> DivAssign<&T>
+    for TensorRank3<D, I, J, K, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Mul<T>
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Mul<&TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Mul<&T>
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> MulAssign<TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> MulAssign<T>
+    for TensorRank3<D, I, J, K, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|self_i| *self_i *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> MulAssign<&TensorRank0>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> MulAssign<&T>
+    for TensorRank3<D, I, J, K, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Add
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Add
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3: Self) -> Self::Output {
@@ -283,8 +300,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Add
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Add<&Self>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Add<&Self>
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_3: &Self) -> Self::Output {
@@ -293,18 +310,18 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Add<&Self>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Add<TensorRank3<D, I, J, K>>
-    for &TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar>
+    Add<TensorRank3<D, I, J, K, T>> for &TensorRank3<D, I, J, K, T>
 {
-    type Output = TensorRank3<D, I, J, K>;
-    fn add(self, mut tensor_rank_3: TensorRank3<D, I, J, K>) -> Self::Output {
+    type Output = TensorRank3<D, I, J, K, T>;
+    fn add(self, mut tensor_rank_3: TensorRank3<D, I, J, K, T>) -> Self::Output {
         tensor_rank_3 += self;
         tensor_rank_3
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> AddAssign
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> AddAssign
+    for TensorRank3<D, I, J, K, T>
 {
     fn add_assign(&mut self, tensor_rank_3: Self) {
         self.iter_mut()
@@ -313,8 +330,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> AddAssign
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> AddAssign<&Self>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> AddAssign<&Self>
+    for TensorRank3<D, I, J, K, T>
 {
     fn add_assign(&mut self, tensor_rank_3: &Self) {
         self.iter_mut()
@@ -323,8 +340,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> AddAssign<&
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Sub
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Sub
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3: Self) -> Self::Output {
@@ -333,8 +350,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Sub
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> Sub<&Self>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> Sub<&Self>
+    for TensorRank3<D, I, J, K, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_3: &Self) -> Self::Output {
@@ -343,8 +360,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> Sub<&Self>
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> SubAssign
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> SubAssign
+    for TensorRank3<D, I, J, K, T>
 {
     fn sub_assign(&mut self, tensor_rank_3: Self) {
         self.iter_mut()
@@ -353,8 +370,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize> SubAssign
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize> SubAssign<&Self>
-    for TensorRank3<D, I, J, K>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, T: Scalar> SubAssign<&Self>
+    for TensorRank3<D, I, J, K, T>
 {
     fn sub_assign(&mut self, tensor_rank_3: &Self) {
         self.iter_mut()
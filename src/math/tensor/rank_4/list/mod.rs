@@ -7,11 +7,11 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
 };
 
-use super::{Tensor, TensorArray, TensorRank0, TensorRank4};
+use super::{Scalar, Tensor, TensorArray, TensorRank0, TensorRank4};
 
 /// A list of *d*-dimensional tensor of rank 4.
 ///
-/// `D` is the dimension, `I`, `J`, `K`, `L` are the configurations, `W` is the list length.
+/// `D` is the dimension, `I`, `J`, `K`, `L` are the configurations, `W` is the list length, `T` is the scalar type.
 #[derive(Debug)]
 pub struct TensorRank4List<
     const D: usize,
@@ -20,7 +20,8 @@ pub struct TensorRank4List<
     const K: usize,
     const L: usize,
     const W: usize,
->([TensorRank4<D, I, J, K, L>; W]);
+    T = TensorRank0,
+>([TensorRank4<D, I, J, K, L, T>; W]);
 
 impl<
         const D: usize,
@@ -29,7 +30,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Display for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Display for TensorRank4List<D, I, J, K, L, W, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
@@ -43,12 +45,22 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Tensor for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Tensor<T> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    type Item = TensorRank4<D, I, J, K, L>;
+    type Item = TensorRank4<D, I, J, K, L, T>;
     fn copy(&self) -> Self {
         self.iter().map(|entry| entry.copy()).collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        (((indices[0] * D + indices[1]) * D + indices[2]) * D + indices[3]) * D + indices[4]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]][indices[3]][indices[4]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]][indices[3]][indices[4]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -64,12 +76,13 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > TensorArray for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > TensorArray for TensorRank4List<D, I, J, K, L, W, T>
 {
-    type Array = [[[[[TensorRank0; D]; D]; D]; D]; W];
-    type Item = TensorRank4<D, I, J, K, L>;
+    type Array = [[[[[T; D]; D]; D]; D]; W];
+    type Item = TensorRank4<D, I, J, K, L, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[[[0.0; D]; D]; D]; D]; W];
+        let mut array = [[[[[T::zero(); D]; D]; D]; D]; W];
         array
             .iter_mut()
             .zip(self.iter())
@@ -97,9 +110,12 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > FromIterator<TensorRank4<D, I, J, K, L>> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > FromIterator<TensorRank4<D, I, J, K, L, T>> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank4<D, I, J, K, L>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank4<D, I, J, K, L, T>>>(
+        into_iterator: Ii,
+    ) -> Self {
         let mut tensor_rank_4_list = Self::zero();
         tensor_rank_4_list
             .iter_mut()
@@ -116,9 +132,10 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Index<usize> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Index<usize> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    type Output = TensorRank4<D, I, J, K, L>;
+    type Output = TensorRank4<D, I, J, K, L, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
@@ -131,7 +148,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > IndexMut<usize> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > IndexMut<usize> for TensorRank4List<D, I, J, K, L, W, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
@@ -145,7 +163,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Add for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Add for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_4: Self) -> Self::Output {
@@ -161,7 +180,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Add<&Self> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Add<&Self> for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_4_list: &Self) -> Self::Output {
@@ -177,7 +197,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > AddAssign for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > AddAssign for TensorRank4List<D, I, J, K, L, W, T>
 {
     fn add_assign(&mut self, tensor_rank_4_list: Self) {
         self.iter_mut()
@@ -193,7 +214,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > AddAssign<&Self> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > AddAssign<&Self> for TensorRank4List<D, I, J, K, L, W, T>
 {
     fn add_assign(&mut self, tensor_rank_4_list: &Self) {
         self.iter_mut()
@@ -209,10 +231,11 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Div<TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Div<T> for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
@@ -225,10 +248,11 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Div<&TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Div<&T> for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
@@ -241,9 +265,10 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > DivAssign<TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > DivAssign<T> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry /= &tensor_rank_0);
     }
 }
@@ -255,9 +280,10 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > DivAssign<&TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > DivAssign<&T> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry /= tensor_rank_0);
     }
 }
@@ -269,10 +295,11 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Mul<TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Mul<T> for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
@@ -285,10 +312,11 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Mul<&TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Mul<&T> for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
@@ -301,9 +329,10 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > MulAssign<TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > MulAssign<T> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|entry| *entry *= &tensor_rank_0);
     }
 }
@@ -315,9 +344,10 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > MulAssign<&TensorRank0> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > MulAssign<&T> for TensorRank4List<D, I, J, K, L, W, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|entry| *entry *= tensor_rank_0);
     }
 }
@@ -329,7 +359,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Sub for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Sub for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_4_list: Self) -> Self::Output {
@@ -345,7 +376,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > Sub<&Self> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > Sub<&Self> for TensorRank4List<D, I, J, K, L, W, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_4_list: &Self) -> Self::Output {
@@ -361,7 +393,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > SubAssign for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > SubAssign for TensorRank4List<D, I, J, K, L, W, T>
 {
     fn sub_assign(&mut self, tensor_rank_4_list: Self) {
         self.iter_mut()
@@ -377,7 +410,8 @@ impl<
         const K: usize,
         const L: usize,
         const W: usize,
-    > SubAssign<&Self> for TensorRank4List<D, I, J, K, L, W>
+        T: Scalar,
+    > SubAssign<&Self> for TensorRank4List<D, I, J, K, L, W, T>
 {
     fn sub_assign(&mut self, tensor_rank_4_list: &Self) {
         self.iter_mut()
@@ -11,15 +11,23 @@ use std::{
 };
 
 use super::{
-    rank_0::TensorRank0, rank_1::TensorRank1, rank_2::TensorRank2, rank_3::TensorRank3, Hessian,
-    Rank2, Tensor, TensorArray,
+    super::{IterativeSolver, SquareMatrix},
+    rank_0::{Scalar, TensorRank0},
+    rank_1::TensorRank1,
+    rank_2::TensorRank2,
+    rank_3::TensorRank3,
+    Hessian, Rank2, Tensor, TensorArray, TensorError,
 };
 
+#[cfg(feature = "simd")]
+use super::simd;
+
 pub mod list;
+pub mod symmetric;
 
 /// A *d*-dimensional tensor of rank 4.
 ///
-/// `D` is the dimension, `I`, `J`, `K`, `L` are the configurations.
+/// `D` is the dimension, `I`, `J`, `K`, `L` are the configurations, `T` is the scalar type.
 #[derive(Debug)]
 pub struct TensorRank4<
     const D: usize,
@@ -27,18 +35,19 @@ pub struct TensorRank4<
     const J: usize,
     const K: usize,
     const L: usize,
->(pub [TensorRank3<D, J, K, L>; D]);
+    T = TensorRank0,
+>(pub [TensorRank3<D, J, K, L, T>; D]);
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Display
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Display for TensorRank4<D, I, J, K, L, T>
 {
     fn fmt(&self, _f: &mut Formatter) -> Result {
         Ok(())
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> PartialEq
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    PartialEq for TensorRank4<D, I, J, K, L, T>
 {
     fn eq(&self, other: &Self) -> bool {
         let mut result = true;
@@ -129,12 +138,12 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    TensorRank4<D, I, J, K, L, T>
 {
-    pub fn as_tensor_rank_2(&self) -> TensorRank2<9, 88, 99> {
+    pub fn as_tensor_rank_2(&self) -> TensorRank2<9, 88, 99, T> {
         assert_eq!(D, 3);
-        let mut tensor_rank_2 = TensorRank2::<9, 88, 99>::zero();
+        let mut tensor_rank_2 = TensorRank2::<9, 88, 99, T>::zero();
         self.iter().enumerate().for_each(|(i, self_i)| {
             self_i.iter().enumerate().for_each(|(j, self_ij)| {
                 self_ij.iter().enumerate().for_each(|(k, self_ijk)| {
@@ -148,9 +157,13 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
         tensor_rank_2
     }
     pub fn dyad_ij_kl(
-        tensor_rank_2_a: &TensorRank2<D, I, J>,
-        tensor_rank_2_b: &TensorRank2<D, K, L>,
+        tensor_rank_2_a: &TensorRank2<D, I, J, T>,
+        tensor_rank_2_b: &TensorRank2<D, K, L, T>,
     ) -> Self {
+        #[cfg(feature = "simd")]
+        if let Some(output) = simd::dyad_ij_kl(tensor_rank_2_a, tensor_rank_2_b) {
+            return output;
+        }
         tensor_rank_2_a
             .iter()
             .map(|tensor_rank_2_a_i| {
@@ -162,9 +175,13 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
             .collect()
     }
     pub fn dyad_ik_jl(
-        tensor_rank_2_a: &TensorRank2<D, I, K>,
-        tensor_rank_2_b: &TensorRank2<D, J, L>,
+        tensor_rank_2_a: &TensorRank2<D, I, K, T>,
+        tensor_rank_2_b: &TensorRank2<D, J, L, T>,
     ) -> Self {
+        #[cfg(feature = "simd")]
+        if let Some(output) = simd::dyad_ik_jl(tensor_rank_2_a, tensor_rank_2_b) {
+            return output;
+        }
         tensor_rank_2_a
             .iter()
             .map(|tensor_rank_2_a_i| {
@@ -181,9 +198,13 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
             .collect()
     }
     pub fn dyad_il_jk(
-        tensor_rank_2_a: &TensorRank2<D, I, L>,
-        tensor_rank_2_b: &TensorRank2<D, J, K>,
+        tensor_rank_2_a: &TensorRank2<D, I, L, T>,
+        tensor_rank_2_b: &TensorRank2<D, J, K, T>,
     ) -> Self {
+        #[cfg(feature = "simd")]
+        if let Some(output) = simd::dyad_il_jk(tensor_rank_2_a, tensor_rank_2_b) {
+            return output;
+        }
         tensor_rank_2_a
             .iter()
             .map(|tensor_rank_2_a_i| {
@@ -200,8 +221,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
             .collect()
     }
     pub fn dyad_il_kj(
-        tensor_rank_2_a: &TensorRank2<D, I, L>,
-        tensor_rank_2_b: &TensorRank2<D, K, J>,
+        tensor_rank_2_a: &TensorRank2<D, I, L, T>,
+        tensor_rank_2_b: &TensorRank2<D, K, J, T>,
     ) -> Self {
         Self::dyad_il_jk(tensor_rank_2_a, &(tensor_rank_2_b.transpose()))
     }
@@ -210,20 +231,65 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
 impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Hessian
     for TensorRank4<D, I, J, K, L>
 {
+    fn eliminate(&mut self, indices: &[usize]) {
+        indices.iter().for_each(|&index| {
+            let (k, l) = (index / D, index % D);
+            self.iter_mut()
+                .for_each(|self_i| self_i.iter_mut().for_each(|self_ij| self_ij[k][l] = 0.0));
+            self[k][l] = TensorRank2::zero();
+            self[k][l][k][l] = 1.0;
+        })
+    }
     fn is_positive_definite(&self) -> bool {
         self.as_tensor_rank_2().cholesky_decomposition().is_ok()
     }
+    fn solve_directly(
+        &self,
+        rhs: &[TensorRank0],
+    ) -> std::result::Result<Vec<TensorRank0>, TensorError> {
+        self.flattened()
+            .chunks(rhs.len())
+            .map(|row| row.iter().copied().collect())
+            .collect::<SquareMatrix>()
+            .solve_directly(rhs)
+    }
+    fn solve_directly_multiple(
+        &self,
+        rhs: &[&[TensorRank0]],
+    ) -> std::result::Result<Vec<Vec<TensorRank0>>, TensorError> {
+        self.flattened()
+            .chunks(D * D)
+            .map(|row| row.iter().copied().collect())
+            .collect::<SquareMatrix>()
+            .solve_directly_multiple(rhs)
+    }
+    fn solve_iteratively(
+        &self,
+        rhs: &[TensorRank0],
+        iterative_solver: &IterativeSolver,
+    ) -> std::result::Result<Vec<TensorRank0>, TensorError> {
+        iterative_solver.solve_dense(&self.flattened(), rhs)
+    }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Tensor
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Tensor<T> for TensorRank4<D, I, J, K, L, T>
 {
-    type Item = TensorRank3<D, J, K, L>;
+    type Item = TensorRank3<D, J, K, L, T>;
     fn copy(&self) -> Self {
         self.iter()
             .map(|entry_rank_3| entry_rank_3.copy())
             .collect()
     }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        ((D * indices[0] + indices[1]) * D + indices[2]) * D + indices[3]
+    }
+    fn get_at(&self, indices: &[usize]) -> &T {
+        &self[indices[0]][indices[1]][indices[2]][indices[3]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut T {
+        &mut self[indices[0]][indices[1]][indices[2]][indices[3]]
+    }
     fn iter(&self) -> impl Iterator<Item = &Self::Item> {
         self.0.iter()
     }
@@ -232,13 +298,13 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> TensorArray
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    TensorArray for TensorRank4<D, I, J, K, L, T>
 {
-    type Array = [[[[TensorRank0; D]; D]; D]; D];
-    type Item = TensorRank3<D, J, K, L>;
+    type Array = [[[[T; D]; D]; D]; D];
+    type Item = TensorRank3<D, J, K, L, T>;
     fn as_array(&self) -> Self::Array {
-        let mut array = [[[[0.0; D]; D]; D]; D];
+        let mut array = [[[[T::zero(); D]; D]; D]; D];
         array
             .iter_mut()
             .zip(self.iter())
@@ -256,10 +322,10 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    FromIterator<TensorRank3<D, J, K, L>> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    FromIterator<TensorRank3<D, J, K, L, T>> for TensorRank4<D, I, J, K, L, T>
 {
-    fn from_iter<Ii: IntoIterator<Item = TensorRank3<D, J, K, L>>>(into_iterator: Ii) -> Self {
+    fn from_iter<Ii: IntoIterator<Item = TensorRank3<D, J, K, L, T>>>(into_iterator: Ii) -> Self {
         let mut tensor_rank_4 = Self::zero();
         tensor_rank_4
             .iter_mut()
@@ -269,25 +335,25 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Index<usize>
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Index<usize> for TensorRank4<D, I, J, K, L, T>
 {
-    type Output = TensorRank3<D, J, K, L>;
+    type Output = TensorRank3<D, J, K, L, T>;
     fn index(&self, index: usize) -> &Self::Output {
         &self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> IndexMut<usize>
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    IndexMut<usize> for TensorRank4<D, I, J, K, L, T>
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         &mut self.0[index]
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> std::iter::Sum
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    std::iter::Sum for TensorRank4<D, I, J, K, L, T>
 {
     fn sum<Ii>(iter: Ii) -> Self
     where
@@ -430,6 +496,10 @@ impl<
         &self,
         tensor_rank_2: &TensorRank2<D, J, N>,
     ) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(output) = simd::contract_second_index_with_first_index_of(self, tensor_rank_2) {
+            return output;
+        }
         let mut output = TensorRank4::zero();
         output
             .iter_mut()
@@ -540,6 +610,14 @@ impl<
         tensor_rank_2_a: &TensorRank2<D, I, M>,
         tensor_rank_2_b: &TensorRank2<D, J, N>,
     ) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(output) = simd::contract_first_second_indices_with_second_indices_of(
+            self,
+            tensor_rank_2_a,
+            tensor_rank_2_b,
+        ) {
+            return output;
+        }
         let mut output = TensorRank4::zero();
         output
             .iter_mut()
@@ -564,83 +642,83 @@ impl<
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Div<TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Div<T> for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: T) -> Self::Output {
         self /= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Div<TensorRank0> for &TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Div<T> for &TensorRank4<D, I, J, K, L, T>
 {
-    type Output = TensorRank4<D, I, J, K, L>;
-    fn div(self, tensor_rank_0: TensorRank0) -> Self::Output {
+    type Output = TensorRank4<D, I, J, K, L, T>;
+    fn div(self, tensor_rank_0: T) -> Self::Output {
         self.iter().map(|self_i| self_i / tensor_rank_0).collect()
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Div<&TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Div<&T> for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
-    fn div(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn div(mut self, tensor_rank_0: &T) -> Self::Output {
         self /= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    DivAssign<TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    DivAssign<T> for TensorRank4<D, I, J, K, L, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|self_i| *self_i /= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    DivAssign<&TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    DivAssign<&T> for TensorRank4<D, I, J, K, L, T>
 {
-    fn div_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn div_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|self_i| *self_i /= tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Mul<TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Mul<T> for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: T) -> Self::Output {
         self *= &tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Mul<&TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Mul<&T> for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
-    fn mul(mut self, tensor_rank_0: &TensorRank0) -> Self::Output {
+    fn mul(mut self, tensor_rank_0: &T) -> Self::Output {
         self *= tensor_rank_0;
         self
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    MulAssign<TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    MulAssign<T> for TensorRank4<D, I, J, K, L, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: T) {
         self.iter_mut().for_each(|self_i| *self_i *= &tensor_rank_0);
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    MulAssign<&TensorRank0> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    MulAssign<&T> for TensorRank4<D, I, J, K, L, T>
 {
-    fn mul_assign(&mut self, tensor_rank_0: &TensorRank0) {
+    fn mul_assign(&mut self, tensor_rank_0: &T) {
         self.iter_mut().for_each(|self_i| *self_i *= tensor_rank_0);
     }
 }
@@ -652,10 +730,11 @@ impl<
         const K: usize,
         const L: usize,
         const M: usize,
-    > Mul<TensorRank2<D, L, M>> for TensorRank4<D, I, J, K, L>
+        T: Scalar,
+    > Mul<TensorRank2<D, L, M, T>> for TensorRank4<D, I, J, K, L, T>
 {
-    type Output = TensorRank4<D, I, J, K, M>;
-    fn mul(self, tensor_rank_2: TensorRank2<D, L, M>) -> Self::Output {
+    type Output = TensorRank4<D, I, J, K, M, T>;
+    fn mul(self, tensor_rank_2: TensorRank2<D, L, M, T>) -> Self::Output {
         self.iter()
             .map(|self_i| {
                 self_i
@@ -674,10 +753,11 @@ impl<
         const K: usize,
         const L: usize,
         const M: usize,
-    > Mul<&TensorRank2<D, L, M>> for TensorRank4<D, I, J, K, L>
+        T: Scalar,
+    > Mul<&TensorRank2<D, L, M, T>> for TensorRank4<D, I, J, K, L, T>
 {
-    type Output = TensorRank4<D, I, J, K, M>;
-    fn mul(self, tensor_rank_2: &TensorRank2<D, L, M>) -> Self::Output {
+    type Output = TensorRank4<D, I, J, K, M, T>;
+    fn mul(self, tensor_rank_2: &TensorRank2<D, L, M, T>) -> Self::Output {
         self.iter()
             .map(|self_i| {
                 self_i
@@ -689,8 +769,8 @@ impl<
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Add
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar> Add
+    for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_4: Self) -> Self::Output {
@@ -699,8 +779,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Add<&Self>
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Add<&Self> for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
     fn add(mut self, tensor_rank_4: &Self) -> Self::Output {
@@ -709,18 +789,18 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    Add<TensorRank4<D, I, J, K, L>> for &TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Add<TensorRank4<D, I, J, K, L, T>> for &TensorRank4<D, I, J, K, L, T>
 {
-    type Output = TensorRank4<D, I, J, K, L>;
-    fn add(self, mut tensor_rank_4: TensorRank4<D, I, J, K, L>) -> Self::Output {
+    type Output = TensorRank4<D, I, J, K, L, T>;
+    fn add(self, mut tensor_rank_4: TensorRank4<D, I, J, K, L, T>) -> Self::Output {
         tensor_rank_4 += self;
         tensor_rank_4
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> AddAssign
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    AddAssign for TensorRank4<D, I, J, K, L, T>
 {
     fn add_assign(&mut self, tensor_rank_4: Self) {
         self.iter_mut()
@@ -729,8 +809,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    AddAssign<&Self> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    AddAssign<&Self> for TensorRank4<D, I, J, K, L, T>
 {
     fn add_assign(&mut self, tensor_rank_4: &Self) {
         self.iter_mut()
@@ -739,8 +819,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Sub
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar> Sub
+    for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_4: Self) -> Self::Output {
@@ -749,8 +829,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> Sub<&Self>
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    Sub<&Self> for TensorRank4<D, I, J, K, L, T>
 {
     type Output = Self;
     fn sub(mut self, tensor_rank_4: &Self) -> Self::Output {
@@ -759,8 +839,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize> SubAssign
-    for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    SubAssign for TensorRank4<D, I, J, K, L, T>
 {
     fn sub_assign(&mut self, tensor_rank_4: Self) {
         self.iter_mut()
@@ -769,8 +849,8 @@ impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: us
     }
 }
 
-impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize>
-    SubAssign<&Self> for TensorRank4<D, I, J, K, L>
+impl<const D: usize, const I: usize, const J: usize, const K: usize, const L: usize, T: Scalar>
+    SubAssign<&Self> for TensorRank4<D, I, J, K, L, T>
 {
     fn sub_assign(&mut self, tensor_rank_4: &Self) {
         self.iter_mut()
@@ -0,0 +1,379 @@
+#[cfg(test)]
+mod test;
+
+#[cfg(test)]
+use super::super::test::ErrorTensor;
+
+use std::{
+    array::from_fn,
+    fmt::{Display, Formatter, Result},
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+};
+
+use super::{
+    super::rank_2::{
+        symmetric::{mandel_weight, TensorRank2Symmetric, MANDEL_INDICES},
+        TensorRank2,
+    },
+    Tensor, TensorArray, TensorRank0, TensorRank4,
+};
+
+/// A 3-dimensional tensor of rank 4 with minor symmetries stored in Mandel notation.
+///
+/// `I`, `J` are the configurations of the first and second pairs of indices.
+/// The entries form a 6x6 matrix that maps symmetric rank-2 tensors in Mandel notation.
+#[derive(Debug)]
+pub struct TensorRank4Symmetric<const I: usize, const J: usize>(pub [TensorRank2Symmetric<J>; 6]);
+
+impl<const I: usize, const J: usize> Display for TensorRank4Symmetric<I, J> {
+    fn fmt(&self, _f: &mut Formatter) -> Result {
+        Ok(())
+    }
+}
+
+impl<const I: usize, const J: usize> PartialEq for TensorRank4Symmetric<I, J> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(self_a, other_a)| self_a == other_a)
+    }
+}
+
+#[cfg(test)]
+impl<const I: usize, const J: usize> ErrorTensor for TensorRank4Symmetric<I, J> {
+    fn error(
+        &self,
+        comparator: &Self,
+        tol_abs: &TensorRank0,
+        tol_rel: &TensorRank0,
+    ) -> Option<usize> {
+        let error_count = self
+            .iter()
+            .zip(comparator.iter())
+            .map(|(self_a, comparator_a)| {
+                self_a
+                    .iter()
+                    .zip(comparator_a.iter())
+                    .filter(|(&self_ab, &comparator_ab)| {
+                        &(self_ab - comparator_ab).abs() >= tol_abs
+                            && &(self_ab / comparator_ab - 1.0).abs() >= tol_rel
+                    })
+                    .count()
+            })
+            .sum();
+        if error_count > 0 {
+            Some(error_count)
+        } else {
+            None
+        }
+    }
+    fn error_fd(&self, comparator: &Self, epsilon: &TensorRank0) -> Option<(bool, usize)> {
+        let error_count = self
+            .iter()
+            .zip(comparator.iter())
+            .map(|(self_a, comparator_a)| {
+                self_a
+                    .iter()
+                    .zip(comparator_a.iter())
+                    .filter(|(&self_ab, &comparator_ab)| {
+                        &(self_ab / comparator_ab - 1.0).abs() >= epsilon
+                            && (&self_ab.abs() >= epsilon || &comparator_ab.abs() >= epsilon)
+                    })
+                    .count()
+            })
+            .sum();
+        if error_count > 0 {
+            Some((true, error_count))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const I: usize, const J: usize> TensorRank4Symmetric<I, J> {
+    /// Returns the rank-4 tensor constructed from a dyad of the given symmetric rank-2 tensors.
+    pub fn dyad(
+        tensor_rank_2_symmetric_a: &TensorRank2Symmetric<I>,
+        tensor_rank_2_symmetric_b: &TensorRank2Symmetric<J>,
+    ) -> Self {
+        tensor_rank_2_symmetric_a
+            .iter()
+            .map(|tensor_rank_2_symmetric_a_a| {
+                tensor_rank_2_symmetric_b * *tensor_rank_2_symmetric_a_a
+            })
+            .collect()
+    }
+    /// Returns the inverse of the rank-4 tensor on the space of symmetric rank-2 tensors.
+    pub fn inverse(&self) -> TensorRank4Symmetric<J, I> {
+        TensorRank2::<6, I, J>::new(self.as_array())
+            .inverse()
+            .iter()
+            .map(|inverse_a| inverse_a.iter().copied().collect())
+            .collect()
+    }
+}
+
+impl<const I: usize, const J: usize> Tensor for TensorRank4Symmetric<I, J> {
+    type Item = TensorRank2Symmetric<J>;
+    fn copy(&self) -> Self {
+        self.iter().map(|entry| entry.copy()).collect()
+    }
+    fn flat_index(&self, indices: &[usize]) -> usize {
+        6 * indices[0] + indices[1]
+    }
+    fn get_at(&self, indices: &[usize]) -> &TensorRank0 {
+        &self[indices[0]][indices[1]]
+    }
+    fn get_at_mut(&mut self, indices: &[usize]) -> &mut TensorRank0 {
+        &mut self[indices[0]][indices[1]]
+    }
+    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
+        self.0.iter()
+    }
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Self::Item> {
+        self.0.iter_mut()
+    }
+}
+
+impl<const I: usize, const J: usize> TensorArray for TensorRank4Symmetric<I, J> {
+    type Array = [[TensorRank0; 6]; 6];
+    type Item = TensorRank2Symmetric<J>;
+    fn as_array(&self) -> Self::Array {
+        from_fn(|a| self[a].as_array())
+    }
+    fn identity() -> Self {
+        Self(from_fn(|a| {
+            TensorRank2Symmetric::new(from_fn(|b| ((a == b) as u8) as TensorRank0))
+        }))
+    }
+    fn new(array: Self::Array) -> Self {
+        array.into_iter().map(TensorRank2Symmetric::new).collect()
+    }
+    fn zero() -> Self {
+        Self(from_fn(|_| TensorRank2Symmetric::zero()))
+    }
+}
+
+impl<const I: usize, const J: usize> From<&TensorRank4<3, I, I, J, J>>
+    for TensorRank4Symmetric<I, J>
+{
+    fn from(tensor_rank_4: &TensorRank4<3, I, I, J, J>) -> Self {
+        MANDEL_INDICES
+            .iter()
+            .enumerate()
+            .map(|(a, [i, j])| {
+                MANDEL_INDICES
+                    .iter()
+                    .enumerate()
+                    .map(|(b, [k, l])| {
+                        0.25 * mandel_weight(a)
+                            * mandel_weight(b)
+                            * (tensor_rank_4[*i][*j][*k][*l]
+                                + tensor_rank_4[*j][*i][*k][*l]
+                                + tensor_rank_4[*i][*j][*l][*k]
+                                + tensor_rank_4[*j][*i][*l][*k])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<const I: usize, const J: usize> From<&TensorRank4Symmetric<I, J>>
+    for TensorRank4<3, I, I, J, J>
+{
+    fn from(tensor_rank_4_symmetric: &TensorRank4Symmetric<I, J>) -> Self {
+        let mut tensor_rank_4 = Self::zero();
+        MANDEL_INDICES
+            .iter()
+            .zip(tensor_rank_4_symmetric.iter())
+            .enumerate()
+            .for_each(|(a, ([i, j], tensor_rank_4_symmetric_a))| {
+                MANDEL_INDICES
+                    .iter()
+                    .zip(tensor_rank_4_symmetric_a.iter())
+                    .enumerate()
+                    .for_each(|(b, ([k, l], entry))| {
+                        let value = entry / mandel_weight(a) / mandel_weight(b);
+                        tensor_rank_4[*i][*j][*k][*l] = value;
+                        tensor_rank_4[*j][*i][*k][*l] = value;
+                        tensor_rank_4[*i][*j][*l][*k] = value;
+                        tensor_rank_4[*j][*i][*l][*k] = value;
+                    })
+            });
+        tensor_rank_4
+    }
+}
+
+impl<const I: usize, const J: usize> FromIterator<TensorRank2Symmetric<J>>
+    for TensorRank4Symmetric<I, J>
+{
+    fn from_iter<Ii: IntoIterator<Item = TensorRank2Symmetric<J>>>(into_iterator: Ii) -> Self {
+        let mut tensor_rank_4_symmetric = Self::zero();
+        tensor_rank_4_symmetric
+            .iter_mut()
+            .zip(into_iterator)
+            .for_each(|(tensor_rank_4_symmetric_a, value_a)| *tensor_rank_4_symmetric_a = value_a);
+        tensor_rank_4_symmetric
+    }
+}
+
+impl<const I: usize, const J: usize> Index<usize> for TensorRank4Symmetric<I, J> {
+    type Output = TensorRank2Symmetric<J>;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const I: usize, const J: usize> IndexMut<usize> for TensorRank4Symmetric<I, J> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const I: usize, const J: usize> std::iter::Sum for TensorRank4Symmetric<I, J> {
+    fn sum<Ii>(iter: Ii) -> Self
+    where
+        Ii: Iterator<Item = Self>,
+    {
+        let mut output = Self::zero();
+        iter.for_each(|item| output += item);
+        output
+    }
+}
+
+impl<const I: usize, const J: usize> Div<TensorRank0> for TensorRank4Symmetric<I, J> {
+    type Output = Self;
+    fn div(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+        self /= tensor_rank_0;
+        self
+    }
+}
+
+impl<const I: usize, const J: usize> DivAssign<TensorRank0> for TensorRank4Symmetric<I, J> {
+    fn div_assign(&mut self, tensor_rank_0: TensorRank0) {
+        self.iter_mut().for_each(|self_a| *self_a /= tensor_rank_0);
+    }
+}
+
+impl<const I: usize, const J: usize> Mul<TensorRank0> for TensorRank4Symmetric<I, J> {
+    type Output = Self;
+    fn mul(mut self, tensor_rank_0: TensorRank0) -> Self::Output {
+        self *= tensor_rank_0;
+        self
+    }
+}
+
+impl<const I: usize, const J: usize> MulAssign<TensorRank0> for TensorRank4Symmetric<I, J> {
+    fn mul_assign(&mut self, tensor_rank_0: TensorRank0) {
+        self.iter_mut().for_each(|self_a| *self_a *= tensor_rank_0);
+    }
+}
+
+impl<const I: usize, const J: usize> Mul<&TensorRank2Symmetric<J>> for &TensorRank4Symmetric<I, J> {
+    type Output = TensorRank2Symmetric<I>;
+    fn mul(self, tensor_rank_2_symmetric: &TensorRank2Symmetric<J>) -> Self::Output {
+        self.iter()
+            .map(|self_a| self_a.full_contraction(tensor_rank_2_symmetric))
+            .collect()
+    }
+}
+
+impl<const I: usize, const J: usize> Mul<TensorRank2Symmetric<J>> for TensorRank4Symmetric<I, J> {
+    type Output = TensorRank2Symmetric<I>;
+    fn mul(self, tensor_rank_2_symmetric: TensorRank2Symmetric<J>) -> Self::Output {
+        &self * &tensor_rank_2_symmetric
+    }
+}
+
+impl<const I: usize, const J: usize, const K: usize> Mul<&TensorRank4Symmetric<J, K>>
+    for &TensorRank4Symmetric<I, J>
+{
+    type Output = TensorRank4Symmetric<I, K>;
+    fn mul(self, tensor_rank_4_symmetric: &TensorRank4Symmetric<J, K>) -> Self::Output {
+        self.iter()
+            .map(|self_a| {
+                self_a
+                    .iter()
+                    .zip(tensor_rank_4_symmetric.iter())
+                    .map(|(self_ab, tensor_rank_4_symmetric_b)| {
+                        tensor_rank_4_symmetric_b * *self_ab
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+impl<const I: usize, const J: usize, const K: usize> Mul<TensorRank4Symmetric<J, K>>
+    for TensorRank4Symmetric<I, J>
+{
+    type Output = TensorRank4Symmetric<I, K>;
+    fn mul(self, tensor_rank_4_symmetric: TensorRank4Symmetric<J, K>) -> Self::Output {
+        &self * &tensor_rank_4_symmetric
+    }
+}
+
+impl<const I: usize, const J: usize> Add for TensorRank4Symmetric<I, J> {
+    type Output = Self;
+    fn add(mut self, tensor_rank_4_symmetric: Self) -> Self::Output {
+        self += tensor_rank_4_symmetric;
+        self
+    }
+}
+
+impl<const I: usize, const J: usize> Add<&Self> for TensorRank4Symmetric<I, J> {
+    type Output = Self;
+    fn add(mut self, tensor_rank_4_symmetric: &Self) -> Self::Output {
+        self += tensor_rank_4_symmetric;
+        self
+    }
+}
+
+impl<const I: usize, const J: usize> AddAssign for TensorRank4Symmetric<I, J> {
+    fn add_assign(&mut self, tensor_rank_4_symmetric: Self) {
+        self.iter_mut()
+            .zip(tensor_rank_4_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_4_symmetric_a)| *self_a += tensor_rank_4_symmetric_a);
+    }
+}
+
+impl<const I: usize, const J: usize> AddAssign<&Self> for TensorRank4Symmetric<I, J> {
+    fn add_assign(&mut self, tensor_rank_4_symmetric: &Self) {
+        self.iter_mut()
+            .zip(tensor_rank_4_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_4_symmetric_a)| *self_a += tensor_rank_4_symmetric_a);
+    }
+}
+
+impl<const I: usize, const J: usize> Sub for TensorRank4Symmetric<I, J> {
+    type Output = Self;
+    fn sub(mut self, tensor_rank_4_symmetric: Self) -> Self::Output {
+        self -= tensor_rank_4_symmetric;
+        self
+    }
+}
+
+impl<const I: usize, const J: usize> Sub<&Self> for TensorRank4Symmetric<I, J> {
+    type Output = Self;
+    fn sub(mut self, tensor_rank_4_symmetric: &Self) -> Self::Output {
+        self -= tensor_rank_4_symmetric;
+        self
+    }
+}
+
+impl<const I: usize, const J: usize> SubAssign for TensorRank4Symmetric<I, J> {
+    fn sub_assign(&mut self, tensor_rank_4_symmetric: Self) {
+        self.iter_mut()
+            .zip(tensor_rank_4_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_4_symmetric_a)| *self_a -= tensor_rank_4_symmetric_a);
+    }
+}
+
+impl<const I: usize, const J: usize> SubAssign<&Self> for TensorRank4Symmetric<I, J> {
+    fn sub_assign(&mut self, tensor_rank_4_symmetric: &Self) {
+        self.iter_mut()
+            .zip(tensor_rank_4_symmetric.iter())
+            .for_each(|(self_a, tensor_rank_4_symmetric_a)| *self_a -= tensor_rank_4_symmetric_a);
+    }
+}
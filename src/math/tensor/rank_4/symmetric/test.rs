@@ -0,0 +1,91 @@
+use super::{
+    super::{
+        super::test::{assert_eq_within_tols, TestError},
+        ContractThirdFourthIndicesWithFirstSecondIndicesOf,
+    },
+    Tensor, TensorArray, TensorRank2, TensorRank2Symmetric, TensorRank4, TensorRank4Symmetric,
+};
+
+fn get_tensor_rank_2() -> TensorRank2<3, 1, 1> {
+    TensorRank2::new([[1.0, 4.0, 6.0], [4.0, 2.0, 5.0], [6.0, 5.0, 3.0]])
+}
+
+fn get_tensor_rank_4() -> TensorRank4<3, 1, 1, 1, 1> {
+    let identity = TensorRank2::<3, 1, 1>::identity();
+    TensorRank4::dyad_ij_kl(&identity, &identity) * 2.0
+        + (TensorRank4::dyad_ik_jl(&identity, &identity)
+            + TensorRank4::dyad_il_jk(&identity, &identity))
+            * 1.5
+}
+
+#[test]
+fn dyad() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let tensor_rank_2_symmetric = TensorRank2Symmetric::from(&tensor_rank_2);
+    assert_eq_within_tols(
+        &TensorRank4::from(&TensorRank4Symmetric::dyad(
+            &tensor_rank_2_symmetric,
+            &tensor_rank_2_symmetric,
+        )),
+        &TensorRank4::dyad_ij_kl(&tensor_rank_2, &tensor_rank_2),
+    )
+}
+
+#[test]
+fn from_tensor_rank_4() -> Result<(), TestError> {
+    let tensor_rank_4 = get_tensor_rank_4();
+    assert_eq_within_tols(
+        &TensorRank4::from(&TensorRank4Symmetric::from(&tensor_rank_4)),
+        &tensor_rank_4,
+    )
+}
+
+#[test]
+fn identity() -> Result<(), TestError> {
+    let tensor_rank_2_symmetric = TensorRank2Symmetric::<1>::from(&get_tensor_rank_2());
+    assert_eq_within_tols(
+        &(&TensorRank4Symmetric::<1, 1>::identity() * &tensor_rank_2_symmetric),
+        &tensor_rank_2_symmetric,
+    )
+}
+
+#[test]
+fn inverse() -> Result<(), TestError> {
+    let tensor_rank_4_symmetric = TensorRank4Symmetric::from(&get_tensor_rank_4());
+    assert_eq_within_tols(
+        &(&tensor_rank_4_symmetric * &tensor_rank_4_symmetric.inverse()),
+        &TensorRank4Symmetric::identity(),
+    )
+}
+
+#[test]
+fn mul_tensor_rank_2_symmetric() -> Result<(), TestError> {
+    let tensor_rank_2 = get_tensor_rank_2();
+    let tensor_rank_4 = get_tensor_rank_4();
+    assert_eq_within_tols(
+        &TensorRank2::from(
+            &(TensorRank4Symmetric::from(&tensor_rank_4)
+                * TensorRank2Symmetric::from(&tensor_rank_2)),
+        ),
+        &tensor_rank_4.contract_third_fourth_indices_with_first_second_indices_of(&tensor_rank_2),
+    )
+}
+
+#[test]
+fn mul_tensor_rank_4_symmetric() -> Result<(), TestError> {
+    let tensor_rank_4_symmetric = TensorRank4Symmetric::<1, 1>::from(&get_tensor_rank_4());
+    let product = &tensor_rank_4_symmetric * &tensor_rank_4_symmetric;
+    let tensor_rank_2_symmetric = TensorRank2Symmetric::from(&get_tensor_rank_2());
+    assert_eq_within_tols(
+        &(&product * &tensor_rank_2_symmetric),
+        &(&tensor_rank_4_symmetric * &(&tensor_rank_4_symmetric * &tensor_rank_2_symmetric)),
+    )
+}
+
+#[test]
+fn norm() -> Result<(), TestError> {
+    assert_eq_within_tols(
+        &TensorRank4Symmetric::from(&get_tensor_rank_4()).norm(),
+        &get_tensor_rank_4().norm(),
+    )
+}
@@ -1,5 +1,5 @@
 use super::{
-    super::{test::ErrorTensor, Tensor, TensorArray},
+    super::{test::ErrorTensor, Hessian, Tensor, TensorArray},
     ContractAllIndicesWithFirstIndicesOf, ContractFirstSecondIndicesWithSecondIndicesOf,
     ContractFirstThirdFourthIndicesWithFirstIndicesOf,
     ContractSecondFourthIndicesWithFirstIndicesOf, ContractSecondIndexWithFirstIndexOf,
@@ -854,6 +854,44 @@ fn dyad_il_kj() {
         });
 }
 
+#[test]
+fn eliminate() {
+    let mut tensor_rank_4 = get_tensor_rank_4();
+    tensor_rank_4.eliminate(&[5]);
+    tensor_rank_4
+        .iter()
+        .zip(get_array().iter())
+        .enumerate()
+        .for_each(|(i, (tensor_rank_4_i, array_i))| {
+            tensor_rank_4_i
+                .iter()
+                .zip(array_i.iter())
+                .enumerate()
+                .for_each(|(j, (tensor_rank_4_ij, array_ij))| {
+                    tensor_rank_4_ij
+                        .iter()
+                        .zip(array_ij.iter())
+                        .enumerate()
+                        .for_each(|(k, (tensor_rank_4_ijk, array_ijk))| {
+                            tensor_rank_4_ijk
+                                .iter()
+                                .zip(array_ijk.iter())
+                                .enumerate()
+                                .for_each(|(l, (tensor_rank_4_ijkl, array_ijkl))| {
+                                    if (i, j) == (1, 2) || (k, l) == (1, 2) {
+                                        assert_eq!(
+                                            tensor_rank_4_ijkl,
+                                            &(((i, j, k, l) == (1, 2, 1, 2)) as u8 as TensorRank0)
+                                        )
+                                    } else {
+                                        assert_eq!(tensor_rank_4_ijkl, array_ijkl)
+                                    }
+                                })
+                        })
+                })
+        });
+}
+
 #[test]
 fn error() {
     let a = get_tensor_rank_4();
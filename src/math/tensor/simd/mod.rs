@@ -0,0 +1,403 @@
+#[cfg(test)]
+mod test;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+use std::arch::x86_64::*;
+use std::{
+    any::Any,
+    ops::{Add, Mul},
+};
+
+use super::{
+    rank_0::{Scalar, TensorRank0},
+    rank_2::TensorRank2,
+    rank_4::TensorRank4,
+    TensorArray,
+};
+
+/// Four lanes of `f64` held in packed registers.
+///
+/// An AVX register is used when the target enables AVX (for example with `-C target-cpu=native`),
+/// and otherwise a plain array that the compiler lowers to whatever packed instructions the target has.
+/// Rows of 3-dimensional tensors are padded with a zero fourth lane.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+#[derive(Clone, Copy)]
+struct F64x4(__m256d);
+
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx")))]
+#[derive(Clone, Copy)]
+struct F64x4([TensorRank0; 4]);
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+impl F64x4 {
+    #[inline(always)]
+    fn load(row: &[TensorRank0; 3]) -> Self {
+        //
+        // The lower half reads the first two entries of the row and the upper half reads only the third.
+        //
+        unsafe {
+            Self(_mm256_insertf128_pd(
+                _mm256_castpd128_pd256(_mm_loadu_pd(row.as_ptr())),
+                _mm_load_sd(&row[2]),
+                1,
+            ))
+        }
+    }
+    #[inline(always)]
+    fn new(lanes: [TensorRank0; 4]) -> Self {
+        Self(unsafe { _mm256_loadu_pd(lanes.as_ptr()) })
+    }
+    #[inline(always)]
+    fn splat(value: TensorRank0) -> Self {
+        Self(unsafe { _mm256_set1_pd(value) })
+    }
+    /// Stores all four lanes, where `entries` must be valid for four writes.
+    #[inline(always)]
+    unsafe fn store_unaligned(self, entries: *mut TensorRank0) {
+        unsafe { _mm256_storeu_pd(entries, self.0) }
+    }
+    #[inline(always)]
+    fn store(self, row: &mut [TensorRank0; 3]) {
+        unsafe {
+            _mm_storeu_pd(row.as_mut_ptr(), _mm256_castpd256_pd128(self.0));
+            _mm_store_sd(&mut row[2], _mm256_extractf128_pd(self.0, 1))
+        }
+    }
+    #[inline(always)]
+    fn add(self, f64x4: Self) -> Self {
+        Self(unsafe { _mm256_add_pd(self.0, f64x4.0) })
+    }
+    #[inline(always)]
+    fn mul(self, f64x4: Self) -> Self {
+        Self(unsafe { _mm256_mul_pd(self.0, f64x4.0) })
+    }
+    #[cfg(target_feature = "fma")]
+    #[inline(always)]
+    fn mul_add(self, f64x4_a: Self, f64x4_b: Self) -> Self {
+        Self(unsafe { _mm256_fmadd_pd(self.0, f64x4_a.0, f64x4_b.0) })
+    }
+    #[cfg(not(target_feature = "fma"))]
+    #[inline(always)]
+    fn mul_add(self, f64x4_a: Self, f64x4_b: Self) -> Self {
+        self.mul(f64x4_a).add(f64x4_b)
+    }
+}
+
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx")))]
+impl F64x4 {
+    #[inline(always)]
+    fn load(row: &[TensorRank0; 3]) -> Self {
+        Self([row[0], row[1], row[2], 0.0])
+    }
+    #[inline(always)]
+    fn new(lanes: [TensorRank0; 4]) -> Self {
+        Self(lanes)
+    }
+    #[inline(always)]
+    fn splat(value: TensorRank0) -> Self {
+        Self([value; 4])
+    }
+    /// Stores all four lanes, where `entries` must be valid for four writes.
+    #[inline(always)]
+    unsafe fn store_unaligned(self, entries: *mut TensorRank0) {
+        unsafe { entries.cast::<[TensorRank0; 4]>().write_unaligned(self.0) }
+    }
+    #[inline(always)]
+    fn store(self, row: &mut [TensorRank0; 3]) {
+        row.copy_from_slice(&self.0[..3])
+    }
+    #[inline(always)]
+    fn add(self, f64x4: Self) -> Self {
+        Self(std::array::from_fn(|lane| self.0[lane] + f64x4.0[lane]))
+    }
+    #[inline(always)]
+    fn mul(self, f64x4: Self) -> Self {
+        Self(std::array::from_fn(|lane| self.0[lane] * f64x4.0[lane]))
+    }
+    #[inline(always)]
+    fn mul_add(self, f64x4_a: Self, f64x4_b: Self) -> Self {
+        self.mul(f64x4_a).add(f64x4_b)
+    }
+}
+
+impl Add for F64x4 {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, f64x4: Self) -> Self::Output {
+        F64x4::add(self, f64x4)
+    }
+}
+
+impl Mul for F64x4 {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, f64x4: Self) -> Self::Output {
+        F64x4::mul(self, f64x4)
+    }
+}
+
+/// The nine entries of a 3-dimensional rank-2 tensor in row-major order, packed into two full registers and one trailing entry.
+#[derive(Clone, Copy)]
+struct Block(F64x4, F64x4, TensorRank0);
+
+impl Block {
+    #[inline(always)]
+    fn new<const I: usize, const J: usize>(tensor_rank_2: &TensorRank2<3, I, J>) -> Self {
+        let [[a_00, a_01, a_02], [a_10, a_11, a_12], [a_20, a_21, a_22]] =
+            tensor_rank_2.0.each_ref().map(|row| row.0);
+        Self(
+            F64x4::new([a_00, a_01, a_02, a_10]),
+            F64x4::new([a_11, a_12, a_20, a_21]),
+            a_22,
+        )
+    }
+    /// Returns the block with entries $`a_{ij}=v_i`$.
+    #[inline(always)]
+    fn repeat_columns(&[v_0, v_1, v_2]: &[TensorRank0; 3]) -> Self {
+        Self(
+            F64x4::new([v_0, v_0, v_0, v_1]),
+            F64x4::new([v_1, v_1, v_2, v_2]),
+            v_2,
+        )
+    }
+    /// Returns the block with entries $`a_{ij}=v_j`$.
+    #[inline(always)]
+    fn repeat_rows(&[v_0, v_1, v_2]: &[TensorRank0; 3]) -> Self {
+        Self(
+            F64x4::new([v_0, v_1, v_2, v_0]),
+            F64x4::new([v_1, v_2, v_0, v_1]),
+            v_2,
+        )
+    }
+    #[inline(always)]
+    fn splat(value: TensorRank0) -> Self {
+        Self(F64x4::splat(value), F64x4::splat(value), value)
+    }
+    #[inline(always)]
+    fn store<const I: usize, const J: usize>(self, tensor_rank_2: &mut TensorRank2<3, I, J>) {
+        //
+        // Rank-2 and rank-1 tensors are transparent wrappers, so the nine entries are contiguous.
+        //
+        let entries = (tensor_rank_2 as *mut TensorRank2<3, I, J>).cast::<TensorRank0>();
+        unsafe {
+            self.0.store_unaligned(entries);
+            self.1.store_unaligned(entries.add(4));
+            *entries.add(8) = self.2
+        }
+    }
+}
+
+impl Mul for Block {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, block: Self) -> Self::Output {
+        Self(self.0 * block.0, self.1 * block.1, self.2 * block.2)
+    }
+}
+
+/// Returns the argument as the given type if they are the same type.
+///
+/// Comparisons of type identifiers are resolved during monomorphization, so this costs nothing.
+#[inline(always)]
+fn cast<S: 'static, U: 'static>(value: &S) -> Option<&U> {
+    (value as &dyn Any).downcast_ref()
+}
+
+#[inline(always)]
+fn cast_mut<S: 'static, U: 'static>(value: &mut S) -> Option<&mut U> {
+    (value as &mut dyn Any).downcast_mut()
+}
+
+#[inline(always)]
+fn rows<const I: usize, const J: usize>(tensor_rank_2: &TensorRank2<3, I, J>) -> [F64x4; 3] {
+    [
+        F64x4::load(&tensor_rank_2[0].0),
+        F64x4::load(&tensor_rank_2[1].0),
+        F64x4::load(&tensor_rank_2[2].0),
+    ]
+}
+
+/// Computes the product of two rank-2 tensors for *d* = 3 and `f64` entries.
+pub fn tensor_rank_2_mul_tensor_rank_2<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    T: Scalar,
+>(
+    tensor_rank_2_a: &TensorRank2<D, I, J, T>,
+    tensor_rank_2_b: &TensorRank2<D, J, K, T>,
+) -> Option<TensorRank2<D, I, K, T>> {
+    let a = cast::<_, TensorRank2<3, I, J>>(tensor_rank_2_a)?;
+    let b = rows(cast::<_, TensorRank2<3, J, K>>(tensor_rank_2_b)?);
+    let mut output = TensorRank2::zero();
+    let c = cast_mut::<_, TensorRank2<3, I, K>>(&mut output)?;
+    c.0.iter_mut().zip(a.0.iter()).for_each(|(c_i, a_i)| {
+        (F64x4::splat(a_i[0]) * b[0] + F64x4::splat(a_i[1]) * b[1] + F64x4::splat(a_i[2]) * b[2])
+            .store(&mut c_i.0)
+    });
+    Some(output)
+}
+
+/// Computes the dyadic product with indices (*ij*, *kl*) for *d* = 3 and `f64` entries.
+pub fn dyad_ij_kl<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    const L: usize,
+    T: Scalar,
+>(
+    tensor_rank_2_a: &TensorRank2<D, I, J, T>,
+    tensor_rank_2_b: &TensorRank2<D, K, L, T>,
+) -> Option<TensorRank4<D, I, J, K, L, T>> {
+    let a = cast::<_, TensorRank2<3, I, J>>(tensor_rank_2_a)?;
+    let b = Block::new(cast::<_, TensorRank2<3, K, L>>(tensor_rank_2_b)?);
+    let mut output = TensorRank4::zero();
+    let c = cast_mut::<_, TensorRank4<3, I, J, K, L>>(&mut output)?;
+    c.0.iter_mut().zip(a.0.iter()).for_each(|(c_i, a_i)| {
+        c_i.0
+            .iter_mut()
+            .zip(a_i.0.iter())
+            .for_each(|(c_ij, a_ij)| (Block::splat(*a_ij) * b).store(c_ij))
+    });
+    Some(output)
+}
+
+/// Computes the dyadic product with indices (*ik*, *jl*) for *d* = 3 and `f64` entries.
+pub fn dyad_ik_jl<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    const L: usize,
+    T: Scalar,
+>(
+    tensor_rank_2_a: &TensorRank2<D, I, K, T>,
+    tensor_rank_2_b: &TensorRank2<D, J, L, T>,
+) -> Option<TensorRank4<D, I, J, K, L, T>> {
+    let a = cast::<_, TensorRank2<3, I, K>>(tensor_rank_2_a)?;
+    let b = cast::<_, TensorRank2<3, J, L>>(tensor_rank_2_b)?;
+    let b_j: [Block; 3] = std::array::from_fn(|j| Block::repeat_rows(&b[j].0));
+    let mut output = TensorRank4::zero();
+    let c = cast_mut::<_, TensorRank4<3, I, J, K, L>>(&mut output)?;
+    c.0.iter_mut().zip(a.0.iter()).for_each(|(c_i, a_i)| {
+        let a_i = Block::repeat_columns(&a_i.0);
+        c_i.0
+            .iter_mut()
+            .zip(b_j.iter())
+            .for_each(|(c_ij, b_j)| (a_i * *b_j).store(c_ij))
+    });
+    Some(output)
+}
+
+/// Computes the dyadic product with indices (*il*, *jk*) for *d* = 3 and `f64` entries.
+pub fn dyad_il_jk<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    const L: usize,
+    T: Scalar,
+>(
+    tensor_rank_2_a: &TensorRank2<D, I, L, T>,
+    tensor_rank_2_b: &TensorRank2<D, J, K, T>,
+) -> Option<TensorRank4<D, I, J, K, L, T>> {
+    let a = cast::<_, TensorRank2<3, I, L>>(tensor_rank_2_a)?;
+    let b = cast::<_, TensorRank2<3, J, K>>(tensor_rank_2_b)?;
+    let b_j: [Block; 3] = std::array::from_fn(|j| Block::repeat_columns(&b[j].0));
+    let mut output = TensorRank4::zero();
+    let c = cast_mut::<_, TensorRank4<3, I, J, K, L>>(&mut output)?;
+    c.0.iter_mut().zip(a.0.iter()).for_each(|(c_i, a_i)| {
+        let a_i = Block::repeat_rows(&a_i.0);
+        c_i.0
+            .iter_mut()
+            .zip(b_j.iter())
+            .for_each(|(c_ij, b_j)| (a_i * *b_j).store(c_ij))
+    });
+    Some(output)
+}
+
+/// Contracts the first and second indices of a rank-4 tensor with the second indices of two rank-2 tensors for *d* = 3.
+pub fn contract_first_second_indices_with_second_indices_of<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    const L: usize,
+    const M: usize,
+    const N: usize,
+>(
+    tensor_rank_4: &TensorRank4<D, M, N, K, L>,
+    tensor_rank_2_a: &TensorRank2<D, I, M>,
+    tensor_rank_2_b: &TensorRank2<D, J, N>,
+) -> Option<TensorRank4<D, I, J, K, L>> {
+    //
+    // The contraction is done in two passes, first over the second index and then over the first index,
+    // which takes 162 rather than 243 packed multiply-adds.
+    //
+    let s = cast::<_, TensorRank4<3, M, N, K, L>>(tensor_rank_4)?;
+    let a = cast::<_, TensorRank2<3, I, M>>(tensor_rank_2_a)?;
+    let b = cast::<_, TensorRank2<3, J, N>>(tensor_rank_2_b)?;
+    let s_mnk: [[[F64x4; 3]; 3]; 3] = std::array::from_fn(|m| {
+        std::array::from_fn(|n| std::array::from_fn(|k| F64x4::load(&s[m][n][k].0)))
+    });
+    let t_mjk: [[[F64x4; 3]; 3]; 3] = std::array::from_fn(|m| {
+        std::array::from_fn(|j| {
+            let b_j = b[j].0.map(F64x4::splat);
+            std::array::from_fn(|k| {
+                s_mnk[m][0][k].mul_add(
+                    b_j[0],
+                    s_mnk[m][1][k].mul_add(b_j[1], s_mnk[m][2][k] * b_j[2]),
+                )
+            })
+        })
+    });
+    let mut output = TensorRank4::zero();
+    let c = cast_mut::<_, TensorRank4<3, I, J, K, L>>(&mut output)?;
+    c.0.iter_mut().zip(a.0.iter()).for_each(|(c_i, a_i)| {
+        let a_i = a_i.0.map(F64x4::splat);
+        c_i.0.iter_mut().enumerate().for_each(|(j, c_ij)| {
+            c_ij.0.iter_mut().enumerate().for_each(|(k, c_ijk)| {
+                t_mjk[0][j][k]
+                    .mul_add(
+                        a_i[0],
+                        t_mjk[1][j][k].mul_add(a_i[1], t_mjk[2][j][k] * a_i[2]),
+                    )
+                    .store(&mut c_ijk.0)
+            })
+        })
+    });
+    Some(output)
+}
+
+/// Contracts the second index of a rank-4 tensor with the first index of a rank-2 tensor for *d* = 3.
+pub fn contract_second_index_with_first_index_of<
+    const D: usize,
+    const I: usize,
+    const J: usize,
+    const K: usize,
+    const L: usize,
+    const N: usize,
+>(
+    tensor_rank_4: &TensorRank4<D, I, J, K, L>,
+    tensor_rank_2: &TensorRank2<D, J, N>,
+) -> Option<TensorRank4<D, I, N, K, L>> {
+    let s = cast::<_, TensorRank4<3, I, J, K, L>>(tensor_rank_4)?;
+    let t = cast::<_, TensorRank2<3, J, N>>(tensor_rank_2)?;
+    let mut output = TensorRank4::zero();
+    let c = cast_mut::<_, TensorRank4<3, I, N, K, L>>(&mut output)?;
+    c.0.iter_mut().zip(s.0.iter()).for_each(|(c_i, s_i)| {
+        let s_isk: [[F64x4; 3]; 3] =
+            std::array::from_fn(|j| std::array::from_fn(|k| F64x4::load(&s_i[j][k].0)));
+        c_i.0.iter_mut().enumerate().for_each(|(n, c_in)| {
+            c_in.0.iter_mut().enumerate().for_each(|(k, c_ink)| {
+                (s_isk[0][k] * F64x4::splat(t[0][n])
+                    + s_isk[1][k] * F64x4::splat(t[1][n])
+                    + s_isk[2][k] * F64x4::splat(t[2][n]))
+                .store(&mut c_ink.0)
+            })
+        })
+    });
+    Some(output)
+}
@@ -0,0 +1,110 @@
+use super::{
+    super::test::{assert_eq_within_tols, TestError},
+    contract_first_second_indices_with_second_indices_of,
+    contract_second_index_with_first_index_of, dyad_ij_kl, dyad_ik_jl, dyad_il_jk,
+    tensor_rank_2_mul_tensor_rank_2, TensorArray, TensorRank2, TensorRank4,
+};
+
+fn get_tensor_rank_2() -> TensorRank2<3, 1, 1> {
+    TensorRank2::new([
+        [1.31, -0.27, 0.58],
+        [0.44, 2.07, -1.13],
+        [-0.92, 0.36, 1.75],
+    ])
+}
+
+fn get_other_tensor_rank_2() -> TensorRank2<3, 1, 1> {
+    TensorRank2::new([
+        [0.83, 1.62, -0.41],
+        [-1.27, 0.19, 0.96],
+        [0.55, -0.74, 2.38],
+    ])
+}
+
+fn get_tensor_rank_4() -> TensorRank4<3, 1, 1, 1, 1> {
+    TensorRank4::new(std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            std::array::from_fn(|k| {
+                std::array::from_fn(|l| ((27 * i + 9 * j + 3 * k + l) as f64 * 0.37).sin())
+            })
+        })
+    }))
+}
+
+fn reference_tensor_rank_4(
+    entry: impl Fn(usize, usize, usize, usize) -> f64,
+) -> TensorRank4<3, 1, 1, 1, 1> {
+    TensorRank4::new(std::array::from_fn(|i| {
+        std::array::from_fn(|j| std::array::from_fn(|k| std::array::from_fn(|l| entry(i, j, k, l))))
+    }))
+}
+
+#[test]
+fn contract_first_second_indices_with_second_indices_of_tensor_rank_2() -> Result<(), TestError> {
+    let a = get_tensor_rank_2();
+    let b = get_other_tensor_rank_2();
+    let s = get_tensor_rank_4();
+    assert_eq_within_tols(
+        &contract_first_second_indices_with_second_indices_of(&s, &a, &b).unwrap(),
+        &reference_tensor_rank_4(|i, j, k, l| {
+            (0..3)
+                .flat_map(|m| (0..3).map(move |n| (m, n)))
+                .map(|(m, n)| s[m][n][k][l] * a[i][m] * b[j][n])
+                .sum()
+        }),
+    )
+}
+
+#[test]
+fn contract_second_index_with_first_index_of_tensor_rank_2() -> Result<(), TestError> {
+    let a = get_tensor_rank_2();
+    let s = get_tensor_rank_4();
+    assert_eq_within_tols(
+        &contract_second_index_with_first_index_of(&s, &a).unwrap(),
+        &reference_tensor_rank_4(|i, j, k, l| (0..3).map(|m| s[i][m][k][l] * a[m][j]).sum()),
+    )
+}
+
+#[test]
+fn dyads() -> Result<(), TestError> {
+    let a = get_tensor_rank_2();
+    let b = get_other_tensor_rank_2();
+    assert_eq_within_tols(
+        &dyad_ij_kl(&a, &b).unwrap(),
+        &reference_tensor_rank_4(|i, j, k, l| a[i][j] * b[k][l]),
+    )?;
+    assert_eq_within_tols(
+        &dyad_ik_jl(&a, &b).unwrap(),
+        &reference_tensor_rank_4(|i, j, k, l| a[i][k] * b[j][l]),
+    )?;
+    assert_eq_within_tols(
+        &dyad_il_jk(&a, &b).unwrap(),
+        &reference_tensor_rank_4(|i, j, k, l| a[i][l] * b[j][k]),
+    )
+}
+
+#[test]
+fn fallback() {
+    assert!(tensor_rank_2_mul_tensor_rank_2(
+        &TensorRank2::<2, 1, 1>::identity(),
+        &TensorRank2::<2, 1, 1>::identity()
+    )
+    .is_none());
+    assert!(tensor_rank_2_mul_tensor_rank_2(
+        &TensorRank2::<3, 1, 1, f32>::identity(),
+        &TensorRank2::<3, 1, 1, f32>::identity()
+    )
+    .is_none());
+}
+
+#[test]
+fn tensor_rank_2_mul_tensor_rank_2_f64() -> Result<(), TestError> {
+    let a = get_tensor_rank_2();
+    let b = get_other_tensor_rank_2();
+    assert_eq_within_tols(
+        &tensor_rank_2_mul_tensor_rank_2(&a, &b).unwrap(),
+        &TensorRank2::new(std::array::from_fn(|i| {
+            std::array::from_fn(|k| (0..3).map(|j| a[i][j] * b[j][k]).sum())
+        })),
+    )
+}
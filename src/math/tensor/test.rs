@@ -85,7 +85,9 @@ impl fmt::Debug for TestError {
 }
 
 impl From<TensorError> for TestError {
-    fn from(_error: TensorError) -> TestError {
-        todo!()
+    fn from(error: TensorError) -> TestError {
+        TestError {
+            message: format!("{:?}", error),
+        }
     }
 }
@@ -4,9 +4,9 @@
 pub mod test;
 
 use crate::math::{
-    tensor_rank_1_zero, TensorRank0, TensorRank0List, TensorRank1, TensorRank1List,
-    TensorRank1List2D, TensorRank2, TensorRank2List, TensorRank2List2D, TensorRank3, TensorRank4,
-    TensorRank4List,
+    tensor_rank_1_zero, Rank2, TensorError, TensorRank0, TensorRank0List, TensorRank1,
+    TensorRank1List, TensorRank1List2D, TensorRank2, TensorRank2List, TensorRank2List2D,
+    TensorRank3, TensorRank4, TensorRank4List,
 };
 
 pub const IDENTITY: TensorRank2<3, 1, 1> = TensorRank2([
@@ -201,6 +201,9 @@ pub type HeatFlux = TensorRank1<3, 1>;
 /// The left Cauchy-Green deformation $`\mathbf{B}`$.
 pub type LeftCauchyGreenDeformation = TensorRank2<3, 1, 1>;
 
+/// The left stretch $`\mathbf{V}`$.
+pub type LeftStretch = TensorRank2<3, 1, 1>;
+
 /// The Mandel stress $`\mathbf{M}`$.
 pub type MandelStress = TensorRank2<3, 2, 2>;
 
@@ -216,6 +219,9 @@ pub type ReferenceCoordinates<const W: usize> = TensorRank1List<3, 0, W>;
 /// The right Cauchy-Green deformation $`\mathbf{C}`$.
 pub type RightCauchyGreenDeformation = TensorRank2<3, 0, 0>;
 
+/// The rotation $`\mathbf{R}`$ from the polar decomposition of the deformation gradient.
+pub type Rotation = TensorRank2<3, 1, 0>;
+
 /// The rotation of the current configuration $`\mathbf{Q}`$.
 pub type RotationCurrentConfiguration = TensorRank2<3, 1, 1>;
 
@@ -225,6 +231,9 @@ pub type RotationRateCurrentConfiguration = TensorRank2<3, 1, 1>;
 /// The rotation of the reference configuration $`\mathbf{Q}_0`$.
 pub type RotationReferenceConfiguration = TensorRank2<3, 0, 0>;
 
+/// The right stretch $`\mathbf{U}`$.
+pub type RightStretch = TensorRank2<3, 0, 0>;
+
 /// A scalar.
 pub type Scalar = TensorRank0;
 
@@ -266,3 +275,31 @@ pub type Vectors<const I: usize, const W: usize> = TensorRank1List<3, I, W>;
 
 /// A 2D list of vectors.
 pub type Vectors2D<const I: usize, const W: usize, const X: usize> = TensorRank1List2D<3, I, W, X>;
+
+impl DeformationGradient {
+    /// Returns the polar decomposition $`\mathbf{F}=\mathbf{R}\cdot\mathbf{U}=\mathbf{V}\cdot\mathbf{R}`$ of the deformation gradient.
+    ///
+    /// ```math
+    /// \mathbf{U} = \sqrt{\mathbf{F}^T\cdot\mathbf{F}},\quad\mathbf{R} = \mathbf{F}\cdot\mathbf{U}^{-1},\quad\mathbf{V} = \mathbf{R}\cdot\mathbf{U}\cdot\mathbf{R}^T
+    /// ```
+    ///
+    /// Deformation gradients without a positive determinant are reported as singular.
+    pub fn polar_decomposition(
+        &self,
+    ) -> Result<(Rotation, RightStretch, LeftStretch), TensorError> {
+        //
+        // The left stretch is symmetrized after the rotation so that rounding errors do not make it unsymmetric.
+        //
+        if self.determinant() <= 0.0 {
+            return Err(TensorError::Singular);
+        }
+        let right_stretch: RightStretch = (self.transpose() * self).square_root()?;
+        let rotation: Rotation = self * right_stretch.inverse();
+        let left_stretch: LeftStretch = &rotation * &right_stretch * rotation.transpose();
+        Ok((
+            rotation,
+            right_stretch,
+            (left_stretch.transpose() + left_stretch) * 0.5,
+        ))
+    }
+}
@@ -6,7 +6,7 @@ use super::{
 };
 use crate::math::{
     test::{assert_eq_within_tols, TestError},
-    Rank2, Tensor, TensorArray,
+    Rank2, Tensor, TensorArray, TensorError,
 };
 
 pub fn get_deformation_gradient() -> DeformationGradient {
@@ -143,6 +143,62 @@ fn identity_1010() {
     })
 }
 
+#[test]
+fn polar_decomposition() -> Result<(), TestError> {
+    let deformation_gradient = get_deformation_gradient();
+    let (rotation, right_stretch, left_stretch) = deformation_gradient.polar_decomposition()?;
+    assert_eq_within_tols(&(&rotation * rotation.transpose()), &IDENTITY)?;
+    assert_eq_within_tols(&rotation.determinant(), &1.0)?;
+    assert_eq_within_tols(&right_stretch, &right_stretch.transpose())?;
+    assert_eq!(left_stretch, left_stretch.transpose());
+    assert_eq_within_tols(&(&rotation * &right_stretch), &deformation_gradient)?;
+    assert_eq_within_tols(&(&left_stretch * &rotation), &deformation_gradient)
+}
+
+#[test]
+fn polar_decomposition_negative_determinant() {
+    assert_eq!(
+        DeformationGradient::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]])
+            .polar_decomposition(),
+        Err(TensorError::Singular)
+    )
+}
+
+#[test]
+fn polar_decomposition_objectivity() -> Result<(), TestError> {
+    let (rotation, right_stretch, left_stretch) =
+        get_deformation_gradient().polar_decomposition()?;
+    let (rotation_rotated, right_stretch_rotated, left_stretch_rotated) =
+        get_deformation_gradient_rotated().polar_decomposition()?;
+    assert_eq_within_tols(
+        &rotation_rotated,
+        &(get_rotation_current_configuration()
+            * rotation
+            * get_rotation_reference_configuration().transpose()),
+    )?;
+    assert_eq_within_tols(
+        &right_stretch_rotated,
+        &(get_rotation_reference_configuration()
+            * right_stretch
+            * get_rotation_reference_configuration().transpose()),
+    )?;
+    assert_eq_within_tols(
+        &left_stretch_rotated,
+        &(get_rotation_current_configuration()
+            * left_stretch
+            * get_rotation_current_configuration().transpose()),
+    )
+}
+
+#[test]
+fn polar_decomposition_singular() {
+    assert_eq!(
+        DeformationGradient::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]])
+            .polar_decomposition(),
+        Err(TensorError::Singular)
+    )
+}
+
 #[test]
 fn rotation_current_configuration() -> Result<(), TestError> {
     assert_eq_within_tols(